@@ -1,4 +1,4 @@
-use crate::{Addition, ClosedUnder, Division, Groupoid, Multiplication, Subtraction};
+use crate::{Addition, And, Bounds, ClosedUnder, Division, Groupoid, Max, Min, Multiplication, Or, Subtraction, Xor};
 
 impl<T: core::ops::Add<Output = T> + ClosedUnder<Addition> + Copy> Groupoid<Addition> for T {
     fn op(&self, rhs: &Self) -> Self {
@@ -19,4 +19,29 @@ impl<T: core::ops::Div<Output = T> + ClosedUnder<Division> + Copy> Groupoid<Divi
     fn op(&self, rhs: &Self) -> Self {
         self.div(*rhs)
     }
-}
\ No newline at end of file
+}
+impl<T: Bounds + ClosedUnder<Min> + Copy> Groupoid<Min> for T {
+    fn op(&self, rhs: &Self) -> Self {
+        Bounds::min(*self, *rhs)
+    }
+}
+impl<T: Bounds + ClosedUnder<Max> + Copy> Groupoid<Max> for T {
+    fn op(&self, rhs: &Self) -> Self {
+        Bounds::max(*self, *rhs)
+    }
+}
+impl<T: core::ops::BitAnd<Output = T> + ClosedUnder<And> + Copy> Groupoid<And> for T {
+    fn op(&self, rhs: &Self) -> Self {
+        self.bitand(*rhs)
+    }
+}
+impl<T: core::ops::BitOr<Output = T> + ClosedUnder<Or> + Copy> Groupoid<Or> for T {
+    fn op(&self, rhs: &Self) -> Self {
+        self.bitor(*rhs)
+    }
+}
+impl<T: core::ops::BitXor<Output = T> + ClosedUnder<Xor> + Copy> Groupoid<Xor> for T {
+    fn op(&self, rhs: &Self) -> Self {
+        self.bitxor(*rhs)
+    }
+}
@@ -1,9 +1,13 @@
 mod properties;
 mod util;
 mod addition;
+mod order;
+mod morphism;
 pub use properties::*;
 pub use util::*;
 pub use addition::*;
+pub use order::*;
+pub use morphism::*;
 
 /// Algebraic structure which denotes a set (which is the implementor)
 /// that is closed under an operator ⨁.
@@ -162,6 +166,28 @@ impl<DistributiveOp: Operator, Op: Operator, T> Field<DistributiveOp, Op> for T
     Inverse<DistributiveOp>{
     
 }
+/// A set with a complement that's a [`Semiring`] under `(And, Or)` and a
+/// [`CommutativeRing`] under `(And, Xor)`, with [`Or`] also distributing over
+/// [`And`]. `bool` is one, and so are the unsigned integers, as sets of bits.
+///
+/// Every value squares to itself in the ring, and for `bool` it's GF(2).
+pub trait BooleanAlgebra:
+    Semiring<And, Or> +
+    CommutativeRing<And, Xor> +
+    Distributive<Or, And> +
+    core::ops::Not<Output = Self> {
+    
+}
+
+impl<T> BooleanAlgebra for T
+    where T: 
+    Semiring<And, Or> +
+    CommutativeRing<And, Xor> +
+    Distributive<Or, And> +
+    core::ops::Not<Output = Self> {
+    
+}
+
 /// Represents an n dimensional tuple of scalars which correspond to a [`Semiring`], 
 /// and has a distributive scalar multiplication operation ×, such that for semimodules 
 /// `B` and `C`, and scalar `a`, then `a × (B + C) = aB + aC`.
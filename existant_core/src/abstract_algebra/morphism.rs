@@ -0,0 +1,81 @@
+use core::marker::PhantomData;
+
+use crate::{Operator, Semimodule};
+
+/// A map from `Self` into `Target` that preserves the operator ⨁, so
+/// `f(a ⨁ b) = f(a) ⨁ f(b)`. Maps that preserve several operators implement
+/// this once for each.
+pub trait Homomorphism<Op: Operator, Target> {
+    fn map(self) -> Target;
+}
+
+/// [`Homomorphism`] that has an inverse going the other way, so `Self` and
+/// `Target` are the same structure under ⨁ written differently. Any pair of
+/// homomorphisms both ways is assumed to be inverses of each other.
+pub trait Isomorphism<Op: Operator, Target>: Homomorphism<Op, Target> + Sized
+    where Target: Homomorphism<Op, Self> {
+    fn unmap(target: Target) -> Self {
+        target.map()
+    }
+}
+
+impl<Op: Operator, Target, T> Isomorphism<Op, Target> for T
+    where T: Homomorphism<Op, Target>,
+    Target: Homomorphism<Op, T> {
+
+}
+
+/// A map between two [`Semimodule`]s over the same scalars that preserves
+/// their addition and scalar multiplication, `f(aU + bV) = af(U) + bf(V)`.
+/// Unlike a [`Homomorphism`] it's a value, like a matrix.
+pub trait LinearMap<V: Semimodule, W: Semimodule<Scalar = <V as Semimodule>::Scalar>> {
+    fn apply(&self, v: V) -> W;
+    /// The map that applies `self` and then `next`.
+    fn then<U, G>(self, next: G) -> Composition<Self, G, W>
+        where Self: Sized,
+        U: Semimodule<Scalar = <V as Semimodule>::Scalar>,
+        G: LinearMap<W, U> {
+        Composition { first: self, second: next, middle: PhantomData }
+    }
+}
+
+/// [`LinearMap`] that can be undone whenever it's one-to-one, like a square
+/// matrix with a nonzero determinant.
+pub trait InvertibleLinearMap<V: Semimodule, W: Semimodule<Scalar = <V as Semimodule>::Scalar>>: LinearMap<V, W> {
+    type Inverse: LinearMap<W, V>;
+    /// The map going back from `W`, or `None` when `self` squashes some
+    /// nonzero vector to zero.
+    fn invert(&self) -> Option<Self::Inverse>;
+}
+
+/// Two [`LinearMap`]s applied one after the other, through `V`.
+#[derive(Debug, Clone, Copy)]
+pub struct Composition<F, G, V> {
+    first: F,
+    second: G,
+    middle: PhantomData<V>,
+}
+
+impl<U, V, W, F, G> LinearMap<U, W> for Composition<F, G, V>
+    where U: Semimodule,
+    V: Semimodule<Scalar = <U as Semimodule>::Scalar>,
+    W: Semimodule<Scalar = <U as Semimodule>::Scalar>,
+    F: LinearMap<U, V>,
+    G: LinearMap<V, W> {
+    fn apply(&self, v: U) -> W {
+        self.second.apply(self.first.apply(v))
+    }
+}
+
+/// Undoing both maps in the opposite order undoes their composition.
+impl<U, V, W, F, G> InvertibleLinearMap<U, W> for Composition<F, G, V>
+    where U: Semimodule,
+    V: Semimodule<Scalar = <U as Semimodule>::Scalar>,
+    W: Semimodule<Scalar = <U as Semimodule>::Scalar>,
+    F: InvertibleLinearMap<U, V>,
+    G: InvertibleLinearMap<V, W> {
+    type Inverse = Composition<G::Inverse, F::Inverse, V>;
+    fn invert(&self) -> Option<Self::Inverse> {
+        Some(Composition { first: self.second.invert()?, second: self.first.invert()?, middle: PhantomData })
+    }
+}
@@ -0,0 +1,115 @@
+use core::cmp::Ordering;
+
+use crate::BasicField;
+
+/// A set with a partial order ≤ that's reflexive, antisymmetric and
+/// transitive, but where two elements don't have to be comparable. Unlike
+/// [`PartialOrd`] this is free to disagree with the lexicographic order, so
+/// vectors are ordered component-wise.
+///
+/// NaN isn't part of the order on the floats, it isn't even `≤` itself.
+pub trait PartialOrderSet {
+    fn leq(&self, other: &Self) -> bool;
+    fn geq(&self, other: &Self) -> bool {
+        other.leq(self)
+    }
+    /// Whether either one is `≤` the other.
+    fn comparable(&self, other: &Self) -> bool {
+        self.leq(other) || other.leq(self)
+    }
+    /// Whether `self` is strictly above `other`, which for vectors is Pareto
+    /// dominance: no smaller anywhere, and bigger somewhere.
+    fn dominates(&self, other: &Self) -> bool {
+        other.leq(self) && !self.leq(other)
+    }
+    fn partial_order(&self, other: &Self) -> Option<Ordering> {
+        match (self.leq(other), other.leq(self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+/// [`PartialOrderSet`] where every pair has a greatest lower bound, the meet
+/// `a ∧ b`, and a least upper bound, the join `a ∨ b`.
+pub trait Lattice: PartialOrderSet + Sized {
+    fn meet(self, other: Self) -> Self;
+    fn join(self, other: Self) -> Self;
+}
+
+/// [`Lattice`] with a bottom ⊥ below everything and a top ⊤ above everything,
+/// which are the identities of join and meet respectively.
+pub trait BoundedLattice: Lattice {
+    const BOTTOM: Self;
+    const TOP: Self;
+}
+
+/// [`Lattice`] where meet distributes over join, `a ∧ (b ∨ c) = (a ∧ b) ∨ (a ∧ c)`,
+/// and so join over meet. Every total order is one.
+pub trait DistributiveLattice: Lattice {
+
+}
+
+/// [`BasicField`] with a total order that agrees with its operations, so
+/// `a ≤ b` implies `a + c ≤ b + c`, and `0 ≤ a`, `0 ≤ b` imply `0 ≤ ab`.
+pub trait OrderedField:
+    BasicField +
+    DistributiveLattice {
+
+}
+
+impl<T> OrderedField for T
+    where T:
+    BasicField +
+    DistributiveLattice {
+
+}
+
+macro_rules! impl_order {
+    ($bottom:ident, $top:ident, $($structure:tt),*) => {
+        $(
+            impl PartialOrderSet for $structure {
+                fn leq(&self, other: &Self) -> bool {
+                    self <= other
+                }
+            }
+            impl Lattice for $structure {
+                fn meet(self, other: Self) -> Self {
+                    crate::Bounds::min(self, other)
+                }
+                fn join(self, other: Self) -> Self {
+                    crate::Bounds::max(self, other)
+                }
+            }
+            impl BoundedLattice for $structure {
+                const BOTTOM: Self = $structure::$bottom;
+                const TOP: Self = $structure::$top;
+            }
+            impl DistributiveLattice for $structure {}
+        )*
+    };
+}
+
+impl_order!(MIN, MAX, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_order!(NEG_INFINITY, INFINITY, f32, f64);
+
+impl PartialOrderSet for bool {
+    fn leq(&self, other: &Self) -> bool {
+        self <= other
+    }
+}
+impl Lattice for bool {
+    fn meet(self, other: Self) -> Self {
+        self & other
+    }
+    fn join(self, other: Self) -> Self {
+        self | other
+    }
+}
+impl BoundedLattice for bool {
+    const BOTTOM: Self = false;
+    const TOP: Self = true;
+}
+impl DistributiveLattice for bool {}
@@ -77,11 +77,106 @@ macro_rules! impl_absorption {
         )*
     };
 }
+/// [`Min`] and [`Max`] over a totally ordered type, where the bottom of the
+/// order absorbs [`Min`] and is the identity of [`Max`], and the top the
+/// other way around.
+macro_rules! impl_lattice {
+    ($bottom:ident, $top:ident, $($structure:tt),*) => {
+        $(
+            impl_properties!($structure, Min, AssociativeOver, CommutativeOver, ClosedUnder);
+            impl_properties!($structure, Max, AssociativeOver, CommutativeOver, ClosedUnder);
+            impl Identity<Min> for $structure {
+                const IDENTITY: Self = $structure::$top;
+                fn is_identity(&self) -> bool {
+                    *self == $structure::$top
+                }
+            }
+            impl Identity<Max> for $structure {
+                const IDENTITY: Self = $structure::$bottom;
+                fn is_identity(&self) -> bool {
+                    *self == $structure::$bottom
+                }
+            }
+            impl Absorption<Min> for $structure {
+                const ABSORBING: Self = $structure::$bottom;
+                fn is_absorber(&self) -> bool {
+                    *self == $structure::$bottom
+                }
+            }
+            impl Absorption<Max> for $structure {
+                const ABSORBING: Self = $structure::$top;
+                fn is_absorber(&self) -> bool {
+                    *self == $structure::$top
+                }
+            }
+        )*
+    };
+}
+/// The bitwise operators, which act on each bit by itself, so everything
+/// that holds for `bool` holds here too. `$none` has no bits set.
+macro_rules! impl_bitwise {
+    ($none:tt, $($structure:tt),*) => {
+        $(
+            impl_properties!($structure, And, AssociativeOver, CommutativeOver, ClosedUnder);
+            impl_properties!($structure, Or, AssociativeOver, CommutativeOver, ClosedUnder);
+            impl_properties!($structure, Xor, AssociativeOver, CommutativeOver, ClosedUnder);
+            impl Identity<And> for $structure {
+                const IDENTITY: Self = !$none;
+                fn is_identity(&self) -> bool {
+                    *self == !$none
+                }
+            }
+            impl Absorption<And> for $structure {
+                const ABSORBING: Self = $none;
+                fn is_absorber(&self) -> bool {
+                    *self == $none
+                }
+            }
+            impl Identity<Or> for $structure {
+                const IDENTITY: Self = $none;
+                fn is_identity(&self) -> bool {
+                    *self == $none
+                }
+            }
+            impl Absorption<Or> for $structure {
+                const ABSORBING: Self = !$none;
+                fn is_absorber(&self) -> bool {
+                    *self == !$none
+                }
+            }
+            impl Identity<Xor> for $structure {
+                const IDENTITY: Self = $none;
+                fn is_identity(&self) -> bool {
+                    *self == $none
+                }
+            }
+            // x ^ x = 0
+            impl Inverse<Xor> for $structure {
+                fn inverse(self) -> Self {
+                    self
+                }
+            }
+            impl Distributive<And, Or> for $structure {}
+            impl Distributive<Or, And> for $structure {}
+            impl Distributive<And, Xor> for $structure {}
+        )*
+    };
+}
 
 pub struct Addition;
 pub struct Subtraction;
 pub struct Multiplication;
 pub struct Division;
+/// Picks the smaller of two values.
+pub struct Min;
+/// Picks the larger of two values.
+pub struct Max;
+/// Logical or bitwise and, `&`.
+pub struct And;
+/// Logical or bitwise or, `|`.
+pub struct Or;
+/// Logical or bitwise exclusive or, `^`.
+pub struct Xor;
 /// Denotes a binary operator that can be used
 /// over some set. The operators themselves are
 /// denoted by sizeless structures, which can be
@@ -100,7 +195,8 @@ pub struct Division;
 /// already been proven that those categories have been
 /// satisfied, as the traits dont check whether operations
 /// actually satisfy those properties, they are just there
-/// as dummies.
+/// as dummies. The `laws` feature adds the `laws` module,
+/// which tests them against random values.
 pub trait Operator: Sized {
     
 }
@@ -148,6 +244,11 @@ impl Operator for Addition {}
 impl Operator for Subtraction {}
 impl Operator for Multiplication {}
 impl Operator for Division {}
+impl Operator for Min {}
+impl Operator for Max {}
+impl Operator for And {}
+impl Operator for Or {}
+impl Operator for Xor {}
 
 impl_ty_properties!(Addition, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 impl_ty_properties!(Multiplication, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
@@ -177,6 +278,27 @@ impl_identity!(1.0, Division, f32, f64);
 impl_absorption!(0, Multiplication, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 impl_absorption!(0.0, Multiplication, f32, f64);
 
+impl_lattice!(MIN, MAX, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_lattice!(NEG_INFINITY, INFINITY, f32, f64);
+// a + min(b, c) = min(a + b, a + c), which is what makes the tropical
+// semirings work, and min and max distribute over each other. The integers
+// are left to `Tropical`, since adding to their `MAX` overflows instead of
+// staying the zero the way the infinities do.
+impl_distributive!(Addition, Min, f32, f64);
+impl_distributive!(Addition, Max, f32, f64);
+impl_distributive!(Min, Max, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_distributive!(Max, Min, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+
+impl_bitwise!(false, bool);
+impl_bitwise!(0, u8, u16, u32, u64, u128, usize);
+// bool under And and Xor is GF(2), where the only nonzero value is its own
+// inverse.
+impl Inverse<And> for bool {
+    fn inverse(self) -> Self {
+        self
+    }
+}
 
 // impl_semigroup!(u8, u16, u32, u64, u128);
 // impl_ring!(i8, i16, i32, i64, i128);
@@ -0,0 +1,194 @@
+use core::fmt::Debug;
+
+use crate::{Bounds, Signed};
+#[cfg(feature = "half")]
+use crate::{bf16, f16};
+
+/// How far apart two values can be for [`ApproxEq`] to still call them equal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance<E> {
+    /// `|a - b| <= epsilon`, for values that are close to zero, where a
+    /// relative tolerance would want them to match exactly.
+    Absolute(E),
+    /// `|a - b| <= epsilon * max(|a|, |b|)`, so the tolerance scales with
+    /// the values.
+    Relative(E),
+    /// At most this many floats apart. Values of opposite signs are only
+    /// equal when they're both zero.
+    Ulps(u32),
+}
+
+/// Equality up to rounding. Composite types compare each component on its
+/// own with the same [`Tolerance`], so a vector is only equal to another if
+/// every component is.
+///
+/// Values that are exactly equal, infinities included, are always
+/// approximately equal, and NaN never is.
+pub trait ApproxEq {
+    type Epsilon: Copy;
+    /// What [`assert_approx_eq!`](crate::assert_approx_eq) uses when it isn't
+    /// given a tolerance. For floats that's 4 ulps, which only covers results
+    /// that should have been the same but got rounded differently, so
+    /// anything compared against zero wants [`Tolerance::Absolute`].
+    fn default_tolerance() -> Tolerance<Self::Epsilon>;
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool;
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.approx_eq(other, Tolerance::Absolute(epsilon))
+    }
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.approx_eq(other, Tolerance::Relative(epsilon))
+    }
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.approx_eq(other, Tolerance::Ulps(max_ulps))
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    ($($structure:ident),*) => {
+        $(
+            impl ApproxEq for $structure {
+                type Epsilon = Self;
+                fn default_tolerance() -> Tolerance<Self> {
+                    Tolerance::Ulps(4)
+                }
+                fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self>) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if !self.is_finite() || !other.is_finite() {
+                        return false;
+                    }
+                    match tolerance {
+                        Tolerance::Absolute(epsilon) => Signed::abs(*self - *other) <= epsilon,
+                        Tolerance::Relative(epsilon) => {
+                            let largest = Bounds::max(Signed::abs(*self), Signed::abs(*other));
+                            Signed::abs(*self - *other) <= largest * epsilon
+                        }
+                        // floats of the same sign are ordered the same way
+                        // as their bits
+                        Tolerance::Ulps(max_ulps) => {
+                            self.is_sign_negative() == other.is_sign_negative() &&
+                            self.to_bits().abs_diff(other.to_bits()) as u64 <= max_ulps as u64
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_float!(f32, f64);
+#[cfg(feature = "half")]
+impl_approx_eq_float!(f16, bf16);
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    /// Slices of different lengths are never equal.
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.len() == other.len() &&
+        self.iter().zip(other).all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance)
+    }
+}
+
+/// Asserts that two values are [`ApproxEq`], within their default tolerance
+/// or the one given. On failure both values are printed, one above the other,
+/// along with the tolerance.
+/// ```
+/// use existant_core::{assert_approx_eq, Tolerance};
+///
+/// assert_approx_eq!(0.1f32 + 0.2, 0.3);
+/// assert_approx_eq!([1.0f64, 1e-12], [1.0, 0.0], Tolerance::Absolute(1e-9));
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let tolerance = $crate::approx_default_tolerance(left);
+                if !$crate::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx_assert_failed("≈", left, right, tolerance, None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx_assert_failed("≈", left, right, tolerance, None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx_assert_failed("≈", left, right, tolerance, Some(format_args!($($arg)+)));
+                }
+            }
+        }
+    };
+}
+
+/// The opposite of [`assert_approx_eq!`], for values that should have moved
+/// further than rounding would.
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let tolerance = $crate::approx_default_tolerance(left);
+                if $crate::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx_assert_failed("≉", left, right, tolerance, None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if $crate::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx_assert_failed("≉", left, right, tolerance, None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if $crate::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx_assert_failed("≉", left, right, tolerance, Some(format_args!($($arg)+)));
+                }
+            }
+        }
+    };
+}
+
+/// Lets the assertions name the default tolerance without naming the type.
+#[doc(hidden)]
+pub fn approx_default_tolerance<T: ApproxEq + ?Sized>(_: &T) -> Tolerance<T::Epsilon> {
+    T::default_tolerance()
+}
+
+#[doc(hidden)]
+#[cold]
+#[track_caller]
+pub fn approx_assert_failed<T: Debug + ?Sized, E: Debug>(relation: &str, left: &T, right: &T, tolerance: Tolerance<E>, message: Option<core::fmt::Arguments<'_>>) -> ! {
+    match message {
+        Some(message) => panic!("assertion `left {relation} right` failed: {message}\n     left: {left:?}\n    right: {right:?}\ntolerance: {tolerance:?}"),
+        None => panic!("assertion `left {relation} right` failed\n     left: {left:?}\n    right: {right:?}\ntolerance: {tolerance:?}"),
+    }
+}
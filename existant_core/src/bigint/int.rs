@@ -0,0 +1,342 @@
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FromPrimitive, Identity, Integer, IntoPrimitive, Inverse, Multiplication, Signed, Subtraction};
+
+use super::{impl_assign, impl_bigint_identity, impl_bigint_properties, BigUint, ParseBigIntError};
+
+/// A signed integer stored as a sign and a [`BigUint`] magnitude, so it
+/// holds anything whose absolute value is below 2^(64 * `LIMBS`).
+///
+/// Like [`BigUint`] that capacity is fixed, and going past it panics even
+/// in release builds, with the `checked_*` methods returning [`None`].
+///
+/// Division truncates toward zero and the remainder takes the sign of the
+/// dividend, like the primitive integers.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigInt<const LIMBS: usize = 16> {
+    /// Never set for zero, so there's only one zero.
+    negative: bool,
+    magnitude: BigUint<LIMBS>,
+}
+
+impl<const LIMBS: usize> BigInt<LIMBS> {
+    pub const ZERO: Self = Self { negative: false, magnitude: BigUint::ZERO };
+    pub const ONE: Self = Self { negative: false, magnitude: BigUint::ONE };
+    pub const MIN: Self = Self { negative: true, magnitude: BigUint::MAX };
+    pub const MAX: Self = Self { negative: false, magnitude: BigUint::MAX };
+
+    pub const fn from_parts(negative: bool, magnitude: BigUint<LIMBS>) -> Self {
+        Self { negative: negative && !magnitude.is_zero(), magnitude }
+    }
+    pub const fn from_biguint(magnitude: BigUint<LIMBS>) -> Self {
+        Self { negative: false, magnitude }
+    }
+    pub const fn unsigned_abs(self) -> BigUint<LIMBS> {
+        self.magnitude
+    }
+    pub const fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+    /// -1, 0 or 1 depending on the sign.
+    pub const fn signum(self) -> Self {
+        if self.magnitude.is_zero() {
+            Self::ZERO
+        } else {
+            Self { negative: self.negative, magnitude: BigUint::ONE }
+        }
+    }
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.negative == rhs.negative {
+            return Some(Self::from_parts(self.negative, self.magnitude.checked_add(rhs.magnitude)?));
+        }
+        // Opposite signs, so the larger magnitude decides the sign.
+        Some(match self.magnitude.cmp(&rhs.magnitude) {
+            Ordering::Less => Self::from_parts(rhs.negative, rhs.magnitude - self.magnitude),
+            _ => Self::from_parts(self.negative, self.magnitude - rhs.magnitude),
+        })
+    }
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::from_parts(self.negative != rhs.negative, self.magnitude.checked_mul(rhs.magnitude)?))
+    }
+    /// Returns the truncated quotient and remainder of `self / rhs`, or
+    /// [`None`] when dividing by zero.
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        let (quotient, remainder) = self.magnitude.checked_div_rem(rhs.magnitude)?;
+        Some((
+            Self::from_parts(self.negative != rhs.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        ))
+    }
+    pub fn checked_pow(self, exponent: u32) -> Option<Self> {
+        Some(Self::from_parts(self.negative && exponent & 1 == 1, self.magnitude.checked_pow(exponent)?))
+    }
+    pub fn pow(self, exponent: u32) -> Self {
+        self.checked_pow(exponent).expect("attempt to multiply with overflow")
+    }
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.magnitude.to_f64();
+        if self.negative { -magnitude } else { magnitude }
+    }
+    pub fn to_f32(&self) -> f32 {
+        let magnitude = self.magnitude.to_f32();
+        if self.negative { -magnitude } else { magnitude }
+    }
+    /// Truncates toward zero like `as`, saturating at [`BigInt::MIN`] and
+    /// [`BigInt::MAX`].
+    pub fn from_f64(value: f64) -> Self {
+        Self::from_parts(value < 0.0, BigUint::from_f64(value.abs()))
+    }
+    pub const fn from_i128(value: i128) -> Self {
+        Self::from_parts(value < 0, BigUint::from_u128(value.unsigned_abs()))
+    }
+    /// The lowest 128 bits in two's complement, which is what `as` keeps
+    /// when casting to a narrower primitive.
+    pub const fn low_i128(&self) -> i128 {
+        let low = self.magnitude.low_u128();
+        (if self.negative { low.wrapping_neg() } else { low }) as i128
+    }
+}
+
+impl<const LIMBS: usize> Default for BigInt<LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> From<BigUint<LIMBS>> for BigInt<LIMBS> {
+    fn from(value: BigUint<LIMBS>) -> Self {
+        Self::from_biguint(value)
+    }
+}
+
+impl<const LIMBS: usize> Ord for BigInt<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+impl<const LIMBS: usize> PartialOrd for BigInt<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> fmt::Display for BigInt<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        fmt::Display::fmt(&self.magnitude, f)
+    }
+}
+impl<const LIMBS: usize> fmt::Debug for BigInt<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+impl<const LIMBS: usize> FromStr for BigInt<LIMBS> {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        Ok(Self::from_parts(negative, BigUint::parse_digits(digits)?))
+    }
+}
+
+impl<const LIMBS: usize> core::ops::Neg for BigInt<LIMBS> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+impl<const LIMBS: usize> core::ops::Add for BigInt<LIMBS> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+impl<const LIMBS: usize> core::ops::Sub for BigInt<LIMBS> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("attempt to subtract with overflow")
+    }
+}
+impl<const LIMBS: usize> core::ops::Mul for BigInt<LIMBS> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("attempt to multiply with overflow")
+    }
+}
+impl<const LIMBS: usize> core::ops::Div for BigInt<LIMBS> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div_rem(rhs).expect("attempt to divide by zero").0
+    }
+}
+impl<const LIMBS: usize> core::ops::Rem for BigInt<LIMBS> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_div_rem(rhs).expect("attempt to calculate the remainder with a divisor of zero").1
+    }
+}
+
+impl_assign!(BigInt,
+    AddAssign, add_assign, Add, add, Self;
+    SubAssign, sub_assign, Sub, sub, Self;
+    MulAssign, mul_assign, Mul, mul, Self;
+    DivAssign, div_assign, Div, div, Self;
+    RemAssign, rem_assign, Rem, rem, Self;
+);
+
+impl<const LIMBS: usize> Signed for BigInt<LIMBS> {
+    fn is_negative(self) -> bool {
+        self.negative
+    }
+    fn is_positive(self) -> bool {
+        !self.negative && !self.magnitude.is_zero()
+    }
+    fn abs(self) -> Self {
+        Self::from_biguint(self.magnitude)
+    }
+    fn flip_sign(self) -> Self {
+        -self
+    }
+}
+
+impl<const LIMBS: usize> Integer for BigInt<LIMBS> {}
+
+impl<const LIMBS: usize> Bounds for BigInt<LIMBS> {
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+    const MIN: Self = Self::MIN;
+    const MAX: Self = Self::MAX;
+}
+
+impl_bigint_properties!(BigInt, Addition, AssociativeOver, CommutativeOver, ClosedUnder);
+impl_bigint_properties!(BigInt, Multiplication, AssociativeOver, CommutativeOver, ClosedUnder);
+impl_bigint_properties!(BigInt, Subtraction, AssociativeOver, ClosedUnder);
+impl<const LIMBS: usize> Distributive<Multiplication, Addition> for BigInt<LIMBS> {}
+impl<const LIMBS: usize> Distributive<Multiplication, Subtraction> for BigInt<LIMBS> {}
+impl<const LIMBS: usize> Distributive<Division, Addition> for BigInt<LIMBS> {}
+impl<const LIMBS: usize> Distributive<Division, Subtraction> for BigInt<LIMBS> {}
+impl_bigint_identity!(BigInt, ZERO, Addition, Subtraction);
+impl_bigint_identity!(BigInt, ONE, Multiplication, Division);
+impl<const LIMBS: usize> Absorption<Multiplication> for BigInt<LIMBS> {
+    const ABSORBING: Self = Self::ZERO;
+    fn is_absorber(&self) -> bool {
+        self.is_zero()
+    }
+}
+impl<const LIMBS: usize> Inverse<Addition> for BigInt<LIMBS> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+impl<const LIMBS: usize> Inverse<Subtraction> for BigInt<LIMBS> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+
+impl<const LIMBS: usize> FromPrimitive for BigInt<LIMBS> {
+    fn from_u8(val: u8) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_u16(val: u16) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_u32(val: u32) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_u64(val: u64) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_u128(val: u128) -> Self {
+        Self::from_biguint(BigUint::from_u128(val))
+    }
+    fn from_usize(val: usize) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_i8(val: i8) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_i16(val: i16) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_i32(val: i32) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_i64(val: i64) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_i128(val: i128) -> Self {
+        Self::from_i128(val)
+    }
+    fn from_isize(val: isize) -> Self {
+        Self::from_i128(val as i128)
+    }
+    fn from_f32(val: f32) -> Self {
+        Self::from_f64(val as f64)
+    }
+    fn from_f64(val: f64) -> Self {
+        Self::from_f64(val)
+    }
+}
+
+impl<const LIMBS: usize> IntoPrimitive for BigInt<LIMBS> {
+    fn to_u8(self) -> u8 {
+        self.low_i128() as u8
+    }
+    fn to_u16(self) -> u16 {
+        self.low_i128() as u16
+    }
+    fn to_u32(self) -> u32 {
+        self.low_i128() as u32
+    }
+    fn to_u64(self) -> u64 {
+        self.low_i128() as u64
+    }
+    fn to_u128(self) -> u128 {
+        self.low_i128() as u128
+    }
+    fn to_usize(self) -> usize {
+        self.low_i128() as usize
+    }
+    fn to_i8(self) -> i8 {
+        self.low_i128() as i8
+    }
+    fn to_i16(self) -> i16 {
+        self.low_i128() as i16
+    }
+    fn to_i32(self) -> i32 {
+        self.low_i128() as i32
+    }
+    fn to_i64(self) -> i64 {
+        self.low_i128() as i64
+    }
+    fn to_i128(self) -> i128 {
+        self.low_i128()
+    }
+    fn to_isize(self) -> isize {
+        self.low_i128() as isize
+    }
+    fn to_f32(self) -> f32 {
+        BigInt::to_f32(&self)
+    }
+    fn to_f64(self) -> f64 {
+        BigInt::to_f64(&self)
+    }
+}
@@ -0,0 +1,37 @@
+//! Fixed capacity big integers. The algebra traits need `Copy`, so rather
+//! than growing on the heap these carry their limbs inline, with the amount
+//! of limbs as a const parameter. A result that doesn't fit in those limbs
+//! is an overflow, the same as with the primitive integers.
+
+mod int;
+mod uint;
+pub use int::*;
+pub use uint::*;
+use uint::{impl_assign, impl_bigint_identity, impl_bigint_properties};
+
+/// Error from parsing a [`BigUint`] or [`BigInt`] out of a decimal string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    Empty,
+    InvalidDigit,
+    /// The value doesn't fit in the limbs of the integer.
+    Overflow,
+}
+
+impl core::fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "cannot parse integer from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::Overflow => "number too large to fit in target type",
+        })
+    }
+}
+impl core::error::Error for ParseBigIntError {}
+
+pub type U256 = BigUint<4>;
+pub type U512 = BigUint<8>;
+pub type U1024 = BigUint<16>;
+pub type I256 = BigInt<4>;
+pub type I512 = BigInt<8>;
+pub type I1024 = BigInt<16>;
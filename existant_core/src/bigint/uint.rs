@@ -0,0 +1,596 @@
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FromPrimitive, Identity, Integer, IntoPrimitive, Multiplication, Subtraction};
+
+use super::ParseBigIntError;
+
+/// The largest power of 10 that fits in a limb.
+const DECIMAL_CHUNK: u64 = 10_000_000_000_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 19;
+
+/// An unsigned integer made out of `LIMBS` 64 bit limbs, so it can hold
+/// anything below 2^(64 * `LIMBS`). By default that's 1024 bits.
+///
+/// The capacity is fixed, so this doesn't grow to fit a result the way a
+/// heap allocated big integer would. Arithmetic that goes past it panics,
+/// in release builds as well, and the `checked_*` methods return [`None`]
+/// instead. Pick `LIMBS` with room for the largest intermediate value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigUint<const LIMBS: usize = 16> {
+    /// Little endian, so `limbs[0]` holds the lowest 64 bits.
+    limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> BigUint<LIMBS> {
+    pub const ZERO: Self = Self { limbs: [0; LIMBS] };
+    pub const ONE: Self = Self::from_u128(1);
+    pub const MAX: Self = Self { limbs: [u64::MAX; LIMBS] };
+    /// The amount of bits this integer can hold.
+    pub const BITS: u32 = 64 * LIMBS as u32;
+
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self { limbs }
+    }
+    /// The limbs of this integer, least significant first.
+    pub const fn limbs(&self) -> &[u64; LIMBS] {
+        &self.limbs
+    }
+    /// Creates an integer out of a u128, dropping the bits that don't fit
+    /// when `LIMBS` is 1.
+    pub const fn from_u128(value: u128) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value as u64;
+        if LIMBS > 1 {
+            limbs[1] = (value >> 64) as u64;
+        }
+        Self { limbs }
+    }
+    /// The lowest 128 bits of this integer.
+    pub const fn low_u128(&self) -> u128 {
+        if LIMBS > 1 {
+            (self.limbs[1] as u128) << 64 | self.limbs[0] as u128
+        } else {
+            self.limbs[0] as u128
+        }
+    }
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.limbs[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+    pub const fn is_even(&self) -> bool {
+        self.limbs[0] & 1 == 0
+    }
+    /// Number of limbs up to and including the most significant nonzero one.
+    fn len(&self) -> usize {
+        self.limbs.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1)
+    }
+    /// Number of bits needed to represent this integer.
+    pub fn bits(&self) -> u32 {
+        match self.len() {
+            0 => 0,
+            len => 64 * len as u32 - self.limbs[len - 1].leading_zeros(),
+        }
+    }
+    pub fn leading_zeros(&self) -> u32 {
+        Self::BITS - self.bits()
+    }
+    pub fn trailing_zeros(&self) -> u32 {
+        match self.limbs.iter().position(|&limb| limb != 0) {
+            Some(i) => 64 * i as u32 + self.limbs[i].trailing_zeros(),
+            None => Self::BITS,
+        }
+    }
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let mut limbs = [0; LIMBS];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, overflow_a) = self.limbs[i].overflowing_add(rhs.limbs[i]);
+            let (sum, overflow_b) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = overflow_a | overflow_b;
+        }
+        (!carry).then_some(Self { limbs })
+    }
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let mut limbs = [0; LIMBS];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (difference, overflow_a) = self.limbs[i].overflowing_sub(rhs.limbs[i]);
+            let (difference, overflow_b) = difference.overflowing_sub(borrow as u64);
+            *limb = difference;
+            borrow = overflow_a | overflow_b;
+        }
+        (!borrow).then_some(Self { limbs })
+    }
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mut limbs = [0; LIMBS];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            if a == 0 {
+                continue;
+            }
+            let mut carry = 0u64;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let product = a as u128 * b as u128 + carry as u128;
+                if i + j < LIMBS {
+                    let sum = product + limbs[i + j] as u128;
+                    limbs[i + j] = sum as u64;
+                    carry = (sum >> 64) as u64;
+                } else if product != 0 {
+                    return None;
+                }
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        Some(Self { limbs })
+    }
+    /// Returns the quotient and remainder of `self / rhs`, or [`None`] when
+    /// dividing by zero.
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        let divisor_len = rhs.len();
+        if divisor_len == 0 {
+            return None;
+        }
+        if self < rhs {
+            return Some((Self::ZERO, self));
+        }
+        if divisor_len == 1 {
+            let (quotient, remainder) = self.div_rem_limb(rhs.limbs[0]);
+            return Some((quotient, Self::from_u128(remainder as u128)));
+        }
+        Some(self.div_rem_knuth(rhs, divisor_len))
+    }
+    pub fn checked_pow(self, mut exponent: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+    pub fn pow(self, exponent: u32) -> Self {
+        self.checked_pow(exponent).expect("attempt to multiply with overflow")
+    }
+    /// Divides by a single limb, which is all that's needed to print in
+    /// decimal.
+    fn div_rem_limb(self, divisor: u64) -> (Self, u64) {
+        let mut quotient = [0; LIMBS];
+        let mut remainder = 0u128;
+        for i in (0..LIMBS).rev() {
+            let dividend = remainder << 64 | self.limbs[i] as u128;
+            quotient[i] = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        (Self { limbs: quotient }, remainder as u64)
+    }
+    /// Knuth's algorithm D, from The Art of Computer Programming volume 2,
+    /// section 4.3.1. `divisor_len` has to be at least 2.
+    fn div_rem_knuth(self, divisor: Self, divisor_len: usize) -> (Self, Self) {
+        let n = divisor_len;
+        let m = self.len() - n;
+        // Normalize so the top limb of the divisor has its high bit set,
+        // which keeps the quotient estimates below off by at most 2.
+        let shift = divisor.limbs[n - 1].leading_zeros();
+        let v = divisor << shift;
+        let top = if shift == 0 { 0 } else { self.limbs[LIMBS - 1] >> (64 - shift) };
+        let mut u = Dividend { limbs: (self << shift).limbs, top };
+        let mut quotient = [0; LIMBS];
+        let (v_top, v_next) = (v.limbs[n - 1] as u128, v.limbs[n - 2] as u128);
+        for j in (0..=m).rev() {
+            let numerator = (u.get(j + n) as u128) << 64 | u.get(j + n - 1) as u128;
+            let mut q_hat = numerator / v_top;
+            let mut r_hat = numerator % v_top;
+            while q_hat >> 64 != 0 || q_hat * v_next > (r_hat << 64 | u.get(j + n - 2) as u128) {
+                q_hat -= 1;
+                r_hat += v_top;
+                if r_hat >> 64 != 0 {
+                    break;
+                }
+            }
+            // Multiply and subtract, u[j..=j + n] -= q_hat * v
+            let mut borrow = 0i128;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let product = q_hat * v.limbs[i] as u128 + carry;
+                carry = product >> 64;
+                let difference = u.get(i + j) as i128 - borrow - (product as u64) as i128;
+                u.set(i + j, difference as u64);
+                borrow = (difference < 0) as i128;
+            }
+            let difference = u.get(j + n) as i128 - borrow - carry as i128;
+            u.set(j + n, difference as u64);
+            quotient[j] = q_hat as u64;
+            if difference < 0 {
+                // The estimate was one too large, so add the divisor back.
+                quotient[j] -= 1;
+                let mut carry = 0u128;
+                for i in 0..n {
+                    let sum = u.get(i + j) as u128 + v.limbs[i] as u128 + carry;
+                    u.set(i + j, sum as u64);
+                    carry = sum >> 64;
+                }
+                u.set(j + n, u.get(j + n).wrapping_add(carry as u64));
+            }
+        }
+        let mut remainder = [0; LIMBS];
+        remainder[..n].copy_from_slice(&u.limbs[..n]);
+        (Self { limbs: quotient }, Self { limbs: remainder } >> shift)
+    }
+    pub(crate) fn parse_digits(digits: &str) -> Result<Self, ParseBigIntError> {
+        if digits.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+        if !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+            return Err(ParseBigIntError::InvalidDigit);
+        }
+        let mut value = Self::ZERO;
+        // Leading chunk first, so every later chunk has the full 19 digits.
+        let (head, tail) = digits.split_at(digits.len() % DECIMAL_CHUNK_DIGITS);
+        let chunks = core::iter::once(head).chain(tail.as_bytes().chunks(DECIMAL_CHUNK_DIGITS).map(|chunk| {
+            // The digits were checked to be ascii above.
+            core::str::from_utf8(chunk).unwrap()
+        }));
+        for chunk in chunks.filter(|chunk| !chunk.is_empty()) {
+            let scale = Self::from_u128(10u128.pow(chunk.len() as u32));
+            let chunk = Self::from_u128(chunk.parse::<u64>().unwrap() as u128);
+            value = value
+                .checked_mul(scale)
+                .and_then(|value| value.checked_add(chunk))
+                .ok_or(ParseBigIntError::Overflow)?;
+        }
+        Ok(value)
+    }
+    /// Rounds to the nearest float given a conversion of the top 64 bits
+    /// and a way to scale it back up.
+    fn round_to_float<F: Copy + core::ops::Mul<Output = F>>(&self, from_u64: fn(u64) -> F, two_to_the_64: F) -> F {
+        let len = self.len();
+        if len <= 1 {
+            return from_u64(self.limbs[0]);
+        }
+        // Keep the top 64 significant bits and fold everything below into
+        // the lowest one, so the single rounding in `from_u64` is correct.
+        let shift = 64 * (len as u32 - 1) - self.limbs[len - 1].leading_zeros();
+        let shifted = *self >> shift;
+        let sticky = (shifted << shift) != *self;
+        let mut result = from_u64(shifted.limbs[0] | sticky as u64);
+        // Scaling by powers of 2 is exact, short of overflowing to infinity.
+        for _ in 0..shift / 64 {
+            result = result * two_to_the_64;
+        }
+        result * from_u64(1 << (shift % 64))
+    }
+    pub fn to_f64(&self) -> f64 {
+        self.round_to_float(|x| x as f64, 18446744073709551616.0)
+    }
+    pub fn to_f32(&self) -> f32 {
+        self.round_to_float(|x| x as f32, 18446744073709551616.0)
+    }
+    /// Truncates toward zero like `as`, saturating at 0 and [`BigUint::MAX`].
+    pub fn from_f64(value: f64) -> Self {
+        if value.is_nan() || value < 1.0 {
+            return Self::ZERO;
+        }
+        if value.is_infinite() {
+            return Self::MAX;
+        }
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7FF) as i32 - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        if exponent < 0 {
+            Self::from_u128((mantissa >> -exponent) as u128)
+        } else if exponent as u32 + 53 > Self::BITS {
+            Self::MAX
+        } else {
+            Self::from_u128(mantissa as u128) << exponent as u32
+        }
+    }
+    /// Sign extends `value` to every limb, which is what `as` does when
+    /// casting a negative primitive to a wider unsigned one.
+    pub(crate) fn from_i128_wrapping(value: i128) -> Self {
+        let mut limbs = [if value < 0 { u64::MAX } else { 0 }; LIMBS];
+        limbs[0] = value as u64;
+        if LIMBS > 1 {
+            limbs[1] = (value >> 64) as u64;
+        }
+        Self { limbs }
+    }
+    fn fmt_decimal(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (quotient, remainder) = self.div_rem_limb(DECIMAL_CHUNK);
+        if quotient.is_zero() {
+            write!(f, "{remainder}")
+        } else {
+            quotient.fmt_decimal(f)?;
+            write!(f, "{remainder:019}")
+        }
+    }
+}
+
+/// The dividend during long division, with room for the limb that gets
+/// shifted out of the top while normalizing.
+struct Dividend<const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+    top: u64,
+}
+
+impl<const LIMBS: usize> Dividend<LIMBS> {
+    fn get(&self, i: usize) -> u64 {
+        if i == LIMBS { self.top } else { self.limbs[i] }
+    }
+    fn set(&mut self, i: usize, limb: u64) {
+        if i == LIMBS { self.top = limb } else { self.limbs[i] = limb }
+    }
+}
+
+impl<const LIMBS: usize> Default for BigUint<LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> Ord for BigUint<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+impl<const LIMBS: usize> PartialOrd for BigUint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> fmt::Display for BigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_decimal(f)
+    }
+}
+impl<const LIMBS: usize> fmt::Debug for BigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_decimal(f)
+    }
+}
+impl<const LIMBS: usize> FromStr for BigUint<LIMBS> {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_digits(s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+impl<const LIMBS: usize> core::ops::Add for BigUint<LIMBS> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+impl<const LIMBS: usize> core::ops::Sub for BigUint<LIMBS> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("attempt to subtract with overflow")
+    }
+}
+impl<const LIMBS: usize> core::ops::Mul for BigUint<LIMBS> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("attempt to multiply with overflow")
+    }
+}
+impl<const LIMBS: usize> core::ops::Div for BigUint<LIMBS> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div_rem(rhs).expect("attempt to divide by zero").0
+    }
+}
+impl<const LIMBS: usize> core::ops::Rem for BigUint<LIMBS> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_div_rem(rhs).expect("attempt to calculate the remainder with a divisor of zero").1
+    }
+}
+impl<const LIMBS: usize> core::ops::Shl<u32> for BigUint<LIMBS> {
+    type Output = Self;
+    fn shl(self, rhs: u32) -> Self::Output {
+        assert!(rhs < Self::BITS, "attempt to shift left with overflow");
+        let (limbs, bits) = ((rhs / 64) as usize, rhs % 64);
+        let mut result = [0; LIMBS];
+        for (i, limb) in result.iter_mut().enumerate().skip(limbs) {
+            *limb = self.limbs[i - limbs] << bits;
+            if bits > 0 && i > limbs {
+                *limb |= self.limbs[i - limbs - 1] >> (64 - bits);
+            }
+        }
+        Self { limbs: result }
+    }
+}
+impl<const LIMBS: usize> core::ops::Shr<u32> for BigUint<LIMBS> {
+    type Output = Self;
+    fn shr(self, rhs: u32) -> Self::Output {
+        assert!(rhs < Self::BITS, "attempt to shift right with overflow");
+        let (limbs, bits) = ((rhs / 64) as usize, rhs % 64);
+        let mut result = [0; LIMBS];
+        for (i, limb) in result.iter_mut().enumerate().take(LIMBS - limbs) {
+            *limb = self.limbs[i + limbs] >> bits;
+            if bits > 0 && i + limbs + 1 < LIMBS {
+                *limb |= self.limbs[i + limbs + 1] << (64 - bits);
+            }
+        }
+        Self { limbs: result }
+    }
+}
+
+macro_rules! impl_assign {
+    ($structure:ident, $($assign:ident, $assign_fn:ident, $op:ident, $op_fn:ident, $rhs:ty);* $(;)?) => {
+        $(
+            impl<const LIMBS: usize> core::ops::$assign<$rhs> for $structure<LIMBS> {
+                fn $assign_fn(&mut self, rhs: $rhs) {
+                    *self = core::ops::$op::$op_fn(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+pub(super) use impl_assign;
+
+impl_assign!(BigUint,
+    AddAssign, add_assign, Add, add, Self;
+    SubAssign, sub_assign, Sub, sub, Self;
+    MulAssign, mul_assign, Mul, mul, Self;
+    DivAssign, div_assign, Div, div, Self;
+    RemAssign, rem_assign, Rem, rem, Self;
+    ShlAssign, shl_assign, Shl, shl, u32;
+    ShrAssign, shr_assign, Shr, shr, u32;
+);
+
+impl<const LIMBS: usize> Integer for BigUint<LIMBS> {}
+
+impl<const LIMBS: usize> Bounds for BigUint<LIMBS> {
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+    const MIN: Self = Self::ZERO;
+    const MAX: Self = Self::MAX;
+}
+
+macro_rules! impl_bigint_properties {
+    ($structure:ident, $op:ident, $($property:ident),+) => {
+        $(
+            impl<const LIMBS: usize> $property<$op> for $structure<LIMBS> {
+
+            }
+        )+
+    };
+}
+pub(super) use impl_bigint_properties;
+
+macro_rules! impl_bigint_identity {
+    ($structure:ident, $value:ident, $($op:ident),+) => {
+        $(
+            impl<const LIMBS: usize> Identity<$op> for $structure<LIMBS> {
+                const IDENTITY: Self = Self::$value;
+                fn is_identity(&self) -> bool {
+                    *self == Self::$value
+                }
+            }
+        )+
+    };
+}
+pub(super) use impl_bigint_identity;
+
+impl_bigint_properties!(BigUint, Addition, AssociativeOver, CommutativeOver, ClosedUnder);
+impl_bigint_properties!(BigUint, Multiplication, AssociativeOver, CommutativeOver, ClosedUnder);
+impl<const LIMBS: usize> Distributive<Multiplication, Addition> for BigUint<LIMBS> {}
+impl<const LIMBS: usize> Distributive<Multiplication, Subtraction> for BigUint<LIMBS> {}
+impl<const LIMBS: usize> Distributive<Division, Addition> for BigUint<LIMBS> {}
+impl<const LIMBS: usize> Distributive<Division, Subtraction> for BigUint<LIMBS> {}
+impl_bigint_identity!(BigUint, ZERO, Addition, Subtraction);
+impl_bigint_identity!(BigUint, ONE, Multiplication, Division);
+impl<const LIMBS: usize> Absorption<Multiplication> for BigUint<LIMBS> {
+    const ABSORBING: Self = Self::ZERO;
+    fn is_absorber(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+impl<const LIMBS: usize> FromPrimitive for BigUint<LIMBS> {
+    fn from_u8(val: u8) -> Self {
+        Self::from_u128(val as u128)
+    }
+    fn from_u16(val: u16) -> Self {
+        Self::from_u128(val as u128)
+    }
+    fn from_u32(val: u32) -> Self {
+        Self::from_u128(val as u128)
+    }
+    fn from_u64(val: u64) -> Self {
+        Self::from_u128(val as u128)
+    }
+    fn from_u128(val: u128) -> Self {
+        Self::from_u128(val)
+    }
+    fn from_usize(val: usize) -> Self {
+        Self::from_u128(val as u128)
+    }
+    fn from_i8(val: i8) -> Self {
+        Self::from_i128_wrapping(val as i128)
+    }
+    fn from_i16(val: i16) -> Self {
+        Self::from_i128_wrapping(val as i128)
+    }
+    fn from_i32(val: i32) -> Self {
+        Self::from_i128_wrapping(val as i128)
+    }
+    fn from_i64(val: i64) -> Self {
+        Self::from_i128_wrapping(val as i128)
+    }
+    fn from_i128(val: i128) -> Self {
+        Self::from_i128_wrapping(val)
+    }
+    fn from_isize(val: isize) -> Self {
+        Self::from_i128_wrapping(val as i128)
+    }
+    fn from_f32(val: f32) -> Self {
+        Self::from_f64(val as f64)
+    }
+    fn from_f64(val: f64) -> Self {
+        Self::from_f64(val)
+    }
+}
+
+impl<const LIMBS: usize> IntoPrimitive for BigUint<LIMBS> {
+    fn to_u8(self) -> u8 {
+        self.low_u128() as u8
+    }
+    fn to_u16(self) -> u16 {
+        self.low_u128() as u16
+    }
+    fn to_u32(self) -> u32 {
+        self.low_u128() as u32
+    }
+    fn to_u64(self) -> u64 {
+        self.low_u128() as u64
+    }
+    fn to_u128(self) -> u128 {
+        self.low_u128()
+    }
+    fn to_usize(self) -> usize {
+        self.low_u128() as usize
+    }
+    fn to_i8(self) -> i8 {
+        self.low_u128() as i8
+    }
+    fn to_i16(self) -> i16 {
+        self.low_u128() as i16
+    }
+    fn to_i32(self) -> i32 {
+        self.low_u128() as i32
+    }
+    fn to_i64(self) -> i64 {
+        self.low_u128() as i64
+    }
+    fn to_i128(self) -> i128 {
+        self.low_u128() as i128
+    }
+    fn to_isize(self) -> isize {
+        self.low_u128() as isize
+    }
+    fn to_f32(self) -> f32 {
+        BigUint::to_f32(&self)
+    }
+    fn to_f64(self) -> f64 {
+        BigUint::to_f64(&self)
+    }
+}
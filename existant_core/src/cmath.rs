@@ -1,3 +1,8 @@
+// The functions backing `FloatingPoint` for f32 and f64. These link against the
+// platform's C math library, unless the `libm` feature is enabled, in which case
+// the pure Rust ports from the `libm` crate are used instead.
+
+#[cfg(not(feature = "libm"))]
 unsafe extern "C" {
     pub safe fn acos(a: f64) -> f64;
     pub safe fn acosh(a: f64) -> f64;
@@ -10,12 +15,16 @@ unsafe extern "C" {
     pub safe fn ceil(a: f64) -> f64;
     pub safe fn cos(a: f64) -> f64;
     pub safe fn erf(a: f64) -> f64;
+    pub safe fn erfc(a: f64) -> f64;
     pub safe fn exp(a: f64) -> f64;
     pub safe fn exp2(a: f64) -> f64;
     pub safe fn expm1(a: f64) -> f64;
+    pub safe fn fdim(a: f64, b: f64) -> f64;
     pub safe fn floor(a: f64) -> f64;
+    pub safe fn fma(a: f64, b: f64, c: f64) -> f64;
     #[cfg_attr(target_env = "msvc", link_name = "_hypot")]
     pub safe fn hypot(a: f64, b: f64) -> f64;
+    pub safe fn ldexp(a: f64, n: i32) -> f64;
     pub safe fn log(a: f64) -> f64;
     pub safe fn log1p(a: f64) -> f64;
     pub safe fn log10(a: f64) -> f64;
@@ -27,7 +36,10 @@ unsafe extern "C" {
     pub safe fn sqrt(a: f64) -> f64;
     pub safe fn tan(a: f64) -> f64;
     pub safe fn tanh(a: f64) -> f64;
+    pub safe fn tgamma(a: f64) -> f64;
     pub safe fn trunc(a: f64) -> f64;
+    #[link_name = "frexp"]
+    safe fn frexp_into(a: f64, exponent: &mut i32) -> f64;
     
     pub safe fn acosf(a: f32) -> f32;
     pub safe fn acoshf(a: f32) -> f32;
@@ -40,12 +52,16 @@ unsafe extern "C" {
     pub safe fn ceilf(a: f32) -> f32;
     pub safe fn cosf(a: f32) -> f32;
     pub safe fn erff(a: f32) -> f32;
+    pub safe fn erfcf(a: f32) -> f32;
     pub safe fn expf(a: f32) -> f32;
     pub safe fn exp2f(a: f32) -> f32;
     pub safe fn expm1f(a: f32) -> f32;
+    pub safe fn fdimf(a: f32, b: f32) -> f32;
     pub safe fn floorf(a: f32) -> f32;
+    pub safe fn fmaf(a: f32, b: f32, c: f32) -> f32;
     #[cfg_attr(target_env = "msvc", link_name = "_hypotf")]
     pub safe fn hypotf(a: f32, b: f32) -> f32;
+    pub safe fn ldexpf(a: f32, n: i32) -> f32;
     pub safe fn logf(a: f32) -> f32;
     pub safe fn log1pf(a: f32) -> f32;
     pub safe fn log10f(a: f32) -> f32;
@@ -57,5 +73,45 @@ unsafe extern "C" {
     pub safe fn sqrtf(a: f32) -> f32;
     pub safe fn tanf(a: f32) -> f32;
     pub safe fn tanhf(a: f32) -> f32;
+    pub safe fn tgammaf(a: f32) -> f32;
     pub safe fn truncf(a: f32) -> f32;
-}
\ No newline at end of file
+    #[link_name = "frexpf"]
+    safe fn frexpf_into(a: f32, exponent: &mut i32) -> f32;
+}
+
+// `frexp` hands back the exponent through a pointer, so it's wrapped to return
+// both like the `libm` port does.
+#[cfg(not(feature = "libm"))]
+pub fn frexp(a: f64) -> (f64, i32) {
+    let mut exponent = 0;
+    let mantissa = frexp_into(a, &mut exponent);
+    (mantissa, exponent)
+}
+#[cfg(not(feature = "libm"))]
+pub fn frexpf(a: f32) -> (f32, i32) {
+    let mut exponent = 0;
+    let mantissa = frexpf_into(a, &mut exponent);
+    (mantissa, exponent)
+}
+
+#[cfg(feature = "libm")]
+pub use libm::{
+    acos, asin, asinh, atan, atan2, atanh, cbrt, ceil, cos, erf, erfc, exp, exp2, expm1,
+    fdim, floor, fma, frexp, hypot, ldexp, log, log1p, log10, log2, pow, round, sin, sinh,
+    sqrt, tan, tanh, tgamma, trunc,
+
+    acosf, asinf, asinhf, atanf, atan2f, atanhf, cbrtf, ceilf, cosf, erff, erfcf, expf,
+    exp2f, expm1f, fdimf, floorf, fmaf, frexpf, hypotf, ldexpf, logf, log1pf, log10f, log2f,
+    powf, roundf, sinf, sinhf, sqrtf, tanf, tanhf, tgammaf, truncf
+};
+
+// The `libm` ports leave acosh undefined below 1 and return garbage for it,
+// where the C library gives NaN.
+#[cfg(feature = "libm")]
+pub fn acosh(a: f64) -> f64 {
+    if a < 1.0 { f64::NAN } else { libm::acosh(a) }
+}
+#[cfg(feature = "libm")]
+pub fn acoshf(a: f32) -> f32 {
+    if a < 1.0 { f32::NAN } else { libm::acoshf(a) }
+}
@@ -0,0 +1,451 @@
+//! The elementary functions as `const fn`, for rotations, lookup tables and
+//! anything else that should be worked out at compile time. They're named
+//! after the C functions, with an `f` on the end for the f32 versions.
+//!
+//! The f64 functions are ports of the ones in fdlibm, and stay within an ULP
+//! of the math library. `sqrt` is correctly rounded. Arguments of every size
+//! are reduced with all the bits of 2/π they need, so `sin(1e300)` is as
+//! accurate as `sin(1.0)`. The f32 functions go through f64 and round once
+//! more, which can land them an ULP away from what the math library gives at
+//! runtime, so don't count on them being bit for bit the same.
+
+// the constants are written out the way fdlibm has them, to be checked
+// against it
+#![allow(clippy::excessive_precision)]
+
+const PIO2_HI: f64 = core::f64::consts::FRAC_PI_2;
+const PIO2_LO: f64 = 6.12323399573676603587e-17;
+const PI_LO: f64 = 1.22464679914735317720e-16;
+const LN2_HI: f64 = 6.93147180369123816490e-01;
+const LN2_LO: f64 = 1.90821492927058770002e-10;
+
+/// The bits of 2/π after the point, most significant first. Reducing the
+/// largest f64 needs the first 1100 or so.
+const TWO_OVER_PI: [u64; 19] = [
+    0xA2F9836E4E441529, 0xFC2757D1F534DDC0, 0xDB6295993C439041, 0xFE5163ABDEBBC561,
+    0xB7246E3A424DD2E0, 0x06492EEA09D1921C, 0xFE1DEB1CB129A73E, 0xE88235F52EBB4484,
+    0xE99C7026B45F7E41, 0x3991D639835339F4, 0x9C845F8BBDF9283B, 0x1FF897FFDE05980F,
+    0xEF2F118B5A0A6D1F, 0x6D367ECF27CB09B7, 0x4F463F669E5FEA2D, 0x7527BAC7EBE5F17B,
+    0x3D0739F78A5292EA, 0x6BFB5FB11F8D5D08, 0x56033046FC7B6BAB,
+];
+
+/// 2ⁿ, for `n` in the normal range.
+const fn power_of_two(n: i32) -> f64 {
+    f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+/// `x * 2ⁿ`, in steps that only round on the last one.
+const fn ldexp(mut x: f64, mut n: i32) -> f64 {
+    if n > 1023 {
+        x *= power_of_two(1023);
+        n -= 1023;
+        if n > 1023 {
+            x *= power_of_two(1023);
+            n = if n - 1023 > 1023 { 1023 } else { n - 1023 };
+        }
+    } else if n < -1022 {
+        // going down to 2⁻⁹⁶⁹ first keeps the intermediate result normal
+        x *= power_of_two(-969);
+        n += 969;
+        if n < -1022 {
+            x *= power_of_two(-969);
+            n = if n + 969 < -1022 { -1022 } else { n + 969 };
+        }
+    }
+    x * power_of_two(n)
+}
+
+/// A positive, finite and non zero `x` as `mantissa * 2^exponent`, with the
+/// mantissa in `[2⁵², 2⁵³)`.
+const fn decompose(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let biased = (bits >> 52) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    if biased == 0 {
+        let shift = fraction.leading_zeros() - 11;
+        (fraction << shift, -1074 - shift as i32)
+    } else {
+        (fraction | 1 << 52, biased - 1075)
+    }
+}
+
+/// Bit `i` of 2/π, counting the first one after the point as 1.
+const fn two_over_pi_bit(i: i32) -> u64 {
+    if i < 1 {
+        return 0;
+    }
+    let i = (i - 1) as usize;
+    (TWO_OVER_PI[i / 64] >> (63 - i % 64)) & 1
+}
+
+/// `a * b` as the rounded product and its rounding error, by splitting both
+/// into halves whose products are exact.
+const fn two_product(a: f64, b: f64) -> (f64, f64) {
+    const fn split(x: f64) -> (f64, f64) {
+        let c = 134217729.0 * x;
+        let high = c - (c - x);
+        (high, x - high)
+    }
+    let product = a * b;
+    let ((a_high, a_low), (b_high, b_low)) = (split(a), split(b));
+    let error = ((a_high * b_high - product) + a_high * b_low + a_low * b_high) + a_low * b_low;
+    (product, error)
+}
+
+/// `x` as `n·π/2 + y`, with `|y| <= π/4` given as a sum of two f64s, and
+/// only the last two bits of `n`.
+///
+/// This is Payne and Hanek's method: with `x = m·2ᵉ`, the bits of 2/π
+/// before the `e - 1`th only add multiples of 4 to `x·2/π`, so a window of
+/// 192 bits from there on is enough to get the quadrant and a fraction with
+/// more bits than any f64 can cancel.
+const fn reduce(x: f64) -> (i32, f64, f64) {
+    if x.abs() <= core::f64::consts::FRAC_PI_4 {
+        return (0, x, 0.0);
+    }
+    let (mantissa, exponent) = decompose(x.abs());
+    let start = exponent - 1;
+    let mut window = [0u64; 3];
+    let mut k = 0;
+    while k < 192 {
+        let limb = 2 - k / 64;
+        window[limb as usize] |= two_over_pi_bit(start + k) << (63 - k % 64);
+        k += 1;
+    }
+    // the product, as four 64 bit limbs with the least significant first,
+    // has its point between bits 189 and 190
+    let mut product = [0u64; 4];
+    let mut carry = 0u128;
+    let mut i = 0;
+    while i < 3 {
+        let term = mantissa as u128 * window[i] as u128 + carry;
+        product[i] = term as u64;
+        carry = term >> 64;
+        i += 1;
+    }
+    product[3] = carry as u64;
+    let mut quadrant = (product[2] >> 62) as i32;
+    let low = (product[0] >> 62) | (product[1] << 2);
+    let high = (product[1] >> 62) | (product[2] << 2);
+    let mut fraction = (high as u128) << 64 | low as u128;
+    // past a half, it's closer to the next quadrant
+    let negative = fraction >> 127 == 1;
+    if negative {
+        quadrant += 1;
+        fraction = fraction.wrapping_neg();
+    }
+    let upper = (fraction >> 75) as f64 * power_of_two(-53);
+    let lower = (fraction & ((1 << 75) - 1)) as f64 * power_of_two(-128);
+    let (high, error) = two_product(upper, PIO2_HI);
+    let tail = error + upper * PIO2_LO + lower * PIO2_HI;
+    let mut y = high + tail;
+    let mut y_tail = tail - (y - high);
+    if negative {
+        y = -y;
+        y_tail = -y_tail;
+    }
+    if x < 0.0 {
+        (-quadrant, -y, -y_tail)
+    } else {
+        (quadrant, y, y_tail)
+    }
+}
+
+/// sin(x + tail) for `|x| <= π/4`.
+const fn kernel_sin(x: f64, tail: f64) -> f64 {
+    const S1: f64 = -1.66666666666666324348e-01;
+    const S2: f64 = 8.33333333332248946124e-03;
+    const S3: f64 = -1.98412698298579493134e-04;
+    const S4: f64 = 2.75573137070700676789e-06;
+    const S5: f64 = -2.50507602534068634195e-08;
+    const S6: f64 = 1.58969099521155010221e-10;
+    let z = x * x;
+    let w = z * z;
+    let r = S2 + z * (S3 + z * S4) + z * w * (S5 + z * S6);
+    let v = z * x;
+    x - ((z * (0.5 * tail - v * r) - tail) - v * S1)
+}
+
+/// cos(x + tail) for `|x| <= π/4`.
+const fn kernel_cos(x: f64, tail: f64) -> f64 {
+    const C1: f64 = 4.16666666666666019037e-02;
+    const C2: f64 = -1.38888888888741095749e-03;
+    const C3: f64 = 2.48015872894767294178e-05;
+    const C4: f64 = -2.75573143513906633035e-07;
+    const C5: f64 = 2.08757232129817482790e-09;
+    const C6: f64 = -1.13596475577881948265e-11;
+    let z = x * x;
+    let w = z * z;
+    let r = z * (C1 + z * (C2 + z * C3)) + w * w * (C4 + z * (C5 + z * C6));
+    let half = 0.5 * z;
+    let w = 1.0 - half;
+    w + (((1.0 - w) - half) + (z * r - x * tail))
+}
+
+/// The sine and cosine of `x`, in radians.
+pub const fn sin_cos(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+    let (quadrant, y, tail) = reduce(x);
+    let (sin, cos) = (kernel_sin(y, tail), kernel_cos(y, tail));
+    match quadrant & 3 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+pub const fn sin(x: f64) -> f64 {
+    sin_cos(x).0
+}
+
+pub const fn cos(x: f64) -> f64 {
+    sin_cos(x).1
+}
+
+/// The quotient of [`sin_cos`], so it can be off by an ULP more than they
+/// are.
+pub const fn tan(x: f64) -> f64 {
+    let (sin, cos) = sin_cos(x);
+    sin / cos
+}
+
+/// The arctangent, in `[-π/2, π/2]`.
+pub const fn atan(x: f64) -> f64 {
+    const ATAN_HI: [f64; 4] = [4.63647609000806093515e-01, core::f64::consts::FRAC_PI_4, 9.82793723247329054082e-01, PIO2_HI];
+    const ATAN_LO: [f64; 4] = [2.26987774529616870924e-17, 3.06161699786838301793e-17, 1.39033110312309984516e-17, 6.12323399573676603587e-17];
+    const T: [f64; 11] = [
+        3.33333333333329318027e-01, -1.99999999998764832476e-01, 1.42857142725034663711e-01,
+        -1.11111104054623557880e-01, 9.09088713343650656196e-02, -7.69187620504482999495e-02,
+        6.66107313738753120669e-02, -5.83357013379057348645e-02, 4.97687799461593236017e-02,
+        -3.65315727442169155270e-02, 1.62858201153657823623e-02,
+    ];
+    if x.is_nan() {
+        return x;
+    }
+    let magnitude = x.abs();
+    if magnitude >= 7.378697629483821e19 {
+        // 2⁶⁶, past which the arctangent rounds to ±π/2
+        return (ATAN_HI[3] + ATAN_LO[3]).copysign(x);
+    }
+    if magnitude < 3.725290298461914e-9 {
+        return x;
+    }
+    // which of atan(½), atan(1), atan(3/2) and atan(∞) the argument gets
+    // measured from, if any
+    let (id, t) = if magnitude < 0.4375 {
+        (4, x)
+    } else if magnitude < 0.6875 {
+        (0, (2.0 * magnitude - 1.0) / (2.0 + magnitude))
+    } else if magnitude < 1.1875 {
+        (1, (magnitude - 1.0) / (magnitude + 1.0))
+    } else if magnitude < 2.4375 {
+        (2, (magnitude - 1.5) / (1.0 + 1.5 * magnitude))
+    } else {
+        (3, -1.0 / magnitude)
+    };
+    let z = t * t;
+    let w = z * z;
+    let odd = z * (T[0] + w * (T[2] + w * (T[4] + w * (T[6] + w * (T[8] + w * T[10])))));
+    let even = w * (T[1] + w * (T[3] + w * (T[5] + w * (T[7] + w * T[9]))));
+    if id == 4 {
+        return t - t * (odd + even);
+    }
+    let result = ATAN_HI[id] - ((t * (odd + even) - ATAN_LO[id]) - t);
+    result.copysign(x)
+}
+
+const fn biased_exponent(x: f64) -> i32 {
+    ((x.to_bits() >> 52) & 0x7FF) as i32
+}
+
+/// The angle of the point `(x, y)` from the positive x axis, in `[-π, π]`.
+pub const fn atan2(y: f64, x: f64) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x == 1.0 {
+        return atan(y);
+    }
+    // the quadrant, by the signs of y then x
+    let quadrant = y.is_sign_negative() as u8 | (x.is_sign_negative() as u8) << 1;
+    if y == 0.0 {
+        return match quadrant {
+            0 | 1 => y,
+            2 => PI,
+            _ => -PI,
+        };
+    }
+    if x == 0.0 {
+        return FRAC_PI_2.copysign(y);
+    }
+    if x.is_infinite() {
+        return if y.is_infinite() {
+            match quadrant {
+                0 => FRAC_PI_4,
+                1 => -FRAC_PI_4,
+                2 => 3.0 * FRAC_PI_4,
+                _ => -3.0 * FRAC_PI_4,
+            }
+        } else {
+            match quadrant {
+                0 => 0.0,
+                1 => -0.0,
+                2 => PI,
+                _ => -PI,
+            }
+        };
+    }
+    if y.is_infinite() {
+        return FRAC_PI_2.copysign(y);
+    }
+    let difference = biased_exponent(y) - biased_exponent(x);
+    let (z, quadrant) = if difference > 60 {
+        (PIO2_HI + 0.5 * PI_LO, quadrant & 1)
+    } else if x < 0.0 && difference < -60 {
+        (0.0, quadrant)
+    } else {
+        (atan((y / x).abs()), quadrant)
+    };
+    match quadrant {
+        0 => z,
+        1 => -z,
+        2 => PI - (z - PI_LO),
+        _ => (z - PI_LO) - PI,
+    }
+}
+
+/// `e` to the power of `x`.
+pub const fn exp(x: f64) -> f64 {
+    const P1: f64 = 1.66666666666666019037e-01;
+    const P2: f64 = -2.77777777770155933842e-03;
+    const P3: f64 = 6.61375632143793436117e-05;
+    const P4: f64 = -1.65339022054652515390e-06;
+    const P5: f64 = 4.13813679705723846039e-08;
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.782712893383973096 {
+        return f64::INFINITY;
+    }
+    if x < -745.13321910194110842 {
+        return 0.0;
+    }
+    if x.abs() < 3.725290298461914e-9 {
+        return 1.0 + x;
+    }
+    // x = k·ln 2 + r, with ln 2 in two parts so k·LN2_HI is exact
+    let k = (core::f64::consts::LOG2_E * x + 0.5f64.copysign(x)) as i32;
+    let high = x - k as f64 * LN2_HI;
+    let low = k as f64 * LN2_LO;
+    let r = high - low;
+    let rr = r * r;
+    let c = r - rr * (P1 + rr * (P2 + rr * (P3 + rr * (P4 + rr * P5))));
+    let y = 1.0 + (r * c / (2.0 - c) - low + high);
+    ldexp(y, k)
+}
+
+/// The natural logarithm.
+pub const fn ln(x: f64) -> f64 {
+    const LG1: f64 = 6.666666666666735130e-01;
+    const LG2: f64 = 3.999999999940941908e-01;
+    const LG3: f64 = 2.857142874366239149e-01;
+    const LG4: f64 = 2.222219843214978396e-01;
+    const LG5: f64 = 1.818357216161805012e-01;
+    const LG6: f64 = 1.531383769920937332e-01;
+    const LG7: f64 = 1.479819860511658591e-01;
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 1.0 {
+        return 0.0;
+    }
+    let (mut x, mut k) = (x, 0);
+    if x < f64::MIN_POSITIVE {
+        x *= power_of_two(54);
+        k -= 54;
+    }
+    // x = 2ᵏ(1 + f), with 1 + f in [√2/2, √2)
+    let bits = x.to_bits();
+    let high = (bits >> 32) as u32 + (0x3FF00000 - 0x3FE6A09E);
+    k += (high >> 20) as i32 - 0x3FF;
+    let high = (high & 0x000FFFFF) + 0x3FE6A09E;
+    let x = f64::from_bits((high as u64) << 32 | (bits & 0xFFFFFFFF));
+    let f = x - 1.0;
+    let half_square = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let even = w * (LG2 + w * (LG4 + w * LG6));
+    let odd = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    let k = k as f64;
+    s * (half_square + odd + even) + k * LN2_LO - half_square + f + k * LN2_HI
+}
+
+/// The square root, correctly rounded like the one at runtime. NaN for
+/// anything below zero.
+pub const fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    let (mantissa, exponent) = decompose(x);
+    // shifted so its root has 53 bits, by whatever leaves an even exponent
+    let shift = 52 + exponent.rem_euclid(2);
+    let scaled = (mantissa as u128) << shift;
+    let mut root = scaled.isqrt();
+    // (root + ½)² = root² + root + ¼, so rounding up comes down to this
+    if scaled - root * root > root {
+        root += 1;
+    }
+    ldexp(root as f64, (exponent - shift) / 2)
+}
+
+pub const fn sqrtf(x: f32) -> f32 {
+    // a root rounded to 53 bits and then to 24 is still correctly rounded
+    sqrt(x as f64) as f32
+}
+
+pub const fn sin_cosf(x: f32) -> (f32, f32) {
+    let (sin, cos) = sin_cos(x as f64);
+    (sin as f32, cos as f32)
+}
+
+pub const fn sinf(x: f32) -> f32 {
+    sin(x as f64) as f32
+}
+
+pub const fn cosf(x: f32) -> f32 {
+    cos(x as f64) as f32
+}
+
+pub const fn tanf(x: f32) -> f32 {
+    tan(x as f64) as f32
+}
+
+pub const fn atanf(x: f32) -> f32 {
+    atan(x as f64) as f32
+}
+
+pub const fn atan2f(y: f32, x: f32) -> f32 {
+    atan2(y as f64, x as f64) as f32
+}
+
+pub const fn expf(x: f32) -> f32 {
+    exp(x as f64) as f32
+}
+
+pub const fn lnf(x: f32) -> f32 {
+    ln(x as f64) as f32
+}
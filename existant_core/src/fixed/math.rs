@@ -0,0 +1,229 @@
+//! Integer only versions of the transcendental functions behind [`Fixed`](super::Fixed).
+//! Everything in here works on raw `i128`s together with how many of their
+//! bits are fractional, so every storage type shares the same code. Results
+//! come back with `Q` fractional bits, which is more than any storage type
+//! has, and two of them can still be multiplied without overflowing.
+
+pub(crate) const Q: u32 = 62;
+const ONE: i128 = 1 << Q;
+
+/// atan(2^-i), the angle CORDIC turns by on step i.
+const ATAN: [i128; 62] = [
+    3622009729038561421, 2138197195906305897, 1129764675555192497, 573486189672913778,
+    287855953345232185, 144068303048368715, 72051730834756822, 36028064038054493,
+    18014306884351854, 9007187801521084, 4503598195715550, 2251799634728303,
+    1125899884473003, 562949950625109, 281474976361131, 140737488311637,
+    70368744172203, 35184372088149, 17592186044331, 8796093022197,
+    4398046511103, 2199023255552, 1099511627776, 549755813888,
+    274877906944, 137438953472, 68719476736, 34359738368,
+    17179869184, 8589934592, 4294967296, 2147483648,
+    1073741824, 536870912, 268435456, 134217728,
+    67108864, 33554432, 16777216, 8388608,
+    4194304, 2097152, 1048576, 524288,
+    262144, 131072, 65536, 32768,
+    16384, 8192, 4096, 2048,
+    1024, 512, 256, 128,
+    64, 32, 16, 8,
+    4, 2,
+];
+/// 2^(2^-i) for i from 1, one per fraction bit of an exponent.
+const EXP2: [i128; 62] = [
+    6521908912666391106, 5484249825272419512, 5029079263719320435, 4815862801830788490,
+    4712668792719003884, 4661903986662671290, 4636727017470743990, 4624189567668517720,
+    4617933561212708776, 4614808732577250068, 4613247111281068008, 4612466498810092975,
+    4612076242109103707, 4611881126141011236, 4611783571252412754, 4611734794581956353,
+    4611710406440186476, 4611698212417665819, 4611692115418496524, 4611689066921934630,
+    4611687542674409371, 4611686780550835664, 4611686399489096040, 4611686208958238036,
+    4611686113692811986, 4611686066060099699, 4611686042243743740, 4611686030335565807,
+    4611686024381476851, 4611686021404432377, 4611686019915910140, 4611686019171649022,
+    4611686018799518463, 4611686018613453183, 4611686018520420544, 4611686018473904224,
+    4611686018450646064, 4611686018439016984, 4611686018433202444, 4611686018430295174,
+    4611686018428841539, 4611686018428114721, 4611686018427751313, 4611686018427569608,
+    4611686018427478756, 4611686018427433330, 4611686018427410617, 4611686018427399261,
+    4611686018427393582, 4611686018427390743, 4611686018427389324, 4611686018427388614,
+    4611686018427388259, 4611686018427388081, 4611686018427387993, 4611686018427387948,
+    4611686018427387926, 4611686018427387915, 4611686018427387910, 4611686018427387907,
+    4611686018427387905, 4611686018427387905,
+];
+/// 1/Π sqrt(1 + 2^-2i), which undoes the stretch of the CORDIC rotations.
+const CORDIC_GAIN: i128 = 2800459870029452954;
+
+// The constants below have `Q` fractional bits, like everything else here.
+pub(crate) const PI: i128 = 14488038916154245685;
+pub(crate) const TAU: i128 = 28976077832308491370;
+
+pub(crate) const PHI: i128 = 7461864723258187525;
+pub(crate) const EGAMMA: i128 = 2661937411443667433;
+pub(crate) const FRAC_PI_2: i128 = 7244019458077122842;
+pub(crate) const FRAC_PI_3: i128 = 4829346305384748562;
+pub(crate) const FRAC_PI_4: i128 = 3622009729038561421;
+pub(crate) const FRAC_PI_6: i128 = 2414673152692374281;
+pub(crate) const FRAC_PI_8: i128 = 1811004864519280711;
+pub(crate) const FRAC_1_PI: i128 = 1467945251641000613;
+pub(crate) const FRAC_1_SQRT_PI: i128 = 2601865214189558307;
+pub(crate) const FRAC_2_PI: i128 = 2935890503282001226;
+pub(crate) const FRAC_2_SQRT_PI: i128 = 5203730428379116615;
+pub(crate) const SQRT_2: i128 = 6521908912666391106;
+pub(crate) const FRAC_1_SQRT_2: i128 = 3260954456333195553;
+pub(crate) const SQRT_3: i128 = 7987674492471257551;
+pub(crate) const FRAC_1_SQRT_3: i128 = 2662558164157085850;
+pub(crate) const E: i128 = 12535862302449814171;
+pub(crate) const LOG2_E: i128 = 6653256548922161246;
+pub(crate) const LOG2_10: i128 = 15319689349413178110;
+pub(crate) const LOG10_E: i128 = 2002829790073392691;
+pub(crate) const LOG10_2: i128 = 1388255822130839283;
+pub(crate) const LN_2: i128 = 3196577161300663915;
+pub(crate) const LN_10: i128 = 10618799479599967255;
+pub(crate) const DEGREES_PER_RADIAN: i128 = 264230145295380110385;
+pub(crate) const RADIANS_PER_DEGREE: i128 = 80489105089745809;
+
+/// Moves `value` from `from` fractional bits to `to`, rounding to nearest
+/// when bits get dropped.
+pub(crate) const fn rescale(value: i128, from: u32, to: u32) -> i128 {
+    if to >= from {
+        value << (to - from)
+    } else if from - to >= 127 {
+        0
+    } else {
+        let shift = from - to;
+        (value + (1 << (shift - 1))) >> shift
+    }
+}
+
+/// `a * b` with `Q` fractional bits dropped from the product. `a` can be as
+/// big as an `i128` allows, as long as `b` fits in 64 bits.
+pub(crate) const fn mul(a: i128, b: i128) -> i128 {
+    (a >> Q) * b + (((a & (ONE - 1)) * b) >> Q)
+}
+
+/// Square root rounded to the nearest integer.
+pub(crate) fn sqrt(value: u128) -> u128 {
+    let root = value.isqrt();
+    // Halfway between root and root + 1 squares to root² + root + 1/4.
+    if value - root * root > root { root + 1 } else { root }
+}
+
+/// Sine and cosine of `angle`, which has `frac` fractional bits.
+pub(crate) fn sin_cos(angle: i128, frac: u32) -> (i128, i128) {
+    // CORDIC only converges within about ±1.74, so the angle is wrapped into
+    // [-π, π] and then turned by π into [-π/2, π/2], which flips both signs.
+    let mut angle = rescale(angle, frac, Q) % TAU;
+    if angle > PI {
+        angle -= TAU;
+    } else if angle < -PI {
+        angle += TAU;
+    }
+    let flip = angle.abs() > FRAC_PI_2;
+    if angle > FRAC_PI_2 {
+        angle -= PI;
+    } else if angle < -FRAC_PI_2 {
+        angle += PI;
+    }
+    let (mut x, mut y) = (CORDIC_GAIN, 0);
+    for (i, &step) in ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if angle >= 0 {
+            x -= dx;
+            y += dy;
+            angle -= step;
+        } else {
+            x += dx;
+            y -= dy;
+            angle += step;
+        }
+    }
+    if flip { (-y, -x) } else { (y, x) }
+}
+
+/// Angle of the point `(x, y)`, which share the same fractional bits.
+pub(crate) fn atan2(y: i128, x: i128) -> i128 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+    // The left half plane is turned by π onto the right one, where CORDIC
+    // converges.
+    let (mut x, mut y, mut angle) = if x < 0 {
+        (-x, -y, if y < 0 { -PI } else { PI })
+    } else {
+        (x, y, 0)
+    };
+    // Only the ratio matters, so scale up small points to keep precision.
+    let shift = x.abs().max(y.abs()).leading_zeros() as i32 - (127 - Q as i32);
+    if shift > 0 {
+        (x, y) = (x << shift, y << shift);
+    } else {
+        (x, y) = (x >> -shift, y >> -shift);
+    }
+    for (i, &step) in ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            angle += step;
+        } else {
+            x -= dx;
+            y += dy;
+            angle -= step;
+        }
+    }
+    angle
+}
+
+/// Base 2 logarithm of `value`, which has `frac` fractional bits and has to
+/// be positive.
+pub(crate) fn log2(value: u128, frac: u32) -> i128 {
+    let top = 127 - value.leading_zeros() as i32;
+    // The integer part is where the top bit is, and the fraction comes from
+    // squaring the rest, which lands in [1, 4) and doubles the logarithm.
+    let mut mantissa = if top >= Q as i32 {
+        value >> (top - Q as i32)
+    } else {
+        value << (Q as i32 - top)
+    };
+    let mut result = ((top - frac as i32) as i128) << Q;
+    for bit in (0..Q).rev() {
+        mantissa = (mantissa * mantissa) >> Q;
+        if mantissa >= 2 << Q {
+            mantissa >>= 1;
+            result += 1 << bit;
+        }
+    }
+    result
+}
+
+/// Natural logarithm of `value`, like [`log2`].
+pub(crate) fn ln(value: u128, frac: u32) -> i128 {
+    mul(log2(value, frac), LN_2)
+}
+
+/// 2 to the power of `value`, which has `frac` fractional bits, returned
+/// with `out` fractional bits and saturating at `u128::MAX`.
+pub(crate) fn exp2(value: i128, frac: u32, out: u32) -> u128 {
+    let whole = value >> frac;
+    let fraction = value & ((1 << frac) - 1);
+    let fraction = if frac > Q { fraction >> (frac - Q) } else { fraction << (Q - frac) };
+    let mut result = ONE as u128;
+    for (i, &factor) in EXP2.iter().enumerate() {
+        if fraction & (1 << (Q as usize - 1 - i)) != 0 {
+            result = (result * factor as u128) >> Q;
+        }
+    }
+    let shift = whole + out as i128 - Q as i128;
+    if shift >= 0 {
+        if shift > result.leading_zeros() as i128 { u128::MAX } else { result << shift }
+    } else if shift <= -127 {
+        0
+    } else {
+        let shift = -shift as u32;
+        (result + (1 << (shift - 1))) >> shift
+    }
+}
+
+/// 2 to the power of `log * power`, where `log` has `Q` fractional bits and
+/// `power` has `frac`. Drops the low bits of `log` when the product wouldn't
+/// fit otherwise.
+pub(crate) fn exp2_mul(log: i128, power: i128, frac: u32, out: u32) -> u128 {
+    let bits = 256 - log.unsigned_abs().leading_zeros() - power.unsigned_abs().leading_zeros();
+    let drop = bits.saturating_sub(126).min(Q);
+    exp2((log >> drop) * power, Q + frac - drop, out)
+}
@@ -0,0 +1,679 @@
+//! Binary fixed point numbers, for when results have to be bit for bit the
+//! same on every machine. Every operation, the transcendental ones included,
+//! is done with integer arithmetic only.
+
+mod math;
+
+use core::fmt;
+
+use crate::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FloatBits, FloatConsts, FloatingPoint, FromPrimitive, Identity, IntoPrimitive, Inverse, Multiplication, Signed, Subtraction};
+use math::Q;
+
+/// A number stored as the integer `I` scaled down by 2^`FRAC`, so Q16.16 is
+/// `Fixed<i32, 16>`. `I` can be [`i16`], [`i32`] or [`i64`], and `FRAC` has to
+/// leave room for at least one integer bit besides the sign.
+///
+/// Since these stand in for floats, arithmetic saturates instead of
+/// overflowing, and [`Bounds::MIN`]/[`Bounds::MAX`] play the part of the
+/// infinities. There is no NaN either, so functions outside of their domain
+/// return zero, and dividing by zero gives whichever bound has the sign of
+/// the dividend.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const FRAC: u32> {
+    bits: I,
+}
+
+pub type Q16_16 = Fixed<i32, 16>;
+pub type Q8_24 = Fixed<i32, 24>;
+pub type Q32_32 = Fixed<i64, 32>;
+
+macro_rules! impl_fixed_op {
+    ($int:ty, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<const FRAC: u32> core::ops::$op for Fixed<$int, FRAC> {
+            type Output = Self;
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                let ($a, $b) = (self.bits, rhs.bits);
+                $body
+            }
+        }
+        impl<const FRAC: u32> core::ops::$assign for Fixed<$int, FRAC> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$op::$op_fn(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_fixed_from {
+    ($($from:ident: $primitive:ty),* $(,)?) => {
+        $(
+            fn $from(val: $primitive) -> Self {
+                Self::from_int(i128::try_from(val).unwrap_or(i128::MAX))
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fixed_into {
+    ($($to:ident: $primitive:ty),* $(,)?) => {
+        $(
+            /// Truncates toward zero and saturates, like casting a float.
+            fn $to(self) -> $primitive {
+                let whole = self.bits as i128 / (1 << FRAC);
+                <$primitive>::try_from(whole).unwrap_or(if whole < 0 { <$primitive>::MIN } else { <$primitive>::MAX })
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fixed_consts {
+    ($($name:ident),* $(,)?) => {
+        $(
+            const $name: Self = Self::from_q(math::$name);
+        )*
+    };
+}
+
+macro_rules! impl_fixed {
+    ($($int:ty),*) => {
+        $(
+            impl<const FRAC: u32> Fixed<$int, FRAC> {
+                pub const ZERO: Self = Self { bits: 0 };
+                pub const ONE: Self = {
+                    assert!(FRAC < <$int>::BITS - 1, "a fixed point number needs at least one integer bit");
+                    Self { bits: 1 << FRAC }
+                };
+                /// The number whose underlying integer is `bits`.
+                pub const fn from_bits(bits: $int) -> Self {
+                    Self { bits }
+                }
+                pub const fn to_bits(self) -> $int {
+                    self.bits
+                }
+                const fn saturate(value: i128) -> Self {
+                    if value > <$int>::MAX as i128 {
+                        Self { bits: <$int>::MAX }
+                    } else if value < <$int>::MIN as i128 {
+                        Self { bits: <$int>::MIN }
+                    } else {
+                        Self { bits: value as $int }
+                    }
+                }
+                fn saturate_unsigned(value: u128) -> Self {
+                    Self::saturate(i128::try_from(value).unwrap_or(i128::MAX))
+                }
+                const fn from_q(value: i128) -> Self {
+                    Self::saturate(math::rescale(value, Q, FRAC))
+                }
+                fn from_int(value: i128) -> Self {
+                    Self::saturate(value.saturating_mul(1 << FRAC))
+                }
+                const fn raw(self) -> i128 {
+                    self.bits as i128
+                }
+                const fn one() -> i128 {
+                    Self::ONE.bits as i128
+                }
+                /// Applies the sign of `self` to `magnitude`, which has to be
+                /// positive.
+                fn with_sign(self, magnitude: Self) -> Self {
+                    if self.bits < 0 { -magnitude } else { magnitude }
+                }
+                /// `sqrt(self² + offset)`, where offset has twice the fractional
+                /// bits. The square can't overflow since it's done in a u128.
+                fn sqrt_of_square_plus(self, offset: i128) -> u128 {
+                    let square = (self.raw() * self.raw()) as u128;
+                    math::sqrt(square.saturating_add_signed(offset))
+                }
+                /// `|self|^n` as a 64 bit mantissa and the power of two it's
+                /// scaled by, by squaring. Keeping the exponent apart means
+                /// nothing in between saturates, and the mantissa keeps more
+                /// bits than `I` has. `self` can't be zero.
+                fn pow_magnitude(self, mut n: u32) -> (u128, i64) {
+                    // Keeps the top 64 bits of a product of two mantissas,
+                    // rounded to nearest.
+                    fn mul((a, a_exp): (u128, i64), (b, b_exp): (u128, i64)) -> (u128, i64) {
+                        let product = a * b;
+                        let shift = if product >> 127 == 1 { 64 } else { 63 };
+                        let rounded = (product >> shift) + (product >> (shift - 1) & 1);
+                        if rounded >> 64 == 1 {
+                            (rounded >> 1, a_exp + b_exp + shift as i64 + 1)
+                        } else {
+                            (rounded, a_exp + b_exp + shift as i64)
+                        }
+                    }
+                    let magnitude = self.raw().unsigned_abs();
+                    let shift = magnitude.leading_zeros() - 64;
+                    let mut base = (magnitude << shift, -(FRAC as i64) - shift as i64);
+                    let mut power = (1u128 << 63, -63);
+                    while n > 0 {
+                        if n & 1 == 1 {
+                            power = mul(power, base);
+                        }
+                        n >>= 1;
+                        if n > 0 {
+                            base = mul(base, base);
+                        }
+                    }
+                    power
+                }
+                /// `±mantissa * 2^exponent`, rounded to nearest.
+                fn from_mantissa(mantissa: u128, exponent: i64, negative: bool) -> Self {
+                    let shift = exponent + FRAC as i64;
+                    let magnitude = if shift > 62 {
+                        i128::MAX
+                    } else if shift >= 0 {
+                        (mantissa << shift) as i128
+                    } else if shift > -128 {
+                        (mantissa + (1 << (-shift - 1)) >> -shift) as i128
+                    } else {
+                        0
+                    };
+                    Self::saturate(if negative { -magnitude } else { magnitude })
+                }
+                /// `sqrt(1 - self²)`, the other side of a right triangle with a
+                /// hypotenuse of one. `self` has to be within [-1, 1].
+                fn other_side(self) -> i128 {
+                    math::sqrt(((Self::one() << FRAC) - self.raw() * self.raw()) as u128) as i128
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Display for Fixed<$int, FRAC> {
+                /// Prints the exact value unless a precision is given, which is
+                /// always possible since the fraction is a power of two.
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let magnitude = self.raw().unsigned_abs();
+                    let mask = (1u128 << FRAC) - 1;
+                    let mut whole = magnitude >> FRAC;
+                    let mut fraction = magnitude & mask;
+                    let mut digits = [0u8; 64];
+                    let mut len = 0;
+                    let wanted = Ord::min(f.precision().unwrap_or(digits.len()), digits.len());
+                    while len < wanted && (f.precision().is_some() || fraction != 0) {
+                        fraction *= 10;
+                        digits[len] = (fraction >> FRAC) as u8;
+                        fraction &= mask;
+                        len += 1;
+                    }
+                    // Round half up on what's left over, carrying into the
+                    // integer part if every digit was a 9.
+                    if fraction > mask >> 1 {
+                        let mut carry = true;
+                        for digit in digits[..len].iter_mut().rev() {
+                            *digit += 1;
+                            carry = *digit == 10;
+                            if !carry {
+                                break;
+                            }
+                            *digit = 0;
+                        }
+                        whole += carry as u128;
+                    }
+                    // At most 39 digits for the integer part, a point and the fraction.
+                    let mut buffer = [0u8; 40 + 64];
+                    let mut end = 0;
+                    let mut divisor = 1;
+                    while divisor <= whole / 10 {
+                        divisor *= 10;
+                    }
+                    while divisor != 0 {
+                        buffer[end] = b'0' + (whole / divisor % 10) as u8;
+                        end += 1;
+                        divisor /= 10;
+                    }
+                    if len != 0 {
+                        buffer[end] = b'.';
+                        end += 1;
+                        for &digit in &digits[..len] {
+                            buffer[end] = b'0' + digit;
+                            end += 1;
+                        }
+                    }
+                    let text = core::str::from_utf8(&buffer[..end]).unwrap();
+                    f.pad_integral(self.bits >= 0, "", text)
+                }
+            }
+            impl<const FRAC: u32> fmt::Debug for Fixed<$int, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl<const FRAC: u32> core::ops::Neg for Fixed<$int, FRAC> {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self { bits: self.bits.saturating_neg() }
+                }
+            }
+            impl_fixed_op!($int, Add, add, AddAssign, add_assign, |a, b| Self { bits: a.saturating_add(b) });
+            impl_fixed_op!($int, Sub, sub, SubAssign, sub_assign, |a, b| Self { bits: a.saturating_sub(b) });
+            impl_fixed_op!($int, Mul, mul, MulAssign, mul_assign, |a, b| {
+                Self::saturate(math::rescale(a as i128 * b as i128, 2 * FRAC, FRAC))
+            });
+            impl_fixed_op!($int, Div, div, DivAssign, div_assign, |a, b| {
+                if b == 0 {
+                    return if a == 0 { Self::ZERO } else if a < 0 { Self::MIN } else { Self::MAX };
+                }
+                // Rounds to nearest, by looking at how big the remainder is.
+                let (a, b) = ((a as i128) << FRAC, b as i128);
+                let (quotient, remainder) = (a / b, a % b);
+                let quotient = if 2 * remainder.abs() < b.abs() {
+                    quotient
+                } else if (a < 0) == (b < 0) {
+                    quotient + 1
+                } else {
+                    quotient - 1
+                };
+                Self::saturate(quotient)
+            });
+            impl_fixed_op!($int, Rem, rem, RemAssign, rem_assign, |a, b| {
+                Self { bits: if b == 0 { 0 } else { a.wrapping_rem(b) } }
+            });
+
+            impl<const FRAC: u32> Signed for Fixed<$int, FRAC> {
+                fn is_negative(self) -> bool {
+                    self.bits < 0
+                }
+                fn is_positive(self) -> bool {
+                    self.bits > 0
+                }
+                fn abs(self) -> Self {
+                    Self { bits: self.bits.saturating_abs() }
+                }
+                fn flip_sign(self) -> Self {
+                    -self
+                }
+            }
+
+            impl<const FRAC: u32> Bounds for Fixed<$int, FRAC> {
+                fn min(self, other: Self) -> Self {
+                    Ord::min(self, other)
+                }
+                fn max(self, other: Self) -> Self {
+                    Ord::max(self, other)
+                }
+                const MIN: Self = Self { bits: <$int>::MIN };
+                const MAX: Self = Self { bits: <$int>::MAX };
+            }
+
+            impl<const FRAC: u32> FromPrimitive for Fixed<$int, FRAC> {
+                impl_fixed_from!(
+                    from_u8: u8, from_u16: u16, from_u32: u32, from_u64: u64, from_u128: u128, from_usize: usize,
+                    from_i8: i8, from_i16: i16, from_i32: i32, from_i64: i64, from_i128: i128, from_isize: isize,
+                );
+                fn from_f32(val: f32) -> Self {
+                    Self::from_f64(val as f64)
+                }
+                /// Rounds to the nearest representable value, saturating at the
+                /// bounds. NaN becomes zero.
+                fn from_f64(val: f64) -> Self {
+                    // Scaling by a power of two is exact, so rounding the result to
+                    // an integer is the only rounding going on.
+                    let scaled = val * (1u64 << FRAC) as f64;
+                    let truncated = scaled as i128;
+                    let rest = scaled - truncated as f64;
+                    Self::saturate(if rest >= 0.5 {
+                        truncated.saturating_add(1)
+                    } else if rest <= -0.5 {
+                        truncated.saturating_sub(1)
+                    } else {
+                        truncated
+                    })
+                }
+            }
+            impl<const FRAC: u32> IntoPrimitive for Fixed<$int, FRAC> {
+                impl_fixed_into!(
+                    to_u8: u8, to_u16: u16, to_u32: u32, to_u64: u64, to_u128: u128, to_usize: usize,
+                    to_i8: i8, to_i16: i16, to_i32: i32, to_i64: i64, to_i128: i128, to_isize: isize,
+                );
+                fn to_f32(self) -> f32 {
+                    self.bits as f32 / (1u64 << FRAC) as f32
+                }
+                fn to_f64(self) -> f64 {
+                    self.bits as f64 / (1u64 << FRAC) as f64
+                }
+            }
+
+            impl<const FRAC: u32> FloatConsts for Fixed<$int, FRAC> {
+                impl_fixed_consts!(
+                    PI, TAU, PHI, EGAMMA, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8,
+                    FRAC_1_PI, FRAC_1_SQRT_PI, FRAC_2_PI, FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2,
+                    SQRT_3, FRAC_1_SQRT_3, E, LOG2_E, LOG2_10, LOG10_E, LOG10_2, LN_2, LN_10,
+                );
+                /// The smallest step between two values.
+                const EPSILON: Self = Self { bits: 1 };
+                /// Same as [`Bounds::MAX`], since there's nothing bigger.
+                const INFINITY: Self = Self { bits: <$int>::MAX };
+            }
+
+            impl<const FRAC: u32> FloatingPoint for Fixed<$int, FRAC> {
+                fn acos(self) -> Self {
+                    if self.abs() > Self::ONE {
+                        return Self::ZERO;
+                    }
+                    Self::from_q(math::atan2(self.other_side(), self.raw()))
+                }
+                fn acosh(self) -> Self {
+                    if self < Self::ONE {
+                        return Self::ZERO;
+                    }
+                    let side = self.sqrt_of_square_plus(-(Self::one() << FRAC));
+                    Self::from_q(math::ln(self.raw() as u128 + side, FRAC))
+                }
+                fn asin(self) -> Self {
+                    if self.abs() > Self::ONE {
+                        return Self::ZERO;
+                    }
+                    Self::from_q(math::atan2(self.raw(), self.other_side()))
+                }
+                fn asinh(self) -> Self {
+                    let side = self.sqrt_of_square_plus(Self::one() << FRAC);
+                    let magnitude = Self::from_q(math::ln(self.raw().unsigned_abs() + side, FRAC));
+                    self.with_sign(magnitude)
+                }
+                fn atan(self) -> Self {
+                    Self::from_q(math::atan2(self.raw(), Self::one()))
+                }
+                fn atan2(self, other: Self) -> Self {
+                    Self::from_q(math::atan2(self.raw(), other.raw()))
+                }
+                fn atanh(self) -> Self {
+                    let one = Self::one();
+                    match self.raw().abs().cmp(&one) {
+                        core::cmp::Ordering::Greater => Self::ZERO,
+                        core::cmp::Ordering::Equal => self.with_sign(Self::MAX),
+                        // ln((1 + x) / (1 - x)) / 2, with the logarithms taken
+                        // apart so the ratio isn't rounded when it's small.
+                        core::cmp::Ordering::Less => {
+                            let up = math::ln((one + self.raw()) as u128, FRAC);
+                            let down = math::ln((one - self.raw()) as u128, FRAC);
+                            Self::from_q((up - down) / 2)
+                        }
+                    }
+                }
+                fn cbrt(self) -> Self {
+                    if self.bits == 0 {
+                        return Self::ZERO;
+                    }
+                    let log = math::log2(self.raw().unsigned_abs(), FRAC) / 3;
+                    self.with_sign(Self::saturate_unsigned(math::exp2(log, Q, FRAC)))
+                }
+                fn ceil(self) -> Self {
+                    Self::saturate(-((-self.raw() >> FRAC) << FRAC))
+                }
+                fn cos(self) -> Self {
+                    self.sin_cos().1
+                }
+                fn exp(self) -> Self {
+                    Self::saturate_unsigned(math::exp2_mul(math::LOG2_E, self.raw(), FRAC, FRAC))
+                }
+                fn exp2(self) -> Self {
+                    Self::saturate_unsigned(math::exp2(self.raw(), FRAC, FRAC))
+                }
+                fn exp_m1(self) -> Self {
+                    let exp = math::exp2_mul(math::LOG2_E, self.raw(), FRAC, FRAC);
+                    Self::saturate(i128::try_from(exp).unwrap_or(i128::MAX) - Self::one())
+                }
+                fn floor(self) -> Self {
+                    Self { bits: (self.bits >> FRAC) << FRAC }
+                }
+                fn fract(self) -> Self {
+                    self - self.trunc()
+                }
+                fn hypot(self, other: Self) -> Self {
+                    Self::saturate_unsigned(self.sqrt_of_square_plus(other.raw() * other.raw()))
+                }
+                fn ln(self) -> Self {
+                    match self.bits {
+                        0 => Self::MIN,
+                        bits if bits < 0 => Self::ZERO,
+                        _ => Self::from_q(math::ln(self.raw() as u128, FRAC)),
+                    }
+                }
+                fn ln_1p(self) -> Self {
+                    (self + Self::ONE).ln()
+                }
+                fn log(self, base: Self) -> Self {
+                    if base.bits <= 0 || self.bits < 0 {
+                        return Self::ZERO;
+                    }
+                    if self.bits == 0 {
+                        return if base > Self::ONE { Self::MIN } else { Self::MAX };
+                    }
+                    let (mut log, mut base) = (math::log2(self.raw() as u128, FRAC), math::log2(base.raw() as u128, FRAC));
+                    if base == 0 {
+                        return Self::ZERO;
+                    }
+                    // Big bases lose a few bits so the remainder can be shifted
+                    // up without overflowing.
+                    while base.unsigned_abs() >= 1 << 64 {
+                        (log, base) = (log >> 1, base >> 1);
+                    }
+                    let (whole, rest) = (log / base, log % base);
+                    Self::saturate(whole.saturating_mul(1 << FRAC).saturating_add((rest << FRAC) / base))
+                }
+                fn log10(self) -> Self {
+                    match self.bits {
+                        0 => Self::MIN,
+                        bits if bits < 0 => Self::ZERO,
+                        _ => Self::from_q(math::mul(math::log2(self.raw() as u128, FRAC), math::LOG10_2)),
+                    }
+                }
+                fn log2(self) -> Self {
+                    match self.bits {
+                        0 => Self::MIN,
+                        bits if bits < 0 => Self::ZERO,
+                        _ => Self::from_q(math::log2(self.raw() as u128, FRAC)),
+                    }
+                }
+                fn powf(self, n: Self) -> Self {
+                    if n.bits == 0 {
+                        return Self::ONE;
+                    }
+                    if self.bits == 0 {
+                        return if n.bits > 0 { Self::ZERO } else { Self::MAX };
+                    }
+                    // Only whole powers of negative numbers are real, and odd
+                    // ones keep the sign.
+                    let whole = n.floor() == n;
+                    if self.bits < 0 && !whole {
+                        return Self::ZERO;
+                    }
+                    let odd = whole && (n.raw() >> FRAC) & 1 == 1;
+                    let log = math::log2(self.raw().unsigned_abs(), FRAC);
+                    let magnitude = Self::saturate_unsigned(math::exp2_mul(log, n.raw(), FRAC, FRAC));
+                    if self.bits < 0 && odd { -magnitude } else { magnitude }
+                }
+                /// By squaring, so unlike going through [`powf`](Self::powf)
+                /// the exponent never saturates.
+                fn powi(self, n: i32) -> Self {
+                    if n == 0 {
+                        return Self::ONE;
+                    }
+                    if self.bits == 0 {
+                        return if n > 0 { Self::ZERO } else { Self::MAX };
+                    }
+                    let negative = self.bits < 0 && n & 1 == 1;
+                    let (mantissa, exponent) = self.pow_magnitude(n.unsigned_abs());
+                    if n > 0 {
+                        Self::from_mantissa(mantissa, exponent, negative)
+                    } else {
+                        // 2^127/mantissa is between 2^63 and 2^64, like the
+                        // mantissa was.
+                        let reciprocal = ((1u128 << 127) + mantissa / 2) / mantissa;
+                        Self::from_mantissa(reciprocal, -exponent - 127, negative)
+                    }
+                }
+                fn recip(self) -> Self {
+                    Self::ONE / self
+                }
+                /// Rounds half way cases away from zero.
+                fn round(self) -> Self {
+                    let half = Self::one() >> 1;
+                    let magnitude = Self::saturate(((self.raw().abs() + half) >> FRAC) << FRAC);
+                    self.with_sign(magnitude)
+                }
+                /// Unlike floats there's only one zero, which is its own sign.
+                fn signum(self) -> Self {
+                    match self.bits {
+                        0 => Self::ZERO,
+                        bits if bits < 0 => -Self::ONE,
+                        _ => Self::ONE,
+                    }
+                }
+                fn sin(self) -> Self {
+                    self.sin_cos().0
+                }
+                fn sinh(self) -> Self {
+                    let magnitude = self.raw().abs();
+                    let up = math::exp2_mul(math::LOG2_E, magnitude, FRAC, FRAC);
+                    let down = math::exp2_mul(math::LOG2_E, -magnitude, FRAC, FRAC);
+                    self.with_sign(Self::saturate_unsigned(up / 2 - down / 2))
+                }
+                fn sqrt(self) -> Self {
+                    if self.bits <= 0 {
+                        return Self::ZERO;
+                    }
+                    Self::saturate_unsigned(math::sqrt((self.raw() as u128) << FRAC))
+                }
+                fn tan(self) -> Self {
+                    let (sin, cos) = math::sin_cos(self.raw(), FRAC);
+                    if cos == 0 {
+                        return if sin < 0 { Self::MIN } else { Self::MAX };
+                    }
+                    Self::from_q((sin << Q) / cos)
+                }
+                fn tanh(self) -> Self {
+                    // Written with e^-2|x|, which can't overflow, as
+                    // (1 - e^-2|x|) / (1 + e^-2|x|).
+                    let one = 1 << Q;
+                    let decay = math::exp2_mul(math::LOG2_E, -2 * self.raw().abs(), FRAC, Q) as i128;
+                    self.with_sign(Self::from_q(((one - decay) << Q) / (one + decay)))
+                }
+                fn to_degrees(self) -> Self {
+                    Self::saturate(math::mul(math::DEGREES_PER_RADIAN, self.raw()))
+                }
+                fn to_radians(self) -> Self {
+                    Self::saturate(math::mul(math::RADIANS_PER_DEGREE, self.raw()))
+                }
+                fn trunc(self) -> Self {
+                    let one = Self::one();
+                    Self::saturate(self.raw() / one * one)
+                }
+                fn sin_cos(self) -> (Self, Self)
+                    where Self: Sized {
+                    let (sin, cos) = math::sin_cos(self.raw(), FRAC);
+                    (Self::from_q(sin), Self::from_q(cos))
+                }
+                fn is_finite(self) -> bool {
+                    true
+                }
+                fn is_infinite(self) -> bool {
+                    false
+                }
+                fn is_nan(self) -> bool {
+                    false
+                }
+                fn is_normal(self) -> bool {
+                    self.bits != 0
+                }
+                fn is_subnormal(self) -> bool {
+                    false
+                }
+                fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.bits.cmp(&other.bits)
+                }
+                fn next_up(self) -> Self {
+                    Self { bits: self.bits.saturating_add(1) }
+                }
+                fn next_down(self) -> Self {
+                    Self { bits: self.bits.saturating_sub(1) }
+                }
+                // The product is kept at full width, so it only saturates if the sum does.
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    let product = self.bits as i128 * a.bits as i128 + ((b.bits as i128) << FRAC);
+                    Self::saturate(math::rescale(product, 2 * FRAC, FRAC))
+                }
+                fn ldexp(self, exponent: i32) -> Self {
+                    let raw = self.bits as i128;
+                    if exponent >= 0 {
+                        let shift = Ord::min(exponent as u32, <$int>::BITS);
+                        Self::saturate(raw.saturating_mul(1i128 << shift))
+                    } else {
+                        Self::saturate(math::rescale(raw, Ord::min(exponent.unsigned_abs(), <$int>::BITS + 1), 0))
+                    }
+                }
+            }
+            impl<const FRAC: u32> FloatBits for Fixed<$int, FRAC> {
+                type Bits = $int;
+                fn to_bits(self) -> $int {
+                    self.bits
+                }
+                fn from_bits(bits: $int) -> Self {
+                    Self { bits }
+                }
+            }
+
+            impl<const FRAC: u32> Identity<Addition> for Fixed<$int, FRAC> {
+                const IDENTITY: Self = Self::ZERO;
+                fn is_identity(&self) -> bool {
+                    self.bits == 0
+                }
+            }
+            impl<const FRAC: u32> Identity<Subtraction> for Fixed<$int, FRAC> {
+                const IDENTITY: Self = Self::ZERO;
+                fn is_identity(&self) -> bool {
+                    self.bits == 0
+                }
+            }
+            impl<const FRAC: u32> Identity<Multiplication> for Fixed<$int, FRAC> {
+                const IDENTITY: Self = Self::ONE;
+                fn is_identity(&self) -> bool {
+                    *self == Self::ONE
+                }
+            }
+            impl<const FRAC: u32> Identity<Division> for Fixed<$int, FRAC> {
+                const IDENTITY: Self = Self::ONE;
+                fn is_identity(&self) -> bool {
+                    *self == Self::ONE
+                }
+            }
+            impl<const FRAC: u32> Absorption<Multiplication> for Fixed<$int, FRAC> {
+                const ABSORBING: Self = Self::ZERO;
+                fn is_absorber(&self) -> bool {
+                    self.bits == 0
+                }
+            }
+            impl<const FRAC: u32> Inverse<Addition> for Fixed<$int, FRAC> {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+            impl<const FRAC: u32> Inverse<Subtraction> for Fixed<$int, FRAC> {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+            impl<const FRAC: u32> Inverse<Multiplication> for Fixed<$int, FRAC> {
+                fn inverse(self) -> Self {
+                    self.recip()
+                }
+            }
+            impl<const FRAC: u32> AssociativeOver<Addition> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> CommutativeOver<Addition> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> ClosedUnder<Addition> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> AssociativeOver<Multiplication> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> CommutativeOver<Multiplication> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> ClosedUnder<Multiplication> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> AssociativeOver<Subtraction> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> ClosedUnder<Subtraction> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> ClosedUnder<Division> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> Distributive<Multiplication, Addition> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> Distributive<Multiplication, Subtraction> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> Distributive<Division, Addition> for Fixed<$int, FRAC> {}
+            impl<const FRAC: u32> Distributive<Division, Subtraction> for Fixed<$int, FRAC> {}
+        )*
+    };
+}
+
+impl_fixed!(i16, i32, i64);
@@ -0,0 +1,372 @@
+//! The 16 bit floats from the `half` crate, [`f16`] and [`bf16`], for vertex
+//! and texture data. Math on them is done in f32 and rounded back, which for
+//! the basic operations and sqrt gives the same result as doing it directly,
+//! since an f32 has more than twice the precision of either.
+
+pub use half::{bf16, f16};
+
+use crate::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FloatBits, FloatConsts, FloatingPoint, FromPrimitive, Identity, IntoPrimitive, Inverse, Multiplication, Signed, Subtraction};
+
+/// `value` as an f32, but with the last bit set whenever anything was cut
+/// off. Rounding that to 16 bits gives the same result as rounding `value`
+/// directly, which `half` doesn't do for f64s.
+const fn round_to_odd(value: f64) -> f32 {
+    let rounded = value as f32;
+    if !rounded.is_finite() || rounded as f64 == value || rounded.to_bits() & 1 == 1 {
+        return rounded;
+    }
+    let truncated = if (rounded as f64).abs() > value.abs() { rounded.to_bits() - 1 } else { rounded.to_bits() };
+    f32::from_bits(truncated | 1)
+}
+
+/// The magnitude of an integer as an f64, where everything past the 53 bits
+/// that fit is folded into the last one, so [`round_to_odd`] sees it.
+fn sticky_f64(magnitude: u128) -> f64 {
+    let shift = (128 - magnitude.leading_zeros()).saturating_sub(53);
+    if shift == 0 {
+        return magnitude as f64;
+    }
+    let kept = magnitude >> shift | (magnitude & ((1 << shift) - 1) != 0) as u128;
+    kept as f64 * (1u128 << shift) as f64
+}
+
+/// `a + b` rounded to odd, through Knuth's two-sum for the exact error of
+/// the addition. Like [`round_to_odd`], rounding this again to 16 bits gives
+/// the same result as rounding the exact sum.
+fn sum_to_odd(a: f64, b: f64) -> f64 {
+    let sum = a + b;
+    let a_part = sum - b;
+    let error = (a - a_part) + (b - (sum - a_part));
+    if error == 0.0 || !sum.is_finite() || sum.to_bits() & 1 == 1 {
+        return sum;
+    }
+    // The exact sum is between `sum` and its neighbour on the side of the
+    // error, and that neighbour is the odd one.
+    let away_from_zero = (error > 0.0) == (sum > 0.0);
+    f64::from_bits(if away_from_zero { sum.to_bits() + 1 } else { sum.to_bits() - 1 })
+}
+
+macro_rules! via_f32 {
+    ($structure:ident, $($method:ident),*) => {
+        $(
+            fn $method(self) -> Self {
+                $structure::from_f32(<f32 as FloatingPoint>::$method($structure::to_f32(self)))
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float16 {
+    ($($structure:ident),*) => {
+        $(
+            impl Signed for $structure {
+                fn flip_sign(self) -> Self {
+                    -self
+                }
+                fn is_negative(self) -> bool {
+                    $structure::is_sign_negative(self)
+                }
+                fn is_positive(self) -> bool {
+                    $structure::is_sign_positive(self)
+                }
+                fn abs(self) -> Self {
+                    $structure::from_bits(self.to_bits() & 0x7FFF)
+                }
+            }
+
+            impl Bounds for $structure {
+                fn min(self, other: Self) -> Self {
+                    $structure::min(self, other)
+                }
+                fn max(self, other: Self) -> Self {
+                    $structure::max(self, other)
+                }
+                const MIN: Self = $structure::MIN;
+                const MAX: Self = $structure::MAX;
+            }
+
+            impl AssociativeOver<Addition> for $structure {}
+            impl CommutativeOver<Addition> for $structure {}
+            impl ClosedUnder<Addition> for $structure {}
+            impl AssociativeOver<Multiplication> for $structure {}
+            impl CommutativeOver<Multiplication> for $structure {}
+            impl ClosedUnder<Multiplication> for $structure {}
+            impl AssociativeOver<Subtraction> for $structure {}
+            impl ClosedUnder<Subtraction> for $structure {}
+            impl ClosedUnder<Division> for $structure {}
+            impl Distributive<Multiplication, Addition> for $structure {}
+            impl Distributive<Multiplication, Subtraction> for $structure {}
+            impl Distributive<Division, Addition> for $structure {}
+            impl Distributive<Division, Subtraction> for $structure {}
+            impl Identity<Addition> for $structure {
+                const IDENTITY: Self = $structure::ZERO;
+                fn is_identity(&self) -> bool {
+                    *self == $structure::ZERO
+                }
+            }
+            impl Identity<Subtraction> for $structure {
+                const IDENTITY: Self = $structure::ZERO;
+                fn is_identity(&self) -> bool {
+                    *self == $structure::ZERO
+                }
+            }
+            impl Identity<Multiplication> for $structure {
+                const IDENTITY: Self = $structure::ONE;
+                fn is_identity(&self) -> bool {
+                    *self == $structure::ONE
+                }
+            }
+            impl Identity<Division> for $structure {
+                const IDENTITY: Self = $structure::ONE;
+                fn is_identity(&self) -> bool {
+                    *self == $structure::ONE
+                }
+            }
+            impl Absorption<Multiplication> for $structure {
+                const ABSORBING: Self = $structure::ZERO;
+                fn is_absorber(&self) -> bool {
+                    *self == $structure::ZERO
+                }
+            }
+            impl Inverse<Addition> for $structure {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+            impl Inverse<Subtraction> for $structure {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+            impl Inverse<Multiplication> for $structure {
+                fn inverse(self) -> Self {
+                    $structure::ONE / self
+                }
+            }
+
+            impl FromPrimitive for $structure {
+                fn from_u8(val: u8) -> Self {
+                    $structure::from_f32(val as f32)
+                }
+                fn from_u16(val: u16) -> Self {
+                    $structure::from_f32(val as f32)
+                }
+                fn from_u32(val: u32) -> Self {
+                    Self::from_u128(val as u128)
+                }
+                fn from_u64(val: u64) -> Self {
+                    Self::from_u128(val as u128)
+                }
+                fn from_u128(val: u128) -> Self {
+                    $structure::from_f32(round_to_odd(sticky_f64(val)))
+                }
+                fn from_usize(val: usize) -> Self {
+                    Self::from_u128(val as u128)
+                }
+                fn from_i8(val: i8) -> Self {
+                    $structure::from_f32(val as f32)
+                }
+                fn from_i16(val: i16) -> Self {
+                    $structure::from_f32(val as f32)
+                }
+                fn from_i32(val: i32) -> Self {
+                    Self::from_i128(val as i128)
+                }
+                fn from_i64(val: i64) -> Self {
+                    Self::from_i128(val as i128)
+                }
+                fn from_i128(val: i128) -> Self {
+                    let magnitude = Self::from_u128(val.unsigned_abs());
+                    if val < 0 { -magnitude } else { magnitude }
+                }
+                fn from_isize(val: isize) -> Self {
+                    Self::from_i128(val as i128)
+                }
+                fn from_f32(val: f32) -> Self {
+                    $structure::from_f32(val)
+                }
+                fn from_f64(val: f64) -> Self {
+                    $structure::from_f32(round_to_odd(val))
+                }
+            }
+
+            impl IntoPrimitive for $structure {
+                fn to_u8(self) -> u8 {
+                    $structure::to_f32(self) as u8
+                }
+                fn to_u16(self) -> u16 {
+                    $structure::to_f32(self) as u16
+                }
+                fn to_u32(self) -> u32 {
+                    $structure::to_f32(self) as u32
+                }
+                fn to_u64(self) -> u64 {
+                    $structure::to_f32(self) as u64
+                }
+                fn to_u128(self) -> u128 {
+                    $structure::to_f32(self) as u128
+                }
+                fn to_usize(self) -> usize {
+                    $structure::to_f32(self) as usize
+                }
+                fn to_i8(self) -> i8 {
+                    $structure::to_f32(self) as i8
+                }
+                fn to_i16(self) -> i16 {
+                    $structure::to_f32(self) as i16
+                }
+                fn to_i32(self) -> i32 {
+                    $structure::to_f32(self) as i32
+                }
+                fn to_i64(self) -> i64 {
+                    $structure::to_f32(self) as i64
+                }
+                fn to_i128(self) -> i128 {
+                    $structure::to_f32(self) as i128
+                }
+                fn to_isize(self) -> isize {
+                    $structure::to_f32(self) as isize
+                }
+                fn to_f32(self) -> f32 {
+                    $structure::to_f32(self)
+                }
+                fn to_f64(self) -> f64 {
+                    $structure::to_f64(self)
+                }
+            }
+
+            impl FloatConsts for $structure {
+                const PI: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::PI));
+                const TAU: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::TAU));
+                const PHI: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::PHI));
+                const EGAMMA: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::EGAMMA));
+                const FRAC_PI_2: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_PI_2));
+                const FRAC_PI_3: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_PI_3));
+                const FRAC_PI_4: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_PI_4));
+                const FRAC_PI_6: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_PI_6));
+                const FRAC_PI_8: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_PI_8));
+                const FRAC_1_PI: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_1_PI));
+                const FRAC_1_SQRT_PI: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_1_SQRT_PI));
+                const FRAC_2_PI: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_2_PI));
+                const FRAC_2_SQRT_PI: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_2_SQRT_PI));
+                const SQRT_2: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::SQRT_2));
+                const FRAC_1_SQRT_2: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_1_SQRT_2));
+                const SQRT_3: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::SQRT_3));
+                const FRAC_1_SQRT_3: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::FRAC_1_SQRT_3));
+                const E: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::E));
+                const LOG2_E: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::LOG2_E));
+                const LOG2_10: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::LOG2_10));
+                const LOG10_E: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::LOG10_E));
+                const LOG10_2: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::LOG10_2));
+                const LN_2: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::LN_2));
+                const LN_10: Self = $structure::from_f32_const(round_to_odd(<f64 as FloatConsts>::LN_10));
+                const EPSILON: Self = $structure::EPSILON;
+                const INFINITY: Self = $structure::INFINITY;
+            }
+
+            impl FloatingPoint for $structure {
+                via_f32!($structure, acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, exp, exp2, exp_m1, floor, fract, ln, ln_1p, log10, log2, recip, round, sin, sinh, sqrt, tan, tanh, to_degrees, to_radians, trunc);
+                via_f32!($structure, gamma, ln_gamma, digamma, erf, erfc, erf_inv, elliptic_k, elliptic_e);
+                fn atan2(self, other: Self) -> Self {
+                    $structure::from_f32(FloatingPoint::atan2($structure::to_f32(self), $structure::to_f32(other)))
+                }
+                fn hypot(self, other: Self) -> Self {
+                    $structure::from_f32(FloatingPoint::hypot($structure::to_f32(self), $structure::to_f32(other)))
+                }
+                fn log(self, base: Self) -> Self {
+                    $structure::from_f32(FloatingPoint::log($structure::to_f32(self), $structure::to_f32(base)))
+                }
+                fn powf(self, n: Self) -> Self {
+                    $structure::from_f32(FloatingPoint::powf($structure::to_f32(self), $structure::to_f32(n)))
+                }
+                fn powi(self, n: i32) -> Self {
+                    $structure::from_f32(FloatingPoint::powi($structure::to_f32(self), n))
+                }
+                fn beta(self, b: Self) -> Self {
+                    $structure::from_f32(FloatingPoint::beta($structure::to_f32(self), $structure::to_f32(b)))
+                }
+                fn incomplete_beta(self, a: Self, b: Self) -> Self {
+                    $structure::from_f32(FloatingPoint::incomplete_beta($structure::to_f32(self), $structure::to_f32(a), $structure::to_f32(b)))
+                }
+                fn bessel_j(self, n: i32) -> Self {
+                    $structure::from_f32(FloatingPoint::bessel_j($structure::to_f32(self), n))
+                }
+                fn bessel_y(self, n: i32) -> Self {
+                    $structure::from_f32(FloatingPoint::bessel_y($structure::to_f32(self), n))
+                }
+                fn signum(self) -> Self {
+                    $structure::signum(self)
+                }
+                fn sin_cos(self) -> (Self, Self)
+                        where Self: Sized {
+                    let (sin, cos) = FloatingPoint::sin_cos($structure::to_f32(self));
+                    ($structure::from_f32(sin), $structure::from_f32(cos))
+                }
+                fn is_finite(self) -> bool {
+                    $structure::is_finite(self)
+                }
+                fn is_infinite(self) -> bool {
+                    $structure::is_infinite(self)
+                }
+                fn is_nan(self) -> bool {
+                    $structure::is_nan(self)
+                }
+                fn is_normal(self) -> bool {
+                    $structure::is_normal(self)
+                }
+                fn is_subnormal(self) -> bool {
+                    matches!($structure::classify(self), core::num::FpCategory::Subnormal)
+                }
+                fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    $structure::total_cmp(self, other)
+                }
+                fn next_up(self) -> Self {
+                    let bits = self.to_bits();
+                    if self.is_nan() || bits == $structure::INFINITY.to_bits() {
+                        return self;
+                    }
+                    let next = if bits & 0x7FFF == 0 {
+                        1
+                    } else if bits & 0x8000 == 0 {
+                        bits + 1
+                    } else {
+                        bits - 1
+                    };
+                    $structure::from_bits(next)
+                }
+                fn next_down(self) -> Self {
+                    -(-self).next_up()
+                }
+                // The product of two of these is exact in an f64, so the sum
+                // is the only thing that rounds before the result does.
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    $structure::from_f32(round_to_odd(sum_to_odd(self.to_f64() * a.to_f64(), b.to_f64())))
+                }
+                fn ldexp(self, n: i32) -> Self {
+                    $structure::from_f32(FloatingPoint::ldexp($structure::to_f32(self), n))
+                }
+                fn frexp(self) -> (Self, i32) {
+                    let (mantissa, exponent) = FloatingPoint::frexp($structure::to_f32(self));
+                    ($structure::from_f32(mantissa), exponent)
+                }
+                fn min_num(self, other: Self) -> Self {
+                    $structure::min(self, other)
+                }
+                fn max_num(self, other: Self) -> Self {
+                    $structure::max(self, other)
+                }
+            }
+            impl FloatBits for $structure {
+                type Bits = u16;
+                fn to_bits(self) -> u16 {
+                    $structure::to_bits(self)
+                }
+                fn from_bits(bits: u16) -> Self {
+                    $structure::from_bits(bits)
+                }
+            }
+        )*
+    };
+}
+
+impl_float16!(f16, bf16);
@@ -0,0 +1,763 @@
+use core::{cmp::Ordering, fmt};
+
+use crate::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FloatBits, FloatConsts, FloatingPoint, FromPrimitive, Identity, IntoPrimitive, Inverse, Multiplication, Signed, Subtraction};
+
+/// A closed range of reals `[lo, hi]` that is guaranteed to hold the exact
+/// result of whatever was computed with it. Every bound is rounded outward,
+/// so running code generic over [`FloatingPoint`] on intervals gives a
+/// conservative enclosure of the answer, which is what robust predicates and
+/// culling need.
+///
+/// Implemented for [`f32`] and [`f64`] bounds. Sums and differences are as
+/// tight as possible, while everything else is widened by a step or two
+/// instead of switching rounding modes, two for the transcendental functions
+/// since the math library can be off by an ulp.
+///
+/// `==` means the same set. The comparison operators answer whether the
+/// relation holds for certain, so `a < b` when every value of `a` is below
+/// every value of `b`, see the `certainly_*` and `possibly_*` methods for
+/// the rest. That makes two equal intervals unordered unless they're points,
+/// since the values they stand for needn't be the same, so unlike most types
+/// `a == b` doesn't give `a <= b`.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T: Copy> Interval<T> {
+    pub const fn lo(&self) -> T {
+        self.lo
+    }
+    pub const fn hi(&self) -> T {
+        self.hi
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.lo, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.hi, f)?;
+        f.write_str("]")
+    }
+}
+impl<T: fmt::Display> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+macro_rules! impl_interval_from {
+    ($structure:ident, $($from:ident, $to:ident, $ty:ty);*) => {
+        impl FromPrimitive for Interval<$structure> {
+            $(
+                fn $from(val: $ty) -> Self {
+                    let x = <$structure as FromPrimitive>::$from(val);
+                    // The cast back truncates and saturates, so this only
+                    // passes when `x` is exactly `val`.
+                    if x.$to() == val && val != <$ty>::MAX { Self::point(x) } else { Self { lo: x.next_down(), hi: x.next_up() } }
+                }
+            )*
+            fn from_f32(val: f32) -> Self {
+                let x = val as $structure;
+                if x as f32 == val || val.is_nan() { Self::point(x) } else { Self { lo: x.next_down(), hi: x.next_up() } }
+            }
+            fn from_f64(val: f64) -> Self {
+                let x = val as $structure;
+                if x as f64 == val || val.is_nan() { Self::point(x) } else { Self { lo: x.next_down(), hi: x.next_up() } }
+            }
+        }
+        impl IntoPrimitive for Interval<$structure> {
+            $(
+                fn $to(self) -> $ty {
+                    self.midpoint().$to()
+                }
+            )*
+            fn to_f32(self) -> f32 {
+                self.midpoint() as f32
+            }
+            fn to_f64(self) -> f64 {
+                self.midpoint() as f64
+            }
+        }
+    };
+}
+
+macro_rules! impl_interval_consts {
+    ($structure:ident, $($name:ident),*) => {
+        impl FloatConsts for Interval<$structure> {
+            $(
+                const $name: Self = Self { lo: <$structure as FloatConsts>::$name.next_down(), hi: <$structure as FloatConsts>::$name.next_up() };
+            )*
+            const EPSILON: Self = Self::point(<$structure as FloatConsts>::EPSILON);
+            const INFINITY: Self = Self::point(<$structure as FloatConsts>::INFINITY);
+        }
+    };
+}
+
+macro_rules! impl_interval {
+    ($($structure:ident),*) => {
+        $(
+            impl Interval<$structure> {
+                pub const ZERO: Self = Self::point(0.0);
+                pub const ONE: Self = Self::point(1.0);
+                /// Every real, along with the infinities.
+                pub const ENTIRE: Self = Self { lo: $structure::NEG_INFINITY, hi: $structure::INFINITY };
+                /// What comes out of a function evaluated fully outside of its domain.
+                pub const NAN: Self = Self { lo: $structure::NAN, hi: $structure::NAN };
+                /// Steps the transcendental functions are widened by.
+                const LIBRARY_STEPS: u32 = 2;
+
+                /// # Panics
+                /// When `lo` is greater than `hi`.
+                pub const fn new(lo: $structure, hi: $structure) -> Self {
+                    assert!(lo <= hi || lo.is_nan() || hi.is_nan(), "the lower bound of an interval can't be above the upper bound");
+                    Self { lo, hi }
+                }
+                /// The interval holding just `x`.
+                pub const fn point(x: $structure) -> Self {
+                    Self { lo: x, hi: x }
+                }
+                pub const fn is_point(&self) -> bool {
+                    self.lo == self.hi
+                }
+                pub const fn contains(&self, x: $structure) -> bool {
+                    self.lo <= x && x <= self.hi
+                }
+                /// Whether the two have a value in common.
+                pub const fn overlaps(&self, other: &Self) -> bool {
+                    self.lo <= other.hi && other.lo <= self.hi
+                }
+                /// The smallest interval holding both.
+                pub const fn hull(self, other: Self) -> Self {
+                    Self { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) }
+                }
+                pub const fn intersection(self, other: Self) -> Option<Self> {
+                    if self.overlaps(&other) {
+                        Some(Self { lo: self.lo.max(other.lo), hi: self.hi.min(other.hi) })
+                    } else {
+                        None
+                    }
+                }
+                /// The value in the middle, or zero for [`Self::ENTIRE`].
+                pub fn midpoint(&self) -> $structure {
+                    if self.lo == $structure::NEG_INFINITY && self.hi == $structure::INFINITY {
+                        0.0
+                    } else {
+                        self.lo * 0.5 + self.hi * 0.5
+                    }
+                }
+                /// `hi - lo`, rounded up.
+                pub fn width(&self) -> $structure {
+                    Self::add_up(self.hi, -self.lo)
+                }
+                /// The smallest absolute value in the interval.
+                pub fn mignitude(&self) -> $structure {
+                    if self.lo > 0.0 { self.lo } else if self.hi < 0.0 { -self.hi } else { 0.0 }
+                }
+                /// The largest absolute value in the interval.
+                pub fn magnitude(&self) -> $structure {
+                    self.lo.abs().max(self.hi.abs())
+                }
+
+                pub const fn certainly_lt(&self, other: &Self) -> bool {
+                    self.hi < other.lo
+                }
+                pub const fn certainly_le(&self, other: &Self) -> bool {
+                    self.hi <= other.lo
+                }
+                pub const fn certainly_gt(&self, other: &Self) -> bool {
+                    other.certainly_lt(self)
+                }
+                pub const fn certainly_ge(&self, other: &Self) -> bool {
+                    other.certainly_le(self)
+                }
+                /// Whether both hold the same single value.
+                pub const fn certainly_eq(&self, other: &Self) -> bool {
+                    self.is_point() && self.lo == other.lo && self.hi == other.hi
+                }
+                pub const fn possibly_lt(&self, other: &Self) -> bool {
+                    self.lo < other.hi
+                }
+                pub const fn possibly_le(&self, other: &Self) -> bool {
+                    self.lo <= other.hi
+                }
+                pub const fn possibly_gt(&self, other: &Self) -> bool {
+                    other.possibly_lt(self)
+                }
+                pub const fn possibly_ge(&self, other: &Self) -> bool {
+                    other.possibly_le(self)
+                }
+                pub const fn possibly_eq(&self, other: &Self) -> bool {
+                    self.overlaps(other)
+                }
+
+                /// `a + b` rounded down, using the exact error of the sum to
+                /// only step when it was rounded up.
+                fn add_down(a: $structure, b: $structure) -> $structure {
+                    let sum = a + b;
+                    if !sum.is_finite() {
+                        return if sum == $structure::INFINITY && a.is_finite() && b.is_finite() { $structure::MAX } else { sum };
+                    }
+                    let b_part = sum - a;
+                    let error = (a - (sum - b_part)) + (b - b_part);
+                    if error < 0.0 { sum.next_down() } else { sum }
+                }
+                fn add_up(a: $structure, b: $structure) -> $structure {
+                    -Self::add_down(-a, -b)
+                }
+                fn product_down(a: $structure, b: $structure) -> $structure {
+                    if a == 0.0 || b == 0.0 { 0.0 } else { (a * b).next_down() }
+                }
+                fn product_up(a: $structure, b: $structure) -> $structure {
+                    if a == 0.0 || b == 0.0 { 0.0 } else { (a * b).next_up() }
+                }
+                fn quotient_down(a: $structure, b: $structure) -> $structure {
+                    if a == 0.0 { 0.0 } else { (a / b).next_down() }
+                }
+                fn quotient_up(a: $structure, b: $structure) -> $structure {
+                    if a == 0.0 { 0.0 } else { (a / b).next_up() }
+                }
+                /// `[lo, hi]` pushed out by `steps` floats on both sides.
+                fn outward(mut lo: $structure, mut hi: $structure, steps: u32) -> Self {
+                    for _ in 0..steps {
+                        lo = lo.next_down();
+                        hi = hi.next_up();
+                    }
+                    Self { lo, hi }
+                }
+                /// Encloses a function that only ever goes up.
+                fn increasing(self, f: impl Fn($structure) -> $structure) -> Self {
+                    Self::outward(f(self.lo), f(self.hi), Self::LIBRARY_STEPS)
+                }
+                /// `self` to the whole power `n`. The power is taken in f64,
+                /// which holds every exponent that gets here exactly, so it
+                /// can't lose its parity the way an f32 exponent past 2^24
+                /// would.
+                fn whole_power(self, n: f64, odd: bool) -> Self {
+                    if n == 0.0 {
+                        return Self::ONE;
+                    }
+                    // Odd powers keep the sign and go up everywhere, even
+                    // ones only depend on the magnitude.
+                    let base = if odd { self } else { self.abs() };
+                    let exponent = n.abs();
+                    let mut power = base.increasing(|x| FloatingPoint::powf(f64::from(x), exponent) as $structure);
+                    if !odd {
+                        power = power.clamp_to(0.0, $structure::INFINITY);
+                    }
+                    if n < 0.0 { power.recip() } else { power }
+                }
+                fn decreasing(self, f: impl Fn($structure) -> $structure) -> Self {
+                    Self::outward(f(self.hi), f(self.lo), Self::LIBRARY_STEPS)
+                }
+                fn clamp_to(self, lo: $structure, hi: $structure) -> Self {
+                    Self { lo: self.lo.max(lo), hi: self.hi.min(hi) }
+                }
+                /// Whether `phase + 2kπ` could be inside for some `k`. The
+                /// slack covers the rounding of the test itself, and only ever
+                /// answers yes too often.
+                fn may_contain_phase(&self, phase: $structure) -> bool {
+                    let tau = <$structure as FloatConsts>::TAU;
+                    let slack = (self.magnitude() + 1.0) * 4.0 * $structure::EPSILON;
+                    let k = FloatingPoint::ceil((self.lo - slack - phase) / tau);
+                    phase + k * tau <= self.hi + slack
+                }
+            }
+
+            impl PartialOrd for Interval<$structure> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    if self.is_point() && self == other {
+                        Some(Ordering::Equal)
+                    } else if self.certainly_lt(other) {
+                        Some(Ordering::Less)
+                    } else if self.certainly_gt(other) {
+                        Some(Ordering::Greater)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl core::ops::Add for Interval<$structure> {
+                type Output = Self;
+                fn add(self, rhs: Self) -> Self::Output {
+                    Self { lo: Self::add_down(self.lo, rhs.lo), hi: Self::add_up(self.hi, rhs.hi) }
+                }
+            }
+            impl core::ops::Sub for Interval<$structure> {
+                type Output = Self;
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Self { lo: Self::add_down(self.lo, -rhs.hi), hi: Self::add_up(self.hi, -rhs.lo) }
+                }
+            }
+            impl core::ops::Mul for Interval<$structure> {
+                type Output = Self;
+                fn mul(self, rhs: Self) -> Self::Output {
+                    let corners = [(self.lo, rhs.lo), (self.lo, rhs.hi), (self.hi, rhs.lo), (self.hi, rhs.hi)];
+                    Self {
+                        lo: corners.iter().map(|&(a, b)| Self::product_down(a, b)).fold($structure::INFINITY, $structure::min),
+                        hi: corners.iter().map(|&(a, b)| Self::product_up(a, b)).fold($structure::NEG_INFINITY, $structure::max),
+                    }
+                }
+            }
+            impl core::ops::Div for Interval<$structure> {
+                type Output = Self;
+                fn div(self, rhs: Self) -> Self::Output {
+                    if rhs.contains(0.0) {
+                        return Self::ENTIRE;
+                    }
+                    let corners = [(self.lo, rhs.lo), (self.lo, rhs.hi), (self.hi, rhs.lo), (self.hi, rhs.hi)];
+                    Self {
+                        lo: corners.iter().map(|&(a, b)| Self::quotient_down(a, b)).fold($structure::INFINITY, $structure::min),
+                        hi: corners.iter().map(|&(a, b)| Self::quotient_up(a, b)).fold($structure::NEG_INFINITY, $structure::max),
+                    }
+                }
+            }
+            impl core::ops::Rem for Interval<$structure> {
+                type Output = Self;
+                fn rem(self, rhs: Self) -> Self::Output {
+                    self - rhs * (self / rhs).trunc()
+                }
+            }
+            impl core::ops::Neg for Interval<$structure> {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self { lo: -self.hi, hi: -self.lo }
+                }
+            }
+            impl core::ops::AddAssign for Interval<$structure> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+            impl core::ops::SubAssign for Interval<$structure> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+            impl core::ops::MulAssign for Interval<$structure> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+            impl core::ops::DivAssign for Interval<$structure> {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+            impl core::ops::RemAssign for Interval<$structure> {
+                fn rem_assign(&mut self, rhs: Self) {
+                    *self = *self % rhs;
+                }
+            }
+
+            impl AssociativeOver<Addition> for Interval<$structure> {}
+            impl CommutativeOver<Addition> for Interval<$structure> {}
+            impl ClosedUnder<Addition> for Interval<$structure> {}
+            impl AssociativeOver<Multiplication> for Interval<$structure> {}
+            impl CommutativeOver<Multiplication> for Interval<$structure> {}
+            impl ClosedUnder<Multiplication> for Interval<$structure> {}
+            impl AssociativeOver<Subtraction> for Interval<$structure> {}
+            impl ClosedUnder<Subtraction> for Interval<$structure> {}
+            impl ClosedUnder<Division> for Interval<$structure> {}
+            impl Distributive<Multiplication, Addition> for Interval<$structure> {}
+            impl Distributive<Multiplication, Subtraction> for Interval<$structure> {}
+            impl Distributive<Division, Addition> for Interval<$structure> {}
+            impl Distributive<Division, Subtraction> for Interval<$structure> {}
+            impl Identity<Addition> for Interval<$structure> {
+                const IDENTITY: Self = Self::ZERO;
+                fn is_identity(&self) -> bool {
+                    *self == Self::ZERO
+                }
+            }
+            impl Identity<Subtraction> for Interval<$structure> {
+                const IDENTITY: Self = Self::ZERO;
+                fn is_identity(&self) -> bool {
+                    *self == Self::ZERO
+                }
+            }
+            impl Identity<Multiplication> for Interval<$structure> {
+                const IDENTITY: Self = Self::ONE;
+                fn is_identity(&self) -> bool {
+                    *self == Self::ONE
+                }
+            }
+            impl Identity<Division> for Interval<$structure> {
+                const IDENTITY: Self = Self::ONE;
+                fn is_identity(&self) -> bool {
+                    *self == Self::ONE
+                }
+            }
+            impl Absorption<Multiplication> for Interval<$structure> {
+                const ABSORBING: Self = Self::ZERO;
+                fn is_absorber(&self) -> bool {
+                    *self == Self::ZERO
+                }
+            }
+            impl Inverse<Addition> for Interval<$structure> {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+            impl Inverse<Subtraction> for Interval<$structure> {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+            impl Inverse<Multiplication> for Interval<$structure> {
+                fn inverse(self) -> Self {
+                    self.recip()
+                }
+            }
+
+            impl Signed for Interval<$structure> {
+                /// Whether every value in it is negative.
+                fn is_negative(self) -> bool {
+                    self.hi.is_sign_negative()
+                }
+                /// Whether every value in it is positive.
+                fn is_positive(self) -> bool {
+                    self.lo.is_sign_positive()
+                }
+                fn abs(self) -> Self {
+                    if self.lo >= 0.0 {
+                        self
+                    } else if self.hi <= 0.0 {
+                        -self
+                    } else {
+                        Self { lo: 0.0, hi: self.magnitude() }
+                    }
+                }
+                fn flip_sign(self) -> Self {
+                    -self
+                }
+            }
+
+            impl Bounds for Interval<$structure> {
+                fn min(self, other: Self) -> Self {
+                    Self { lo: self.lo.min(other.lo), hi: self.hi.min(other.hi) }
+                }
+                fn max(self, other: Self) -> Self {
+                    Self { lo: self.lo.max(other.lo), hi: self.hi.max(other.hi) }
+                }
+                const MIN: Self = Self::point($structure::MIN);
+                const MAX: Self = Self::point($structure::MAX);
+            }
+
+            impl_interval_from!($structure,
+                from_u8, to_u8, u8; from_u16, to_u16, u16; from_u32, to_u32, u32; from_u64, to_u64, u64;
+                from_u128, to_u128, u128; from_usize, to_usize, usize; from_i8, to_i8, i8; from_i16, to_i16, i16;
+                from_i32, to_i32, i32; from_i64, to_i64, i64; from_i128, to_i128, i128; from_isize, to_isize, isize
+            );
+            impl_interval_consts!($structure,
+                PI, TAU, PHI, EGAMMA, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, FRAC_1_PI, FRAC_1_SQRT_PI,
+                FRAC_2_PI, FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2, SQRT_3, FRAC_1_SQRT_3, E, LOG2_E, LOG2_10, LOG10_E,
+                LOG10_2, LN_2, LN_10
+            );
+
+            impl FloatingPoint for Interval<$structure> {
+                fn acos(self) -> Self {
+                    match self.intersection(Self::new(-1.0, 1.0)) {
+                        Some(x) => x.decreasing(FloatingPoint::acos),
+                        None => Self::NAN,
+                    }
+                }
+                fn acosh(self) -> Self {
+                    match self.intersection(Self::new(1.0, $structure::INFINITY)) {
+                        Some(x) => x.increasing(FloatingPoint::acosh).clamp_to(0.0, $structure::INFINITY),
+                        None => Self::NAN,
+                    }
+                }
+                fn asin(self) -> Self {
+                    match self.intersection(Self::new(-1.0, 1.0)) {
+                        Some(x) => x.increasing(FloatingPoint::asin),
+                        None => Self::NAN,
+                    }
+                }
+                fn asinh(self) -> Self {
+                    self.increasing(FloatingPoint::asinh)
+                }
+                fn atan(self) -> Self {
+                    self.increasing(FloatingPoint::atan)
+                }
+                fn atan2(self, other: Self) -> Self {
+                    // Around the origin, or across the cut along the negative
+                    // x axis, any angle can come out.
+                    if other.lo <= 0.0 && self.contains(0.0) {
+                        return <Self as FloatConsts>::PI.hull(-<Self as FloatConsts>::PI);
+                    }
+                    // Otherwise the angle is continuous over the box, which
+                    // puts its extremes on the corners.
+                    let corners = [(self.lo, other.lo), (self.lo, other.hi), (self.hi, other.lo), (self.hi, other.hi)];
+                    let angles = corners.map(|(y, x)| FloatingPoint::atan2(y, x));
+                    Self::outward(
+                        angles.into_iter().fold($structure::INFINITY, $structure::min),
+                        angles.into_iter().fold($structure::NEG_INFINITY, $structure::max),
+                        Self::LIBRARY_STEPS,
+                    )
+                }
+                fn atanh(self) -> Self {
+                    match self.intersection(Self::new(-1.0, 1.0)) {
+                        Some(x) => x.increasing(FloatingPoint::atanh),
+                        None => Self::NAN,
+                    }
+                }
+                fn cbrt(self) -> Self {
+                    self.increasing(FloatingPoint::cbrt)
+                }
+                fn ceil(self) -> Self {
+                    Self { lo: FloatingPoint::ceil(self.lo), hi: FloatingPoint::ceil(self.hi) }
+                }
+                fn cos(self) -> Self {
+                    if self.width().partial_cmp(&<$structure as FloatConsts>::TAU) != Some(Ordering::Less) {
+                        return Self::new(-1.0, 1.0);
+                    }
+                    let (a, b) = (FloatingPoint::cos(self.lo), FloatingPoint::cos(self.hi));
+                    let mut cos = Self::outward(a.min(b), a.max(b), Self::LIBRARY_STEPS);
+                    if self.may_contain_phase(0.0) {
+                        cos.hi = 1.0;
+                    }
+                    if self.may_contain_phase(<$structure as FloatConsts>::PI) {
+                        cos.lo = -1.0;
+                    }
+                    cos.clamp_to(-1.0, 1.0)
+                }
+                fn exp(self) -> Self {
+                    self.increasing(FloatingPoint::exp).clamp_to(0.0, $structure::INFINITY)
+                }
+                fn exp2(self) -> Self {
+                    self.increasing(FloatingPoint::exp2).clamp_to(0.0, $structure::INFINITY)
+                }
+                fn exp_m1(self) -> Self {
+                    self.increasing(FloatingPoint::exp_m1).clamp_to(-1.0, $structure::INFINITY)
+                }
+                fn floor(self) -> Self {
+                    Self { lo: FloatingPoint::floor(self.lo), hi: FloatingPoint::floor(self.hi) }
+                }
+                fn fract(self) -> Self {
+                    let trunc = FloatingPoint::trunc(self.lo);
+                    if trunc == FloatingPoint::trunc(self.hi) && (trunc != 0.0 || self.lo >= 0.0 || self.hi <= 0.0) {
+                        Self { lo: FloatingPoint::fract(self.lo), hi: FloatingPoint::fract(self.hi) }
+                    } else if self.lo >= 0.0 {
+                        Self::new(0.0, 1.0)
+                    } else if self.hi <= 0.0 {
+                        Self::new(-1.0, 0.0)
+                    } else {
+                        Self::new(-1.0, 1.0)
+                    }
+                }
+                fn hypot(self, other: Self) -> Self {
+                    Self::outward(
+                        FloatingPoint::hypot(self.mignitude(), other.mignitude()),
+                        FloatingPoint::hypot(self.magnitude(), other.magnitude()),
+                        Self::LIBRARY_STEPS,
+                    ).clamp_to(0.0, $structure::INFINITY)
+                }
+                fn ln(self) -> Self {
+                    match self.intersection(Self::new(0.0, $structure::INFINITY)) {
+                        Some(x) => x.increasing(FloatingPoint::ln),
+                        None => Self::NAN,
+                    }
+                }
+                fn ln_1p(self) -> Self {
+                    match self.intersection(Self::new(-1.0, $structure::INFINITY)) {
+                        Some(x) => x.increasing(FloatingPoint::ln_1p),
+                        None => Self::NAN,
+                    }
+                }
+                fn log(self, base: Self) -> Self {
+                    self.ln() / base.ln()
+                }
+                fn log10(self) -> Self {
+                    match self.intersection(Self::new(0.0, $structure::INFINITY)) {
+                        Some(x) => x.increasing(FloatingPoint::log10),
+                        None => Self::NAN,
+                    }
+                }
+                fn log2(self) -> Self {
+                    match self.intersection(Self::new(0.0, $structure::INFINITY)) {
+                        Some(x) => x.increasing(FloatingPoint::log2),
+                        None => Self::NAN,
+                    }
+                }
+                fn powf(self, n: Self) -> Self {
+                    // Negative bases only have powers with integer exponents.
+                    // Any size of integer, since casting to `i32` would
+                    // saturate to an odd exponent.
+                    if n.is_point() && FloatingPoint::fract(n.lo) == 0.0 {
+                        return self.whole_power(f64::from(n.lo), FloatingPoint::fract(n.lo / 2.0) != 0.0);
+                    }
+                    match self.intersection(Self::new(0.0, $structure::INFINITY)) {
+                        Some(x) => (n * x.ln()).exp(),
+                        None => Self::NAN,
+                    }
+                }
+                fn powi(self, n: i32) -> Self {
+                    self.whole_power(f64::from(n), n % 2 != 0)
+                }
+                fn recip(self) -> Self {
+                    Self::ONE / self
+                }
+                fn round(self) -> Self {
+                    Self { lo: FloatingPoint::round(self.lo), hi: FloatingPoint::round(self.hi) }
+                }
+                fn signum(self) -> Self {
+                    Self { lo: FloatingPoint::signum(self.lo), hi: FloatingPoint::signum(self.hi) }
+                }
+                fn sin(self) -> Self {
+                    if self.width().partial_cmp(&<$structure as FloatConsts>::TAU) != Some(Ordering::Less) {
+                        return Self::new(-1.0, 1.0);
+                    }
+                    let (a, b) = (FloatingPoint::sin(self.lo), FloatingPoint::sin(self.hi));
+                    let mut sin = Self::outward(a.min(b), a.max(b), Self::LIBRARY_STEPS);
+                    if self.may_contain_phase(<$structure as FloatConsts>::FRAC_PI_2) {
+                        sin.hi = 1.0;
+                    }
+                    if self.may_contain_phase(-<$structure as FloatConsts>::FRAC_PI_2) {
+                        sin.lo = -1.0;
+                    }
+                    sin.clamp_to(-1.0, 1.0)
+                }
+                fn sin_cos(self) -> (Self, Self)
+                        where Self: Sized {
+                    (self.sin(), self.cos())
+                }
+                fn sinh(self) -> Self {
+                    self.increasing(FloatingPoint::sinh)
+                }
+                fn sqrt(self) -> Self {
+                    // sqrt is correctly rounded, so a single step is enough.
+                    match self.intersection(Self::new(0.0, $structure::INFINITY)) {
+                        Some(x) => Self::outward(FloatingPoint::sqrt(x.lo), FloatingPoint::sqrt(x.hi), 1).clamp_to(0.0, $structure::INFINITY),
+                        None => Self::NAN,
+                    }
+                }
+                fn tan(self) -> Self {
+                    // tan goes up between each pair of poles at π/2 + kπ.
+                    let half_pi = <$structure as FloatConsts>::FRAC_PI_2;
+                    if self.width().partial_cmp(&<$structure as FloatConsts>::PI) != Some(Ordering::Less) || self.may_contain_phase(half_pi) || self.may_contain_phase(-half_pi) {
+                        return Self::ENTIRE;
+                    }
+                    self.increasing(FloatingPoint::tan)
+                }
+                fn tanh(self) -> Self {
+                    self.increasing(FloatingPoint::tanh).clamp_to(-1.0, 1.0)
+                }
+                fn to_degrees(self) -> Self {
+                    self.increasing(FloatingPoint::to_degrees)
+                }
+                fn to_radians(self) -> Self {
+                    self.increasing(FloatingPoint::to_radians)
+                }
+                fn trunc(self) -> Self {
+                    Self { lo: FloatingPoint::trunc(self.lo), hi: FloatingPoint::trunc(self.hi) }
+                }
+                /// Whether both bounds are finite.
+                fn is_finite(self) -> bool {
+                    self.lo.is_finite() && self.hi.is_finite()
+                }
+                fn is_infinite(self) -> bool {
+                    self.lo.is_infinite() || self.hi.is_infinite()
+                }
+                fn is_nan(self) -> bool {
+                    self.lo.is_nan() || self.hi.is_nan()
+                }
+                fn nan() -> Self {
+                    Self::NAN
+                }
+                /// Whether every value in it is normal.
+                fn is_normal(self) -> bool {
+                    self.lo.is_normal() && self.hi.is_normal() && self.lo.is_sign_negative() == self.hi.is_sign_negative()
+                }
+                /// Whether every value in it is subnormal.
+                fn is_subnormal(self) -> bool {
+                    self.lo.is_subnormal() && self.hi.is_subnormal() && self.lo.is_sign_negative() == self.hi.is_sign_negative()
+                }
+                fn erf(self) -> Self {
+                    self.increasing(FloatingPoint::erf).clamp_to(-1.0, 1.0)
+                }
+                fn erfc(self) -> Self {
+                    self.decreasing(FloatingPoint::erfc).clamp_to(0.0, 2.0)
+                }
+                /// By the lower bound, then the upper one.
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    self.lo.total_cmp(&other.lo).then(self.hi.total_cmp(&other.hi))
+                }
+                fn next_up(self) -> Self {
+                    Self { lo: self.lo.next_up(), hi: self.hi.next_up() }
+                }
+                fn next_down(self) -> Self {
+                    Self { lo: self.lo.next_down(), hi: self.hi.next_down() }
+                }
+                fn min_num(self, other: Self) -> Self {
+                    Self { lo: self.lo.min(other.lo), hi: self.hi.min(other.hi) }
+                }
+                fn max_num(self, other: Self) -> Self {
+                    Self { lo: self.lo.max(other.lo), hi: self.hi.max(other.hi) }
+                }
+                /// Floors the quotient for a positive `rhs` and takes the
+                /// ceiling for a negative one, so `[-1, 1]` by 3 is `[-1, 0]`.
+                fn div_euclid(self, rhs: Self) -> Self {
+                    let quotient = self / rhs;
+                    if rhs.lo > 0.0 {
+                        quotient.floor()
+                    } else if rhs.hi < 0.0 {
+                        quotient.ceil()
+                    } else {
+                        quotient.floor().hull(quotient.ceil())
+                    }
+                }
+                /// Within `[0, |rhs|]`, and only narrower when all of `self`
+                /// falls in one period of `|rhs|`.
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    if self.is_nan() || rhs.is_nan() {
+                        return Self::NAN;
+                    }
+                    let modulus = rhs.abs();
+                    let period = Self { lo: 0.0, hi: modulus.hi };
+                    let quotient = self.div_euclid(modulus);
+                    if quotient.is_point() && quotient.lo.is_finite() {
+                        (self - modulus * quotient).intersection(period).unwrap_or(period)
+                    } else {
+                        period
+                    }
+                }
+                /// Clamps each bound, so `[0, 5]` clamped to `[1, 2]` is
+                /// `[1, 2]`.
+                ///
+                /// # Panics
+                /// When `min` is certainly above `max`, or either of them is
+                /// NaN.
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    assert!(!min.certainly_gt(&max) && !min.is_nan() && !max.is_nan(), "min is greater than max, or either is NaN");
+                    if self.is_nan() {
+                        return self;
+                    }
+                    Self { lo: self.lo.min(max.lo).max(min.lo), hi: self.hi.min(max.hi).max(min.hi) }
+                }
+                fn abs_sub(self, other: Self) -> Self {
+                    let difference = self - other;
+                    if difference.is_nan() {
+                        return difference;
+                    }
+                    Self { lo: difference.lo.max(0.0), hi: difference.hi.max(0.0) }
+                }
+            }
+            impl FloatBits for Interval<$structure> {
+                /// The bits of the lower bound, then the upper one.
+                type Bits = [<$structure as FloatBits>::Bits; 2];
+                fn to_bits(self) -> Self::Bits {
+                    [self.lo.to_bits(), self.hi.to_bits()]
+                }
+                fn from_bits(bits: Self::Bits) -> Self {
+                    Self { lo: $structure::from_bits(bits[0]), hi: $structure::from_bits(bits[1]) }
+                }
+            }
+        )*
+    };
+}
+
+impl_interval!(f32, f64);
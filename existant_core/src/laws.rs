@@ -0,0 +1,462 @@
+//! Random testing for the property traits. Implementing [`AssociativeOver`],
+//! [`Identity`] and the rest is only a promise, so the functions here draw
+//! random values and check that the promise holds, handing back a
+//! [`Counterexample`] for the first one that doesn't.
+//!
+//! There's one function per law, and one per structure which checks every
+//! law that structure is made of:
+//! ```
+//! use existant_core::{laws, Addition, Multiplication, Wrapping};
+//! use rand::{rngs::SmallRng, SeedableRng};
+//!
+//! let mut rng = SmallRng::seed_from_u64(0);
+//! laws::check_commutative_ring::<Wrapping<i32>, Multiplication, Addition, _>(&mut rng, 1000).unwrap();
+//! assert!(laws::check_field::<f64, Multiplication, Addition, _>(&mut rng, 1000).is_ok());
+//! ```
+//! Closure isn't checked, since every [`Groupoid`] already returns `Self`.
+
+use core::fmt;
+
+use rand::{Rng, RngExt};
+
+use crate::{AbelianGroup, Absorption, AssociativeOver, Checked, CommutativeMonoid, CommutativeOver, CommutativeRing, Distributive, Field, Group, Groupoid, Identity, Inverse, Monoid, Operator, Ring, Saturating, Semigroup, Semiring, Tropical, Wrapping};
+
+/// A type that random values can be drawn from.
+pub trait Arbitrary: Sized {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+/// The equality the laws are checked with. It's exact for integers, but
+/// floats only need to agree to within rounding error, since `(a + b) + c`
+/// and `a + (b + c)` round differently.
+pub trait LawEq {
+    fn law_eq(&self, other: &Self) -> bool;
+}
+
+/// One of the laws behind the property traits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Law {
+    /// [`AssociativeOver`], `(a ⨁ b) ⨁ c = a ⨁ (b ⨁ c)`.
+    Associativity,
+    /// [`CommutativeOver`], `a ⨁ b = b ⨁ a`.
+    Commutativity,
+    /// [`Identity`], `a ⨁ IDENTITY = a`.
+    Identity,
+    /// [`Inverse`], `a ⨁ a.inverse() = IDENTITY`.
+    Inverse,
+    /// [`Absorption`], `a ⨁ ABSORBING = ABSORBING`.
+    Absorption,
+    /// [`Distributive`], `a * (b ⨁ c) = a * b ⨁ a * c`.
+    Distributivity,
+}
+
+impl Law {
+    /// Both sides of the law, written with `⨁` and `*`.
+    const fn sides(self) -> (&'static str, &'static str) {
+        match self {
+            Law::Associativity => ("(a ⨁ b) ⨁ c", "a ⨁ (b ⨁ c)"),
+            Law::Commutativity => ("a ⨁ b", "b ⨁ a"),
+            Law::Identity => ("a ⨁ IDENTITY", "a"),
+            Law::Inverse => ("a ⨁ a.inverse()", "IDENTITY"),
+            Law::Absorption => ("a ⨁ ABSORBING", "ABSORBING"),
+            Law::Distributivity => ("a * (b ⨁ c)", "a * b ⨁ a * c"),
+        }
+    }
+}
+
+impl fmt::Display for Law {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Law::Associativity => "associativity",
+            Law::Commutativity => "commutativity",
+            Law::Identity => "identity",
+            Law::Inverse => "inverse",
+            Law::Absorption => "absorption",
+            Law::Distributivity => "distributivity",
+        })
+    }
+}
+
+/// The values a law was broken by. `b` and `c` are only there for the laws
+/// which take them, and `left` and `right` are what the two sides of the law
+/// came out to.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Counterexample<T> {
+    pub law: Law,
+    /// The operator ⨁.
+    pub operator: &'static str,
+    /// The operator * that distributes over ⨁, for [`Law::Distributivity`].
+    pub distributed: Option<&'static str>,
+    pub a: T,
+    pub b: Option<T>,
+    pub c: Option<T>,
+    pub left: T,
+    pub right: T,
+}
+
+impl<T: fmt::Debug> fmt::Display for Counterexample<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (left, right) = self.law.sides();
+        match self.distributed {
+            Some(distributed) => write!(f, "{} of {distributed} over {} failed: ", self.law, self.operator)?,
+            None => write!(f, "{} of {} failed: ", self.law, self.operator)?,
+        }
+        write!(f, "{left} = {:?} but {right} = {:?} for a = {:?}", self.left, self.right, self.a)?;
+        if let Some(b) = &self.b {
+            write!(f, ", b = {b:?}")?;
+        }
+        if let Some(c) = &self.c {
+            write!(f, ", c = {c:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Counterexample<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for Counterexample<T> {}
+
+/// `Addition` rather than `existant_core::Addition`.
+fn name<Op>() -> &'static str {
+    let path = core::any::type_name::<Op>();
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+fn counterexample<T, Op>(law: Law, a: T, b: Option<T>, c: Option<T>, left: T, right: T) -> Counterexample<T> {
+    Counterexample { law, operator: name::<Op>(), distributed: None, a, b, c, left, right }
+}
+
+pub fn check_associativity<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Groupoid<Op> + AssociativeOver<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    for _ in 0..samples {
+        let (a, b, c) = (T::arbitrary(rng), T::arbitrary(rng), T::arbitrary(rng));
+        let left = a.op(&b).op(&c);
+        let right = a.op(&b.op(&c));
+        if !left.law_eq(&right) {
+            return Err(counterexample::<T, Op>(Law::Associativity, a, Some(b), Some(c), left, right));
+        }
+    }
+    Ok(())
+}
+
+pub fn check_commutativity<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Groupoid<Op> + CommutativeOver<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    for _ in 0..samples {
+        let (a, b) = (T::arbitrary(rng), T::arbitrary(rng));
+        let (left, right) = (a.op(&b), b.op(&a));
+        if !left.law_eq(&right) {
+            return Err(counterexample::<T, Op>(Law::Commutativity, a, Some(b), None, left, right));
+        }
+    }
+    Ok(())
+}
+
+pub fn check_identity<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Groupoid<Op> + Identity<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    let identity = <T as Identity<Op>>::IDENTITY;
+    if !<T as Identity<Op>>::is_identity(&identity) {
+        return Err(counterexample::<T, Op>(Law::Identity, identity, None, None, identity, identity));
+    }
+    for _ in 0..samples {
+        let a = T::arbitrary(rng);
+        let left = a.op(&identity);
+        if !left.law_eq(&a) {
+            return Err(counterexample::<T, Op>(Law::Identity, a, None, None, left, a));
+        }
+    }
+    Ok(())
+}
+
+/// Badly conditioned values, such as nearly singular matrices, can fail this
+/// on rounding error alone.
+pub fn check_inverse<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Groupoid<Op> + Identity<Op> + Inverse<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    inverse_where::<T, Op, R>(rng, samples, |_| true)
+}
+
+/// [`check_inverse`] over the values `invertible` accepts.
+fn inverse_where<T, Op, R>(rng: &mut R, samples: usize, invertible: impl Fn(&T) -> bool) -> Result<(), Counterexample<T>>
+    where T: Groupoid<Op> + Identity<Op> + Inverse<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    let identity = <T as Identity<Op>>::IDENTITY;
+    for _ in 0..samples {
+        let a = T::arbitrary(rng);
+        if !invertible(&a) {
+            continue;
+        }
+        let left = a.op(&<T as Inverse<Op>>::inverse(a));
+        if !left.law_eq(&identity) {
+            return Err(counterexample::<T, Op>(Law::Inverse, a, None, None, left, identity));
+        }
+    }
+    Ok(())
+}
+
+pub fn check_absorption<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Groupoid<Op> + Absorption<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    let absorbing = <T as Absorption<Op>>::ABSORBING;
+    if !<T as Absorption<Op>>::is_absorber(&absorbing) {
+        return Err(counterexample::<T, Op>(Law::Absorption, absorbing, None, None, absorbing, absorbing));
+    }
+    for _ in 0..samples {
+        let a = T::arbitrary(rng);
+        let left = a.op(&absorbing);
+        if !left.law_eq(&absorbing) {
+            return Err(counterexample::<T, Op>(Law::Absorption, a, None, None, left, absorbing));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `DistributiveOp` distributes over `Op` from the left.
+pub fn check_distributivity<T, DistributiveOp, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Groupoid<DistributiveOp> + Groupoid<Op> + Distributive<DistributiveOp, Op> + Arbitrary + LawEq,
+    DistributiveOp: Operator,
+    Op: Operator,
+    R: Rng + ?Sized {
+    for _ in 0..samples {
+        let (a, b, c) = (T::arbitrary(rng), T::arbitrary(rng), T::arbitrary(rng));
+        let left = <T as Groupoid<DistributiveOp>>::op(&a, &<T as Groupoid<Op>>::op(&b, &c));
+        let right = <T as Groupoid<Op>>::op(
+            &<T as Groupoid<DistributiveOp>>::op(&a, &b),
+            &<T as Groupoid<DistributiveOp>>::op(&a, &c),
+        );
+        if !left.law_eq(&right) {
+            return Err(Counterexample {
+                distributed: Some(name::<DistributiveOp>()),
+                ..counterexample::<T, Op>(Law::Distributivity, a, Some(b), Some(c), left, right)
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn check_semigroup<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Semigroup<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_associativity::<T, Op, R>(rng, samples)
+}
+
+pub fn check_monoid<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Monoid<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_semigroup::<T, Op, R>(rng, samples)?;
+    check_identity::<T, Op, R>(rng, samples)
+}
+
+pub fn check_commutative_monoid<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: CommutativeMonoid<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_monoid::<T, Op, R>(rng, samples)?;
+    check_commutativity::<T, Op, R>(rng, samples)
+}
+
+pub fn check_group<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Group<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_monoid::<T, Op, R>(rng, samples)?;
+    check_inverse::<T, Op, R>(rng, samples)
+}
+
+pub fn check_abelian_group<T, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: AbelianGroup<Op> + Arbitrary + LawEq,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_group::<T, Op, R>(rng, samples)?;
+    check_commutativity::<T, Op, R>(rng, samples)
+}
+
+pub fn check_semiring<T, DistributiveOp, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Semiring<DistributiveOp, Op> + Arbitrary + LawEq,
+    DistributiveOp: Operator,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_commutative_monoid::<T, Op, R>(rng, samples)?;
+    check_monoid::<T, DistributiveOp, R>(rng, samples)?;
+    check_distributivity::<T, DistributiveOp, Op, R>(rng, samples)
+}
+
+pub fn check_ring<T, DistributiveOp, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Ring<DistributiveOp, Op> + Arbitrary + LawEq,
+    DistributiveOp: Operator,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_abelian_group::<T, Op, R>(rng, samples)?;
+    check_monoid::<T, DistributiveOp, R>(rng, samples)?;
+    check_distributivity::<T, DistributiveOp, Op, R>(rng, samples)
+}
+
+pub fn check_commutative_ring<T, DistributiveOp, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: CommutativeRing<DistributiveOp, Op> + Arbitrary + LawEq,
+    DistributiveOp: Operator,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_ring::<T, DistributiveOp, Op, R>(rng, samples)?;
+    check_commutativity::<T, DistributiveOp, R>(rng, samples)
+}
+
+/// Checks a [`CommutativeRing`] where everything but the identity of `Op`
+/// has an inverse under `DistributiveOp`.
+pub fn check_field<T, DistributiveOp, Op, R>(rng: &mut R, samples: usize) -> Result<(), Counterexample<T>>
+    where T: Field<DistributiveOp, Op> + Arbitrary + LawEq,
+    DistributiveOp: Operator,
+    Op: Operator,
+    R: Rng + ?Sized {
+    check_commutative_ring::<T, DistributiveOp, Op, R>(rng, samples)?;
+    inverse_where::<T, DistributiveOp, R>(rng, samples, |a| !<T as Identity<Op>>::is_identity(a))
+}
+
+/// Integers are kept small enough that three of them can be multiplied
+/// together without overflowing.
+macro_rules! impl_laws_integer {
+    ($($structure:ident ($low:literal, $high:literal)),*) => {
+        $(
+            impl Arbitrary for $structure {
+                fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    rng.random_range($low..=$high)
+                }
+            }
+            impl LawEq for $structure {
+                fn law_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+/// Floats are drawn from `[-4, 4]`, and compared relative to whichever side
+/// is bigger, or to 1 if both are smaller than that.
+macro_rules! impl_laws_float {
+    ($($structure:ident $tolerance:literal),*) => {
+        $(
+            impl Arbitrary for $structure {
+                fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    rng.random_range(-4.0..=4.0)
+                }
+            }
+            impl LawEq for $structure {
+                fn law_eq(&self, other: &Self) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    let scale = self.abs().max(other.abs()).max(1.0);
+                    (self - other).abs() <= $tolerance * scale
+                }
+            }
+        )*
+    };
+}
+
+/// The overflowing types are drawn from the whole range, since overflow is
+/// what they're about.
+macro_rules! impl_laws_overflow {
+    ($($structure:ident from $sample:ident),*) => {
+        $(
+            impl Arbitrary for Wrapping<$structure> {
+                fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    Self(rng.random::<$sample>() as $structure)
+                }
+            }
+            impl Arbitrary for Saturating<$structure> {
+                fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    Self(rng.random::<$sample>() as $structure)
+                }
+            }
+            impl Arbitrary for Checked<$structure> {
+                fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    Self::new(rng.random::<$sample>() as $structure)
+                }
+            }
+            impl LawEq for Wrapping<$structure> {
+                fn law_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+            impl LawEq for Saturating<$structure> {
+                fn law_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+            impl LawEq for Checked<$structure> {
+                fn law_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_laws_integer!(
+    u8 (0, 6), u16 (0, 40), u32 (0, 1000), u64 (0, 100000), u128 (0, 1000000000000),
+    i8 (-5, 5), i16 (-30, 30), i32 (-1000, 1000), i64 (-100000, 100000), i128 (-1000000000000, 1000000000000)
+);
+
+impl Arbitrary for bool {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.random()
+    }
+}
+impl LawEq for bool {
+    fn law_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+// rand doesn't sample the pointer sized integers, since they'd differ
+// between platforms.
+impl Arbitrary for usize {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        u32::arbitrary(rng) as usize
+    }
+}
+impl Arbitrary for isize {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        i32::arbitrary(rng) as isize
+    }
+}
+impl LawEq for usize {
+    fn law_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+impl LawEq for isize {
+    fn law_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+impl_laws_float!(f32 1e-4, f64 1e-9);
+impl_laws_overflow!(
+    u8 from u8, u16 from u16, u32 from u32, u64 from u64, u128 from u128, usize from u64,
+    i8 from i8, i16 from i16, i32 from i32, i64 from i64, i128 from i128, isize from i64
+);
+
+impl<T: Arbitrary, Op> Arbitrary for Tropical<T, Op> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(T::arbitrary(rng))
+    }
+}
+impl<T: LawEq, Op> LawEq for Tropical<T, Op> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.value.law_eq(&other.value)
+    }
+}
@@ -1,8 +1,42 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod abstract_algebra;
+mod approx;
+mod bigint;
 mod cmath;
+pub mod const_math;
+mod fixed;
+#[cfg(feature = "half")]
+mod float16;
+mod interval;
+#[cfg(feature = "laws")]
+pub mod laws;
+mod modular;
+pub mod number_theory;
+#[cfg(feature = "num-traits")]
+mod num_compat;
 mod nums;
+mod overflow;
+mod rational;
+#[cfg(feature = "serde")]
+mod serialize;
+mod special;
+mod tropical;
 pub use abstract_algebra::*;
+pub use approx::*;
+pub use bigint::*;
 pub use cmath::*;
-pub use nums::*;
\ No newline at end of file
+pub use fixed::*;
+#[cfg(feature = "half")]
+pub use float16::*;
+pub use interval::*;
+pub use modular::*;
+#[cfg(feature = "num-traits")]
+pub use num_compat::*;
+pub use nums::*;
+pub use overflow::*;
+pub use rational::*;
+pub use tropical::*;
\ No newline at end of file
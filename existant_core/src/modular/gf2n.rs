@@ -0,0 +1,220 @@
+use core::fmt;
+
+use crate::{Absorption, Addition, AssociativeOver, ClosedUnder, CommutativeOver, Distributive, Division, Identity, Inverse, Multiplication, Subtraction};
+
+/// An element of the binary field GF(2^n), a polynomial over GF(2) with its
+/// coefficients packed into the bits of an integer. `POLY` is the reducing
+/// polynomial in the same packing, with its top bit giving the degree `n`,
+/// which can be at most 63. It has to be irreducible for this to be a field,
+/// which isn't checked.
+///
+/// Addition and subtraction are both xor, since every element is its own
+/// negative.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Gf2n<const POLY: u64> {
+    value: u64,
+}
+
+/// GF(2^8) with the polynomial x^8 + x^4 + x^3 + x + 1 from AES.
+pub type Gf256 = Gf2n<0x11B>;
+/// GF(2^16) with the polynomial x^16 + x^12 + x^3 + x + 1.
+pub type Gf65536 = Gf2n<0x1100B>;
+
+impl<const POLY: u64> Gf2n<POLY> {
+    /// The degree `n` of the field.
+    pub const DEGREE: u32 = {
+        assert!(POLY > 1, "the reducing polynomial needs a degree of at least 1");
+        63 - POLY.leading_zeros()
+    };
+    pub const ZERO: Self = Self { value: 0 };
+    pub const ONE: Self = {
+        assert!(Self::DEGREE > 0);
+        Self { value: 1 }
+    };
+    /// `value` as a polynomial, reduced by `POLY`.
+    pub const fn new(mut value: u64) -> Self {
+        while value != 0 && 63 - value.leading_zeros() >= Self::DEGREE {
+            value ^= POLY << (63 - value.leading_zeros() - Self::DEGREE);
+        }
+        Self { value }
+    }
+    /// The coefficients of the polynomial, with x^i in bit i.
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+    /// Shift and add multiplication, reducing whenever the degree reaches `n`.
+    const fn product(mut a: u64, mut b: u64) -> u64 {
+        let mut result = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                result ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if a >> Self::DEGREE & 1 == 1 {
+                a ^= POLY;
+            }
+        }
+        result
+    }
+    pub const fn pow(self, mut exp: u64) -> Self {
+        let mut base = self.value;
+        let mut result = 1;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                result = Self::product(result, base);
+            }
+            base = Self::product(base, base);
+            exp >>= 1;
+        }
+        Self { value: result }
+    }
+    /// The value `x` with `self * x == 1`, if `self` isn't zero.
+    pub const fn checked_inverse(self) -> Option<Self> {
+        if self.value == 0 {
+            return None;
+        }
+        // The nonzero elements form a group of order 2^n - 1.
+        Some(self.pow((1 << Self::DEGREE) - 2))
+    }
+    /// The value `x` with `self * x == 1`.
+    ///
+    /// # Panics
+    /// When `self` is zero.
+    pub const fn inverse(self) -> Self {
+        match self.checked_inverse() {
+            Some(inverse) => inverse,
+            None => panic!("attempt to divide by zero"),
+        }
+    }
+}
+
+impl<const POLY: u64> From<u64> for Gf2n<POLY> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const POLY: u64> fmt::Display for Gf2n<POLY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.value)
+    }
+}
+impl<const POLY: u64> fmt::Debug for Gf2n<POLY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const POLY: u64> core::ops::Add for Gf2n<POLY> {
+    type Output = Self;
+    // Adding polynomials over GF(2) adds each coefficient mod 2.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { value: self.value ^ rhs.value }
+    }
+}
+impl<const POLY: u64> core::ops::Sub for Gf2n<POLY> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { value: self.value ^ rhs.value }
+    }
+}
+impl<const POLY: u64> core::ops::Mul for Gf2n<POLY> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { value: Self::product(self.value, rhs.value) }
+    }
+}
+impl<const POLY: u64> core::ops::Div for Gf2n<POLY> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Self { value: Self::product(self.value, rhs.inverse().value) }
+    }
+}
+impl<const POLY: u64> core::ops::Neg for Gf2n<POLY> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+impl<const POLY: u64> core::ops::AddAssign for Gf2n<POLY> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<const POLY: u64> core::ops::SubAssign for Gf2n<POLY> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<const POLY: u64> core::ops::MulAssign for Gf2n<POLY> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<const POLY: u64> core::ops::DivAssign for Gf2n<POLY> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const POLY: u64> AssociativeOver<Addition> for Gf2n<POLY> {}
+impl<const POLY: u64> CommutativeOver<Addition> for Gf2n<POLY> {}
+impl<const POLY: u64> ClosedUnder<Addition> for Gf2n<POLY> {}
+impl<const POLY: u64> AssociativeOver<Multiplication> for Gf2n<POLY> {}
+impl<const POLY: u64> CommutativeOver<Multiplication> for Gf2n<POLY> {}
+impl<const POLY: u64> ClosedUnder<Multiplication> for Gf2n<POLY> {}
+impl<const POLY: u64> AssociativeOver<Subtraction> for Gf2n<POLY> {}
+impl<const POLY: u64> ClosedUnder<Subtraction> for Gf2n<POLY> {}
+impl<const POLY: u64> ClosedUnder<Division> for Gf2n<POLY> {}
+impl<const POLY: u64> Distributive<Multiplication, Addition> for Gf2n<POLY> {}
+impl<const POLY: u64> Distributive<Multiplication, Subtraction> for Gf2n<POLY> {}
+impl<const POLY: u64> Distributive<Division, Addition> for Gf2n<POLY> {}
+impl<const POLY: u64> Distributive<Division, Subtraction> for Gf2n<POLY> {}
+impl<const POLY: u64> Identity<Addition> for Gf2n<POLY> {
+    const IDENTITY: Self = Self::ZERO;
+    fn is_identity(&self) -> bool {
+        self.value == 0
+    }
+}
+impl<const POLY: u64> Identity<Subtraction> for Gf2n<POLY> {
+    const IDENTITY: Self = Self::ZERO;
+    fn is_identity(&self) -> bool {
+        self.value == 0
+    }
+}
+impl<const POLY: u64> Identity<Multiplication> for Gf2n<POLY> {
+    const IDENTITY: Self = Self::ONE;
+    fn is_identity(&self) -> bool {
+        self.value == 1
+    }
+}
+impl<const POLY: u64> Identity<Division> for Gf2n<POLY> {
+    const IDENTITY: Self = Self::ONE;
+    fn is_identity(&self) -> bool {
+        self.value == 1
+    }
+}
+impl<const POLY: u64> Absorption<Multiplication> for Gf2n<POLY> {
+    const ABSORBING: Self = Self::ZERO;
+    fn is_absorber(&self) -> bool {
+        self.value == 0
+    }
+}
+impl<const POLY: u64> Inverse<Addition> for Gf2n<POLY> {
+    fn inverse(self) -> Self {
+        self
+    }
+}
+impl<const POLY: u64> Inverse<Subtraction> for Gf2n<POLY> {
+    fn inverse(self) -> Self {
+        self
+    }
+}
+impl<const POLY: u64> Inverse<Multiplication> for Gf2n<POLY> {
+    fn inverse(self) -> Self {
+        Gf2n::inverse(self)
+    }
+}
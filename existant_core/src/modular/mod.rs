@@ -0,0 +1,75 @@
+//! Finite rings and fields. [`ModInt`] is the integers modulo `N`, which is a
+//! ring, [`Gf`] is the same thing for a prime modulus, which makes it a field,
+//! and [`Gf2n`] is a binary field GF(2^n) like the ones used in Reed-Solomon
+//! codes and AES.
+
+mod gf2n;
+mod modint;
+pub use gf2n::*;
+pub use modint::*;
+
+pub(crate) const fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+pub(crate) const fn pow_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut base = base % modulus;
+    let mut result = 1 % modulus;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Inverse of `value` modulo `modulus` through the extended Euclidean
+/// algorithm, if the two are coprime.
+pub(crate) const fn inverse_mod(value: u64, modulus: u64) -> Option<u64> {
+    let (mut r0, mut r1) = (modulus as i128, (value % modulus) as i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 != 0 {
+        let quotient = r0 / r1;
+        (r0, r1) = (r1, r0 - quotient * r1);
+        (t0, t1) = (t1, t0 - quotient * t1);
+    }
+    if r0 == 1 { Some(t0.rem_euclid(modulus as i128) as u64) } else { None }
+}
+
+/// Miller-Rabin with the first twelve primes as witnesses, which is known to
+/// be exact for every `u64`.
+pub(crate) const fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if n % WITNESSES[i] == 0 {
+            return n == WITNESSES[i];
+        }
+        i += 1;
+    }
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    let mut i = 0;
+    'witnesses: while i < WITNESSES.len() {
+        let mut x = pow_mod(WITNESSES[i], odd, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut round = 1;
+        while round < shift {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witnesses;
+            }
+            round += 1;
+        }
+        return false;
+    }
+    true
+}
@@ -0,0 +1,238 @@
+use core::fmt;
+
+use crate::{Absorption, Addition, AssociativeOver, ClosedUnder, CommutativeOver, Distributive, Division, Identity, Inverse, Multiplication, Subtraction};
+
+use super::{inverse_mod, is_prime, mul_mod, pow_mod};
+
+/// An integer modulo `N`, always kept in `0..N`. This is a [`Ring`](crate::Ring)
+/// for any `N`, but only has inverses for the values coprime to `N`, see
+/// [`Gf`] for a prime modulus.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt<const N: u64> {
+    value: u64,
+}
+
+/// An integer modulo the prime `P`, which makes it a [`Field`](crate::Field).
+/// Using it with a `P` that isn't prime fails to compile.
+/// ```compile_fail
+/// let zero = existant_core::Gf::<9>::default();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf<const P: u64> {
+    value: u64,
+}
+
+macro_rules! impl_modular {
+    ($structure:ident, $modulus:ident) => {
+        impl<const $modulus: u64> $structure<$modulus> {
+            /// `value` reduced modulo the modulus.
+            pub const fn new(value: u64) -> Self {
+                let () = Self::VALID;
+                Self { value: value % $modulus }
+            }
+            /// `value` reduced modulo the modulus, where negative values wrap
+            /// around to the top.
+            pub const fn from_i64(value: i64) -> Self {
+                let () = Self::VALID;
+                Self { value: (value as i128).rem_euclid($modulus as i128) as u64 }
+            }
+            /// The representative in `0..modulus`.
+            pub const fn value(self) -> u64 {
+                self.value
+            }
+            pub const fn modulus() -> u64 {
+                $modulus
+            }
+            pub const fn pow(self, exp: u64) -> Self {
+                Self { value: pow_mod(self.value, exp, $modulus) }
+            }
+        }
+
+        impl<const $modulus: u64> Default for $structure<$modulus> {
+            /// Zero, which goes through the check on the modulus like every
+            /// other way of making one.
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const $modulus: u64> From<u64> for $structure<$modulus> {
+            fn from(value: u64) -> Self {
+                Self::new(value)
+            }
+        }
+        impl<const $modulus: u64> From<i64> for $structure<$modulus> {
+            fn from(value: i64) -> Self {
+                Self::from_i64(value)
+            }
+        }
+
+        impl<const $modulus: u64> fmt::Display for $structure<$modulus> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.value, f)
+            }
+        }
+        impl<const $modulus: u64> fmt::Debug for $structure<$modulus> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} (mod {})", self.value, $modulus)
+            }
+        }
+
+        impl<const $modulus: u64> core::ops::Add for $structure<$modulus> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self::Output {
+                let (sum, overflowed) = self.value.overflowing_add(rhs.value);
+                Self { value: if overflowed || sum >= $modulus { sum.wrapping_sub($modulus) } else { sum } }
+            }
+        }
+        impl<const $modulus: u64> core::ops::Sub for $structure<$modulus> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self::Output {
+                let difference = self.value.wrapping_sub(rhs.value);
+                Self { value: if self.value < rhs.value { difference.wrapping_add($modulus) } else { difference } }
+            }
+        }
+        impl<const $modulus: u64> core::ops::Mul for $structure<$modulus> {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self { value: mul_mod(self.value, rhs.value, $modulus) }
+            }
+        }
+        impl<const $modulus: u64> core::ops::Neg for $structure<$modulus> {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                Self::default() - self
+            }
+        }
+        impl<const $modulus: u64> core::ops::AddAssign for $structure<$modulus> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+        impl<const $modulus: u64> core::ops::SubAssign for $structure<$modulus> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+        impl<const $modulus: u64> core::ops::MulAssign for $structure<$modulus> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const $modulus: u64> AssociativeOver<Addition> for $structure<$modulus> {}
+        impl<const $modulus: u64> CommutativeOver<Addition> for $structure<$modulus> {}
+        impl<const $modulus: u64> ClosedUnder<Addition> for $structure<$modulus> {}
+        impl<const $modulus: u64> AssociativeOver<Multiplication> for $structure<$modulus> {}
+        impl<const $modulus: u64> CommutativeOver<Multiplication> for $structure<$modulus> {}
+        impl<const $modulus: u64> ClosedUnder<Multiplication> for $structure<$modulus> {}
+        impl<const $modulus: u64> AssociativeOver<Subtraction> for $structure<$modulus> {}
+        impl<const $modulus: u64> ClosedUnder<Subtraction> for $structure<$modulus> {}
+        impl<const $modulus: u64> Distributive<Multiplication, Addition> for $structure<$modulus> {}
+        impl<const $modulus: u64> Distributive<Multiplication, Subtraction> for $structure<$modulus> {}
+        impl<const $modulus: u64> Identity<Addition> for $structure<$modulus> {
+            const IDENTITY: Self = Self::ZERO;
+            fn is_identity(&self) -> bool {
+                self.value == 0
+            }
+        }
+        impl<const $modulus: u64> Identity<Subtraction> for $structure<$modulus> {
+            const IDENTITY: Self = Self::ZERO;
+            fn is_identity(&self) -> bool {
+                self.value == 0
+            }
+        }
+        impl<const $modulus: u64> Identity<Multiplication> for $structure<$modulus> {
+            const IDENTITY: Self = Self::ONE;
+            fn is_identity(&self) -> bool {
+                *self == Self::ONE
+            }
+        }
+        impl<const $modulus: u64> Absorption<Multiplication> for $structure<$modulus> {
+            const ABSORBING: Self = Self::ZERO;
+            fn is_absorber(&self) -> bool {
+                self.value == 0
+            }
+        }
+        impl<const $modulus: u64> Inverse<Addition> for $structure<$modulus> {
+            fn inverse(self) -> Self {
+                -self
+            }
+        }
+        impl<const $modulus: u64> Inverse<Subtraction> for $structure<$modulus> {
+            fn inverse(self) -> Self {
+                -self
+            }
+        }
+    };
+}
+
+impl_modular!(ModInt, N);
+impl_modular!(Gf, P);
+
+impl<const N: u64> ModInt<N> {
+    const VALID: () = assert!(N != 0, "the modulus can't be zero");
+    pub const ZERO: Self = Self::new(0);
+    pub const ONE: Self = Self::new(1);
+    /// The value `x` with `self * x == 1`, which exists exactly when `self`
+    /// and `N` are coprime.
+    pub const fn checked_inverse(self) -> Option<Self> {
+        match inverse_mod(self.value, N) {
+            Some(value) => Some(Self { value }),
+            None => None,
+        }
+    }
+}
+
+impl<const P: u64> Gf<P> {
+    const VALID: () = assert!(is_prime(P), "the modulus of a prime field has to be prime");
+    pub const ZERO: Self = Self::new(0);
+    pub const ONE: Self = Self::new(1);
+    /// The value `x` with `self * x == 1`.
+    ///
+    /// # Panics
+    /// When `self` is zero.
+    pub const fn inverse(self) -> Self {
+        match inverse_mod(self.value, P) {
+            Some(value) => Self { value },
+            None => panic!("attempt to divide by zero"),
+        }
+    }
+}
+
+impl<const P: u64> From<ModInt<P>> for Gf<P> {
+    fn from(value: ModInt<P>) -> Self {
+        Self::new(value.value)
+    }
+}
+impl<const P: u64> From<Gf<P>> for ModInt<P> {
+    fn from(value: Gf<P>) -> Self {
+        Self::new(value.value)
+    }
+}
+
+impl<const P: u64> core::ops::Div for Gf<P> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Self { value: mul_mod(self.value, rhs.inverse().value, P) }
+    }
+}
+impl<const P: u64> core::ops::DivAssign for Gf<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+impl<const P: u64> ClosedUnder<Division> for Gf<P> {}
+impl<const P: u64> Distributive<Division, Addition> for Gf<P> {}
+impl<const P: u64> Distributive<Division, Subtraction> for Gf<P> {}
+impl<const P: u64> Identity<Division> for Gf<P> {
+    const IDENTITY: Self = Self::ONE;
+    fn is_identity(&self) -> bool {
+        *self == Self::ONE
+    }
+}
+impl<const P: u64> Inverse<Multiplication> for Gf<P> {
+    fn inverse(self) -> Self {
+        Gf::inverse(self)
+    }
+}
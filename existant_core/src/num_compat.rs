@@ -0,0 +1,878 @@
+//! Adapters between the number traits here and the ones in `num_traits`, which
+//! most of the ecosystem is written against. Blanket impls either way would
+//! overlap with the ones for the primitives, so each direction has a wrapper:
+//! [`NumTraits`] makes a `num_traits` float usable here, such as in the
+//! vectors and matrices, and [`Existant`] hands one of the numbers here to code
+//! that wants `num_traits`.
+
+use core::{cmp::Ordering, fmt, hash::Hash, marker::PhantomData, num::FpCategory, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
+
+use num_traits::{float::TotalOrder, Bounded, ConstOne, ConstZero, Float, FloatConst, Num, NumCast, One, ToPrimitive, Zero};
+
+use crate::{Absorption, Addition, ApproxEq, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, DistributiveLattice, Division, FloatBits, FloatConsts, FloatingPoint, FromPrimitive, Identity, IntoPrimitive, Inverse, Lattice, Multiplication, Number, PartialOrderSet, Signed, Subtraction, Tolerance};
+
+/// What a [`FloatingPoint`] has that `num_traits::Float` doesn't, or only has
+/// as functions where this crate needs constants. [`NumTraits`] takes these
+/// from its second parameter, which any [`FloatingPoint`] fills in for itself,
+/// so a float that only implements `num_traits` needs a type of your own to
+/// implement this on.
+pub trait FloatExtras<T> {
+    const MIN: T;
+    const MAX: T;
+    const PI: T;
+    const TAU: T;
+    const PHI: T;
+    const EGAMMA: T;
+    const FRAC_PI_2: T;
+    const FRAC_PI_3: T;
+    const FRAC_PI_4: T;
+    const FRAC_PI_6: T;
+    const FRAC_PI_8: T;
+    const FRAC_1_PI: T;
+    const FRAC_1_SQRT_PI: T;
+    const FRAC_2_PI: T;
+    const FRAC_2_SQRT_PI: T;
+    const SQRT_2: T;
+    const FRAC_1_SQRT_2: T;
+    const SQRT_3: T;
+    const FRAC_1_SQRT_3: T;
+    const E: T;
+    const LOG2_E: T;
+    const LOG2_10: T;
+    const LOG10_E: T;
+    const LOG10_2: T;
+    const LN_2: T;
+    const LN_10: T;
+    const EPSILON: T;
+    const INFINITY: T;
+
+    type Bits: Copy + Eq + Hash + fmt::Debug;
+    fn to_bits(value: T) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> T;
+    fn total_cmp(a: &T, b: &T) -> Ordering;
+    fn next_up(value: T) -> T;
+    fn next_down(value: T) -> T;
+}
+
+macro_rules! consts_of {
+    ($($name:ident),*) => {
+        $(
+            const $name: T = <T as FloatConsts>::$name;
+        )*
+    };
+}
+
+impl<T: FloatBits> FloatExtras<T> for T {
+    const MIN: T = <T as Bounds>::MIN;
+    const MAX: T = <T as Bounds>::MAX;
+    consts_of!(PI, TAU, PHI, EGAMMA, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, FRAC_1_PI, FRAC_1_SQRT_PI, FRAC_2_PI, FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2, SQRT_3, FRAC_1_SQRT_3, E, LOG2_E, LOG2_10, LOG10_E, LOG10_2, LN_2, LN_10, EPSILON, INFINITY);
+
+    type Bits = <T as FloatBits>::Bits;
+    fn to_bits(value: T) -> Self::Bits {
+        FloatBits::to_bits(value)
+    }
+    fn from_bits(bits: Self::Bits) -> T {
+        FloatBits::from_bits(bits)
+    }
+    fn total_cmp(a: &T, b: &T) -> Ordering {
+        FloatingPoint::total_cmp(a, b)
+    }
+    fn next_up(value: T) -> T {
+        FloatingPoint::next_up(value)
+    }
+    fn next_down(value: T) -> T {
+        FloatingPoint::next_down(value)
+    }
+}
+
+/// A float that implements `num_traits::Float`, such as one from another
+/// crate, wrapped so it implements [`FloatingPoint`] and the algebra traits.
+/// The constants and bits come from `C`, see [`FloatExtras`], so
+/// `NumTraits<f64>` works as it is.
+///
+/// Everything else goes through `num_traits`, apart from the special
+/// functions, which use their generic implementations.
+pub struct NumTraits<T, C = T> {
+    pub value: T,
+    constants: PhantomData<C>,
+}
+
+impl<T, C> NumTraits<T, C> {
+    pub const fn new(value: T) -> Self {
+        Self { value, constants: PhantomData }
+    }
+}
+
+impl<T: Clone, C> Clone for NumTraits<T, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+impl<T: Copy, C> Copy for NumTraits<T, C> {}
+impl<T: PartialEq, C> PartialEq for NumTraits<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<T: PartialOrd, C> PartialOrd for NumTraits<T, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+impl<T: Default, C> Default for NumTraits<T, C> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+impl<T: fmt::Debug, C> fmt::Debug for NumTraits<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+impl<T: fmt::Display, C> fmt::Display for NumTraits<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+macro_rules! impl_num_traits_ops {
+    ($(($op:ident, $method:ident, $assign:ident, $assign_method:ident)),*) => {
+        $(
+            impl<T: Float, C> $op for NumTraits<T, C> {
+                type Output = Self;
+                fn $method(self, rhs: Self) -> Self {
+                    Self::new($op::$method(self.value, rhs.value))
+                }
+            }
+            impl<T: Float, C> $assign for NumTraits<T, C> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $op::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_num_traits_ops!((Add, add, AddAssign, add_assign), (Sub, sub, SubAssign, sub_assign), (Mul, mul, MulAssign, mul_assign), (Div, div, DivAssign, div_assign), (Rem, rem, RemAssign, rem_assign));
+
+impl<T: Float, C> Neg for NumTraits<T, C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.value)
+    }
+}
+
+macro_rules! impl_num_traits_properties {
+    ($($property:ident<$($op:ident),*>),*) => {
+        $(
+            impl<T: Float, C> $property<$($op),*> for NumTraits<T, C> {}
+        )*
+    };
+}
+
+impl_num_traits_properties!(
+    AssociativeOver<Addition>, CommutativeOver<Addition>, ClosedUnder<Addition>,
+    AssociativeOver<Multiplication>, CommutativeOver<Multiplication>, ClosedUnder<Multiplication>,
+    AssociativeOver<Subtraction>, ClosedUnder<Subtraction>, ClosedUnder<Division>,
+    Distributive<Multiplication, Addition>, Distributive<Multiplication, Subtraction>,
+    Distributive<Division, Addition>, Distributive<Division, Subtraction>
+);
+
+macro_rules! impl_num_traits_identity {
+    ($constant:ident, $check:ident, $($op:ident),*) => {
+        $(
+            impl<T: Float + $constant, C> Identity<$op> for NumTraits<T, C> {
+                const IDENTITY: Self = Self::new(T::$check);
+                fn is_identity(&self) -> bool {
+                    self.value == T::$check
+                }
+            }
+        )*
+    };
+}
+
+impl_num_traits_identity!(ConstZero, ZERO, Addition, Subtraction);
+impl_num_traits_identity!(ConstOne, ONE, Multiplication, Division);
+
+impl<T: Float + ConstZero, C> Absorption<Multiplication> for NumTraits<T, C> {
+    const ABSORBING: Self = Self::new(T::ZERO);
+    fn is_absorber(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+impl<T: Float, C> Inverse<Addition> for NumTraits<T, C> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+impl<T: Float, C> Inverse<Subtraction> for NumTraits<T, C> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+impl<T: Float, C> Inverse<Multiplication> for NumTraits<T, C> {
+    fn inverse(self) -> Self {
+        Self::new(self.value.recip())
+    }
+}
+
+impl<T: Float, C> PartialOrderSet for NumTraits<T, C> {
+    fn leq(&self, other: &Self) -> bool {
+        self.value <= other.value
+    }
+}
+impl<T: Float, C> Lattice for NumTraits<T, C> {
+    fn meet(self, other: Self) -> Self {
+        Self::new(self.value.min(other.value))
+    }
+    fn join(self, other: Self) -> Self {
+        Self::new(self.value.max(other.value))
+    }
+}
+impl<T: Float, C> DistributiveLattice for NumTraits<T, C> {}
+
+impl<T: Float, C: FloatExtras<T>> Bounds for NumTraits<T, C> {
+    fn min(self, other: Self) -> Self {
+        Self::new(self.value.min(other.value))
+    }
+    fn max(self, other: Self) -> Self {
+        Self::new(self.value.max(other.value))
+    }
+    const MIN: Self = Self::new(C::MIN);
+    const MAX: Self = Self::new(C::MAX);
+}
+
+macro_rules! wrapped_consts {
+    ($($name:ident),*) => {
+        $(
+            const $name: Self = Self::new(C::$name);
+        )*
+    };
+}
+
+impl<T: Float, C: FloatExtras<T>> FloatConsts for NumTraits<T, C> {
+    wrapped_consts!(PI, TAU, PHI, EGAMMA, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, FRAC_1_PI, FRAC_1_SQRT_PI, FRAC_2_PI, FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2, SQRT_3, FRAC_1_SQRT_3, E, LOG2_E, LOG2_10, LOG10_E, LOG10_2, LN_2, LN_10, EPSILON, INFINITY);
+}
+
+impl<T: Float, C> Signed for NumTraits<T, C> {
+    fn flip_sign(self) -> Self {
+        -self
+    }
+    fn is_negative(self) -> bool {
+        self.value.is_sign_negative()
+    }
+    fn is_positive(self) -> bool {
+        self.value.is_sign_positive()
+    }
+    fn abs(self) -> Self {
+        Self::new(self.value.abs())
+    }
+    fn copysign(self, sign: Self) -> Self {
+        Self::new(self.value.copysign(sign.value))
+    }
+}
+
+macro_rules! impl_num_traits_from {
+    ($($method:ident: $primitive:ident),*) => {
+        $(
+            fn $method(val: $primitive) -> Self {
+                Self::new(<T as NumCast>::from(val).unwrap_or_else(T::nan))
+            }
+        )*
+    };
+}
+
+impl<T: Float, C> FromPrimitive for NumTraits<T, C> {
+    impl_num_traits_from!(from_u8: u8, from_u16: u16, from_u32: u32, from_u64: u64, from_u128: u128, from_usize: usize, from_i8: i8, from_i16: i16, from_i32: i32, from_i64: i64, from_i128: i128, from_isize: isize, from_f32: f32, from_f64: f64);
+}
+
+/// `value as P`, which saturates, and takes NaN to zero.
+fn saturating_cast<T: Float, P: NumCast + Bounded + Zero>(value: T) -> P {
+    <P as NumCast>::from(value).unwrap_or_else(|| {
+        if value.is_nan() {
+            P::zero()
+        } else if value.is_sign_positive() {
+            P::max_value()
+        } else {
+            P::min_value()
+        }
+    })
+}
+
+macro_rules! impl_num_traits_into {
+    ($($method:ident: $primitive:ident),*) => {
+        $(
+            fn $method(self) -> $primitive {
+                saturating_cast(self.value)
+            }
+        )*
+    };
+}
+
+impl<T: Float, C> IntoPrimitive for NumTraits<T, C> {
+    impl_num_traits_into!(to_u8: u8, to_u16: u16, to_u32: u32, to_u64: u64, to_u128: u128, to_usize: usize, to_i8: i8, to_i16: i16, to_i32: i32, to_i64: i64, to_i128: i128, to_isize: isize);
+    fn to_f32(self) -> f32 {
+        self.value.to_f32().unwrap_or(f32::NAN)
+    }
+    fn to_f64(self) -> f64 {
+        self.value.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+impl<T: Float, C: FloatExtras<T>> ApproxEq for NumTraits<T, C> {
+    type Epsilon = Self;
+    fn default_tolerance() -> Tolerance<Self> {
+        Tolerance::Ulps(4)
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self>) -> bool {
+        let (a, b) = (self.value, other.value);
+        if a == b {
+            return true;
+        }
+        if !a.is_finite() || !b.is_finite() {
+            return false;
+        }
+        match tolerance {
+            Tolerance::Absolute(epsilon) => (a - b).abs() <= epsilon.value,
+            Tolerance::Relative(epsilon) => (a - b).abs() <= a.abs().max(b.abs()) * epsilon.value,
+            // there's no integer to take the difference of, so this counts
+            // the steps up from the smaller one instead
+            Tolerance::Ulps(max_ulps) => {
+                if a.is_sign_negative() != b.is_sign_negative() {
+                    return false;
+                }
+                let (mut low, high) = if a < b { (a, b) } else { (b, a) };
+                for _ in 0..max_ulps {
+                    low = C::next_up(low);
+                    if low >= high {
+                        return true;
+                    }
+                }
+                false
+            }
+        }
+    }
+}
+
+macro_rules! via_float {
+    ($($method:ident),*) => {
+        $(
+            fn $method(self) -> Self {
+                Self::new(Float::$method(self.value))
+            }
+        )*
+    };
+}
+
+impl<T: Float + ConstZero + ConstOne, C: FloatExtras<T>> FloatingPoint for NumTraits<T, C> {
+    via_float!(acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, exp, exp2, exp_m1, floor, fract, ln, ln_1p, log10, log2, recip, round, signum, sin, sinh, sqrt, tan, tanh, to_degrees, to_radians, trunc);
+    fn atan2(self, other: Self) -> Self {
+        Self::new(self.value.atan2(other.value))
+    }
+    fn hypot(self, other: Self) -> Self {
+        Self::new(self.value.hypot(other.value))
+    }
+    fn log(self, base: Self) -> Self {
+        Self::new(self.value.log(base.value))
+    }
+    fn powf(self, n: Self) -> Self {
+        Self::new(self.value.powf(n.value))
+    }
+    fn powi(self, n: i32) -> Self {
+        Self::new(self.value.powi(n))
+    }
+    fn sin_cos(self) -> (Self, Self)
+            where Self: Sized {
+        let (sin, cos) = self.value.sin_cos();
+        (Self::new(sin), Self::new(cos))
+    }
+    fn is_finite(self) -> bool {
+        self.value.is_finite()
+    }
+    fn is_infinite(self) -> bool {
+        self.value.is_infinite()
+    }
+    fn is_nan(self) -> bool {
+        self.value.is_nan()
+    }
+    fn is_normal(self) -> bool {
+        self.value.is_normal()
+    }
+    fn is_subnormal(self) -> bool {
+        self.value.is_subnormal()
+    }
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        C::total_cmp(&self.value, &other.value)
+    }
+    fn next_up(self) -> Self {
+        Self::new(C::next_up(self.value))
+    }
+    fn next_down(self) -> Self {
+        Self::new(C::next_down(self.value))
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::new(self.value.mul_add(a.value, b.value))
+    }
+    fn min_num(self, other: Self) -> Self {
+        Self::new(self.value.min(other.value))
+    }
+    fn max_num(self, other: Self) -> Self {
+        Self::new(self.value.max(other.value))
+    }
+}
+impl<T: Float + ConstZero + ConstOne, C: FloatExtras<T>> FloatBits for NumTraits<T, C> {
+    type Bits = C::Bits;
+    fn to_bits(self) -> C::Bits {
+        C::to_bits(self.value)
+    }
+    fn from_bits(bits: C::Bits) -> Self {
+        Self::new(C::from_bits(bits))
+    }
+}
+
+/// One of the numbers here, wrapped so it implements the `num_traits` traits
+/// for code written against those. Which ones depends on `T`: any [`Number`]
+/// gets `Num`, `Bounded` and the casts, [`Signed`] ones get
+/// `num_traits::Signed`, and [`FloatingPoint`]s get `Float` and `FloatConst`.
+///
+/// The casts fail when the value is out of range, like they do for the
+/// primitives, where [`FromPrimitive`] and [`IntoPrimitive`] would saturate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Existant<T>(pub T);
+
+impl<T: fmt::Display> fmt::Display for Existant<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_existant_ops {
+    ($(($op:ident, $method:ident, $assign:ident, $assign_method:ident)),*) => {
+        $(
+            impl<T: $op<Output = T>> $op for Existant<T> {
+                type Output = Self;
+                fn $method(self, rhs: Self) -> Self {
+                    Existant($op::$method(self.0, rhs.0))
+                }
+            }
+            impl<T: $assign> $assign for Existant<T> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    $assign::$assign_method(&mut self.0, rhs.0);
+                }
+            }
+        )*
+    };
+}
+
+impl_existant_ops!((Add, add, AddAssign, add_assign), (Sub, sub, SubAssign, sub_assign), (Mul, mul, MulAssign, mul_assign), (Div, div, DivAssign, div_assign), (Rem, rem, RemAssign, rem_assign));
+
+impl<T: Neg<Output = T>> Neg for Existant<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Existant(-self.0)
+    }
+}
+
+impl<T: Identity<Addition> + Add<Output = T>> Zero for Existant<T> {
+    fn zero() -> Self {
+        Existant(<T as Identity<Addition>>::IDENTITY)
+    }
+    fn is_zero(&self) -> bool {
+        <T as Identity<Addition>>::is_identity(&self.0)
+    }
+}
+impl<T: Identity<Addition> + Add<Output = T>> ConstZero for Existant<T> {
+    const ZERO: Self = Existant(<T as Identity<Addition>>::IDENTITY);
+}
+impl<T: Identity<Multiplication> + Mul<Output = T>> One for Existant<T> {
+    fn one() -> Self {
+        Existant(<T as Identity<Multiplication>>::IDENTITY)
+    }
+    fn is_one(&self) -> bool
+            where Self: PartialEq {
+        <T as Identity<Multiplication>>::is_identity(&self.0)
+    }
+}
+impl<T: Identity<Multiplication> + Mul<Output = T>> ConstOne for Existant<T> {
+    const ONE: Self = Existant(<T as Identity<Multiplication>>::IDENTITY);
+}
+
+impl<T: Bounds> Bounded for Existant<T> {
+    fn min_value() -> Self {
+        Existant(T::MIN)
+    }
+    fn max_value() -> Self {
+        Existant(T::MAX)
+    }
+}
+
+/// An error from `Num::from_str_radix` on an [`Existant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseNumberError {
+    Empty,
+    InvalidDigit,
+    /// The value doesn't fit in the type.
+    Overflow,
+}
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "cannot parse number from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::Overflow => "number too large to fit in target type",
+        })
+    }
+}
+impl core::error::Error for ParseNumberError {}
+
+/// `value * base ± digit`, or `None` if that's past the bounds of `T`. The
+/// digits are added toward the sign, so the most negative integer fits.
+fn push_digit<T: Number>(value: T, digit: T, base: T, negative: bool) -> Option<T> {
+    let zero = <T as Identity<Addition>>::IDENTITY;
+    if !negative {
+        (value <= (T::MAX - digit) / base).then(|| value * base + digit)
+    } else if T::MIN < zero {
+        // T::MIN + digit isn't positive, so the division rounds it up
+        (value >= (T::MIN + digit) / base).then(|| value * base - digit)
+    } else {
+        (value == zero && digit == zero).then_some(zero)
+    }
+}
+
+impl<T: Number> Num for Existant<T> {
+    type FromStrRadixErr = ParseNumberError;
+    /// Digits in any radix from 2 to 36, after an optional sign, with a
+    /// fraction after a `.` for types that can hold one. There's no `inf` or
+    /// `NaN`, and floats can come out an ulp or so off of what their own
+    /// parsers give.
+    ///
+    /// # Panics
+    /// When `radix` isn't in `2..=36`.
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, ParseNumberError> {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        let base = T::from_u32(radix);
+        let (negative, digits) = match str.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, str.strip_prefix('+').unwrap_or(str)),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(ParseNumberError::Empty);
+        }
+        let digit = |c: char| c.to_digit(radix).map(T::from_u32).ok_or(ParseNumberError::InvalidDigit);
+        let mut value = zero;
+        for c in whole.chars() {
+            value = push_digit(value, digit(c)?, base, negative).ok_or(ParseNumberError::Overflow)?;
+        }
+        if fraction.is_empty() {
+            return Ok(Existant(value));
+        }
+        if one / base == zero {
+            return Err(ParseNumberError::InvalidDigit);
+        }
+        let mut scale = one;
+        for c in fraction.chars() {
+            let digit = digit(c)?;
+            // digits past what the scale can reach are too small to matter
+            if scale <= T::MAX / base {
+                value = push_digit(value, digit, base, negative).ok_or(ParseNumberError::Overflow)?;
+                scale *= base;
+            }
+        }
+        Ok(Existant(value / scale))
+    }
+}
+
+const TWO_127: f64 = 170141183460469231731687303715884105728.0;
+const TWO_128: f64 = 2.0 * TWO_127;
+
+/// Whether `n` is between the bounds of `T`. The bounds are compared as
+/// integers wherever they fit in one, since through f64 `i64::MAX` rounds
+/// up to 2^63, and a bound that's a fraction truncates toward `n`'s side.
+fn fits_i128<T: Number>(n: i128) -> bool {
+    (T::MIN.to_f64() < -TWO_127 || T::MIN.to_i128() <= n) &&
+    (T::MAX.to_f64() > TWO_127 || n <= T::MAX.to_i128())
+}
+fn fits_u128<T: Number>(n: u128) -> bool {
+    (T::MIN.to_f64() <= 0.0 || T::MIN.to_u128() <= n) &&
+    (T::MAX.to_f64() > TWO_128 || n <= T::MAX.to_u128())
+}
+/// Whether `n` truncated is between the bounds of `T`, which is how
+/// `num_traits` casts floats too.
+fn fits_f64<T: Number>(n: f64) -> bool {
+    if (-TWO_127..TWO_127).contains(&n) {
+        fits_i128::<T>(n as i128)
+    } else if (0.0..TWO_128).contains(&n) {
+        fits_u128::<T>(n as u128)
+    } else if n < 0.0 {
+        T::MIN.to_f64() < -TWO_127 && T::MIN.to_f64() <= n
+    } else {
+        T::MAX.to_f64() > TWO_128 && n <= T::MAX.to_f64()
+    }
+}
+
+impl<T: Number> num_traits::FromPrimitive for Existant<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_i128(n as i128)
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_u128(n as u128)
+    }
+    fn from_i128(n: i128) -> Option<Self> {
+        fits_i128::<T>(n).then(|| Existant(T::from_i128(n)))
+    }
+    fn from_u128(n: u128) -> Option<Self> {
+        fits_u128::<T>(n).then(|| Existant(T::from_u128(n)))
+    }
+    /// Infinity and NaN only make it if `T` has them too.
+    fn from_f64(n: f64) -> Option<Self> {
+        if n.is_finite() {
+            return fits_f64::<T>(n).then(|| Existant(T::from_f64(n)));
+        }
+        let cast = T::from_f64(n);
+        let back = cast.to_f64();
+        (back == n || back.is_nan() && n.is_nan()).then_some(Existant(cast))
+    }
+}
+
+impl<T: Number> NumCast for Existant<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        use num_traits::FromPrimitive;
+        let float = n.to_f64()?;
+        // whole numbers go through the integers so they stay exact
+        if let Some(whole) = n.to_i128().filter(|&whole| whole as f64 == float) {
+            return Self::from_i128(whole);
+        }
+        if let Some(whole) = n.to_u128().filter(|&whole| whole as f64 == float) {
+            return Self::from_u128(whole);
+        }
+        Self::from_f64(float)
+    }
+}
+
+/// Whether `value` truncates to something from `min` up to but not
+/// including `end`. The bounds are compared in `T` when it can hold them,
+/// so a float just past `i64::MAX` doesn't round back into range.
+fn truncates_into<T: Number>(value: T, min: i128, end: f64) -> bool {
+    // Well inside the bounds there's nothing to round, and comparing against
+    // a bound in `T` would overflow a `Rational` with a denominator.
+    let float = value.to_f64();
+    if min as f64 / 2.0 <= float && float <= end / 2.0 {
+        return true;
+    }
+    let above = !fits_i128::<T>(min) || value >= T::from_i128(min) ||
+        min.checked_sub(1).is_some_and(|below| fits_i128::<T>(below) && value > T::from_i128(below));
+    let below = !fits_f64::<T>(end) || value < T::from_f64(end);
+    above && below
+}
+
+macro_rules! impl_existant_to {
+    ($($method:ident: $primitive:ident, $end:expr);*) => {
+        $(
+            fn $method(&self) -> Option<$primitive> {
+                truncates_into(self.0, $primitive::MIN as i128, $end).then(|| IntoPrimitive::$method(self.0))
+            }
+        )*
+    };
+}
+
+impl<T: Number> ToPrimitive for Existant<T> {
+    impl_existant_to!(
+        to_i64: i64, 9223372036854775808.0;
+        to_u64: u64, 18446744073709551616.0;
+        to_i128: i128, TWO_127;
+        to_u128: u128, TWO_128
+    );
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.0.to_f32())
+    }
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0.to_f64())
+    }
+}
+
+impl<T: Number + Signed> num_traits::Signed for Existant<T> {
+    fn abs(&self) -> Self {
+        Existant(self.0.abs())
+    }
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.0 <= other.0 { Self::zero() } else { *self - *other }
+    }
+    /// NaN stays NaN.
+    fn signum(&self) -> Self {
+        if self.0 > <T as Identity<Addition>>::IDENTITY {
+            Self::one()
+        } else if self.0 < <T as Identity<Addition>>::IDENTITY {
+            -Self::one()
+        } else {
+            *self
+        }
+    }
+    fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+macro_rules! via_floating_point {
+    ($($method:ident),*) => {
+        $(
+            fn $method(self) -> Self {
+                Existant(FloatingPoint::$method(self.0))
+            }
+        )*
+    };
+}
+
+impl<T: FloatingPoint> Float for Existant<T> {
+    via_floating_point!(floor, ceil, round, trunc, fract, signum, recip, sqrt, exp, exp2, ln, log2, log10, cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, tanh, asinh, acosh, atanh, to_degrees, to_radians);
+    fn nan() -> Self {
+        Existant(T::nan())
+    }
+    fn infinity() -> Self {
+        Existant(T::INFINITY)
+    }
+    fn neg_infinity() -> Self {
+        Existant(-T::INFINITY)
+    }
+    fn neg_zero() -> Self {
+        -Self::zero()
+    }
+    fn min_value() -> Self {
+        Existant(T::MIN)
+    }
+    /// Worked out from the precision for a type with subnormals, which
+    /// assumes it's laid out like the IEEE 754 floats.
+    fn min_positive_value() -> Self {
+        let smallest = Self::zero().0.next_up();
+        if !smallest.is_subnormal() {
+            return Existant(smallest);
+        }
+        let (_, bottom) = smallest.frexp();
+        let (_, precision) = T::EPSILON.frexp();
+        Existant(Self::one().0.ldexp(bottom - precision))
+    }
+    fn epsilon() -> Self {
+        Existant(T::EPSILON)
+    }
+    fn max_value() -> Self {
+        Existant(T::MAX)
+    }
+    fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+    fn is_infinite(self) -> bool {
+        self.0.is_infinite()
+    }
+    fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+    fn is_normal(self) -> bool {
+        self.0.is_normal()
+    }
+    fn is_subnormal(self) -> bool {
+        self.0.is_subnormal()
+    }
+    fn classify(self) -> FpCategory {
+        if self.0.is_nan() {
+            FpCategory::Nan
+        } else if self.0.is_infinite() {
+            FpCategory::Infinite
+        } else if self.is_zero() {
+            FpCategory::Zero
+        } else if self.0.is_subnormal() {
+            FpCategory::Subnormal
+        } else {
+            FpCategory::Normal
+        }
+    }
+    fn abs(self) -> Self {
+        Existant(Signed::abs(self.0))
+    }
+    fn is_sign_positive(self) -> bool {
+        self.0.is_positive()
+    }
+    fn is_sign_negative(self) -> bool {
+        self.0.is_negative()
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Existant(self.0.mul_add(a.0, b.0))
+    }
+    fn powi(self, n: i32) -> Self {
+        Existant(self.0.powi(n))
+    }
+    fn powf(self, n: Self) -> Self {
+        Existant(self.0.powf(n.0))
+    }
+    fn log(self, base: Self) -> Self {
+        Existant(self.0.log(base.0))
+    }
+    fn max(self, other: Self) -> Self {
+        Existant(self.0.max_num(other.0))
+    }
+    fn min(self, other: Self) -> Self {
+        Existant(self.0.min_num(other.0))
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        Existant(self.0.abs_sub(other.0))
+    }
+    fn hypot(self, other: Self) -> Self {
+        Existant(self.0.hypot(other.0))
+    }
+    fn atan2(self, other: Self) -> Self {
+        Existant(self.0.atan2(other.0))
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.0.sin_cos();
+        (Existant(sin), Existant(cos))
+    }
+    /// Halves e^|x| as e^(|x|/2) times half of that, so it only overflows
+    /// when the result does. Taking ln 2 off the exponent instead would do
+    /// the same, but loses precision to rounding the difference.
+    fn cosh(self) -> Self {
+        let two = T::from_u8(2);
+        let x = self.0.abs();
+        let half = (x / two).exp();
+        Existant(half * (half / two) + (-x).exp() / two)
+    }
+    /// The mantissa is at most 64 bits, and infinity and NaN come back with
+    /// a mantissa of zero.
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let sign = if self.0.is_negative() { -1 } else { 1 };
+        if self.is_zero() || !self.0.is_finite() {
+            return (0, 0, sign);
+        }
+        let (_, precision) = T::EPSILON.frexp();
+        let digits = 2 - precision;
+        let (mantissa, exponent) = Signed::abs(self.0).frexp();
+        (mantissa.ldexp(digits).to_u64(), (exponent - digits) as i16, sign)
+    }
+    fn copysign(self, sign: Self) -> Self {
+        Existant(self.0.copysign(sign.0))
+    }
+}
+
+macro_rules! impl_existant_consts {
+    ($($name:ident),*) => {
+        $(
+            fn $name() -> Self {
+                Existant(T::$name)
+            }
+        )*
+    };
+}
+
+impl<T: FloatConsts> FloatConst for Existant<T> {
+    impl_existant_consts!(E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG2_E, PI, SQRT_2, TAU, LOG10_2, LOG2_10);
+}
+
+impl<T: FloatingPoint> TotalOrder for Existant<T> {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
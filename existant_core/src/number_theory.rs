@@ -0,0 +1,305 @@
+//! Number theory on the [`Integer`] types, written against the operators
+//! every [`Number`](crate::Number) has so it works the same on every width of
+//! primitive and on [`BigUint`](crate::BigUint) and [`BigInt`](crate::BigInt).
+//!
+//! Nothing here overflows as long as the modulus fits in the type, since
+//! products that might are done by doubling and adding instead.
+
+use crate::{Addition, Identity, Integer, Multiplication, Signed};
+
+/// The primes up to 97, which fit in every integer type. Trial division by
+/// these comes before anything smarter.
+const SMALL_PRIMES: [u8; 25] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+
+/// Testing with every prime up to 41 as a base is known to be exact below
+/// this, which covers all of `u64` and the first 81 bits.
+const DETERMINISTIC_BOUND: u128 = 3_317_044_064_679_887_385_961_981;
+
+fn zero<T: Integer>() -> T {
+    <T as Identity<Addition>>::IDENTITY
+}
+fn one<T: Integer>() -> T {
+    <T as Identity<Multiplication>>::IDENTITY
+}
+fn two<T: Integer>() -> T {
+    T::from_u8(2)
+}
+
+/// Greatest common divisor, which is never negative.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(b)
+}
+
+/// Least common multiple, which is never negative.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    a.lcm(b)
+}
+
+/// The gcd `g` of `a` and `b`, along with `x` and `y` such that
+/// `ax + by = g`. The gcd is never negative.
+///
+/// # Panics
+/// When the gcd or a coefficient doesn't fit in `T`, which can happen when
+/// `a` or `b` is `T::MIN`, as in `extended_gcd(i32::MIN, 0)`. That's an
+/// overflow like any other, so release builds of the primitives wrap.
+pub fn extended_gcd<T: Integer + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (one(), zero());
+    let (mut y0, mut y1) = (zero(), one());
+    while r1 != zero() {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < zero() {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// `a` reduced into `[0, modulus)`, even when it's negative.
+///
+/// # Panics
+/// When `modulus` isn't positive.
+pub fn modulo<T: Integer>(a: T, modulus: T) -> T {
+    assert!(modulus > zero(), "the modulus has to be positive");
+    let r = a % modulus;
+    if r < zero() { r + modulus } else { r }
+}
+
+/// `(a + b) mod modulus`, for `a` and `b` already in `[0, modulus)`.
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    if a >= modulus - b { a - (modulus - b) } else { a + b }
+}
+
+/// `(a - b) mod modulus`, for `a` and `b` already in `[0, modulus)`.
+fn sub_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    if a >= b { a - b } else { a + (modulus - b) }
+}
+
+/// `ab mod modulus`, even when `ab` would overflow, see
+/// [`Integer::mul_mod`].
+pub fn mod_mul<T: Integer>(a: T, b: T, modulus: T) -> T {
+    modulo(a, modulus).mul_mod(modulo(b, modulus), modulus)
+}
+
+/// `base` to the power of `exponent`, mod `modulus`, by squaring.
+///
+/// # Panics
+/// When `exponent` is negative or `modulus` isn't positive.
+pub fn mod_pow<T: Integer>(base: T, mut exponent: T, modulus: T) -> T {
+    assert!(exponent >= zero(), "can't raise to a negative power");
+    let mut base = modulo(base, modulus);
+    let mut result = modulo(one(), modulus);
+    while exponent > zero() {
+        if exponent % two() == one() {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent /= two();
+    }
+    result
+}
+
+/// The `x` in `[0, modulus)` with `ax ≡ 1 (mod modulus)`, which only exists
+/// when `a` and `modulus` are coprime. The coefficients of the extended
+/// Euclidean algorithm are kept reduced, so this works on unsigned types.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Option<T> {
+    let (mut r0, mut r1) = (modulus, modulo(a, modulus));
+    let (mut t0, mut t1) = (zero(), modulo(one(), modulus));
+    while r1 != zero() {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, sub_mod(t0, mod_mul(q, t1, modulus), modulus));
+    }
+    (r0 == one()).then_some(t0)
+}
+
+/// Whether `n` is prime. After trial division, this runs Miller-Rabin with
+/// every prime up to 41 as a base, which has no false positives below
+/// 3.3×10²⁴. Past that it keeps going through every prime up to 97, and a
+/// composite that passes all 25 bases is possible but hasn't been found.
+pub fn is_prime<T: Integer>(n: T) -> bool {
+    if n < two() {
+        return false;
+    }
+    for p in SMALL_PRIMES {
+        let p = T::from_u8(p);
+        if n == p {
+            return true;
+        }
+        if n % p == zero() {
+            return false;
+        }
+    }
+    // no factor up to 97, so anything below 97² is prime
+    if n / T::from_u8(97) < T::from_u8(97) {
+        return true;
+    }
+    let deterministic = T::from_u128(n.to_u128()) == n && n.to_u128() < DETERMINISTIC_BOUND;
+    let bases = if deterministic { &SMALL_PRIMES[..13] } else { &SMALL_PRIMES[..] };
+    bases.iter().all(|base| is_strong_probable_prime(n, T::from_u8(*base)))
+}
+
+/// One round of Miller-Rabin: with `n - 1 = d·2ˢ` for odd `d`, a prime `n`
+/// has `base^d ≡ 1` or `base^(d·2ʳ) ≡ -1` for some `r < s`.
+fn is_strong_probable_prime<T: Integer>(n: T, base: T) -> bool {
+    let n_minus_one = n - one();
+    let mut d = n_minus_one;
+    let mut s = 0u32;
+    while d % two() == zero() {
+        d /= two();
+        s += 1;
+    }
+    let mut x = mod_pow(base, d, n);
+    if x == one() || x == n_minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = mod_mul(x, x, n);
+        if x == n_minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+/// The largest integer whose square is at most `n`.
+///
+/// # Panics
+/// When `n` is negative.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= zero(), "can't take the square root of a negative number");
+    if n < two() {
+        return n;
+    }
+    // Newton's method from above, which only ever goes down until it's there
+    let mut x = n / two() + one();
+    loop {
+        let next = (x + n / x) / two();
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// The integer cube root, rounded towards zero.
+pub fn icbrt<T: Integer>(n: T) -> T {
+    if n < zero() {
+        // negating the root of n + 1 rather than n keeps MIN from overflowing,
+        // and then the root might be one further out, if its cube isn't
+        // past n, which is checked by dividing so the cube can't overflow
+        let root = zero::<T>() - icbrt(zero::<T>() - (n + one()));
+        let next = root - one();
+        let magnitude = zero::<T>() - next;
+        return if next >= n / magnitude / magnitude { next } else { root };
+    }
+    if n < two() {
+        return n;
+    }
+    // the square root is already above the cube root, and its square fits
+    let mut x = isqrt(n);
+    loop {
+        let next = (two::<T>() * x + n / (x * x)) / T::from_u8(3);
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// Calls `f` with every prime factor of `n`, as many times as it divides
+/// `n`, in no particular order. Small factors come from trial division and
+/// the rest from Pollard's rho. Nothing is called for `n` below 2.
+pub fn for_each_prime_factor<T: Integer>(mut n: T, mut f: impl FnMut(T)) {
+    if n < two() {
+        return;
+    }
+    for p in SMALL_PRIMES {
+        let p = T::from_u8(p);
+        while n % p == zero() {
+            f(p);
+            n /= p;
+        }
+    }
+    split(n, &mut f);
+}
+
+fn split<T: Integer>(n: T, f: &mut impl FnMut(T)) {
+    if n == one() {
+        return;
+    }
+    if is_prime(n) {
+        f(n);
+        return;
+    }
+    let divisor = pollard_rho(n);
+    split(divisor, f);
+    split(n / divisor, f);
+}
+
+/// A non trivial divisor of the composite `n`, found by Brent's version of
+/// Pollard's rho, which batches up the differences to take fewer gcds.
+fn pollard_rho<T: Integer>(n: T) -> T {
+    const BATCH: u32 = 64;
+    let mut c = one::<T>();
+    loop {
+        let step = |x: T| add_mod(mod_mul(x, x, n), c, n);
+        let (mut x, mut y, mut product) = (two::<T>(), two::<T>(), one::<T>());
+        let mut saved = y;
+        let mut divisor = one();
+        let mut length = 1u32;
+        'search: while divisor == one() {
+            x = y;
+            for _ in 0..length {
+                y = step(y);
+            }
+            let mut done = 0;
+            while done < length {
+                saved = y;
+                for _ in 0..BATCH.min(length - done) {
+                    y = step(y);
+                    product = mod_mul(product, if x > y { x - y } else { y - x }, n);
+                }
+                divisor = product.gcd(n);
+                if divisor != one() {
+                    break 'search;
+                }
+                done += BATCH;
+            }
+            length *= 2;
+        }
+        if divisor == n {
+            // the batch went past the factor, so retrace it one step at a time
+            divisor = one();
+            while divisor == one() {
+                saved = step(saved);
+                divisor = (if x > saved { x - saved } else { saved - x }).gcd(n);
+            }
+        }
+        if divisor != n {
+            return divisor;
+        }
+        c += one();
+    }
+}
+
+/// The prime factors of `n` from smallest to largest, each with how many
+/// times it divides `n`.
+#[cfg(feature = "alloc")]
+pub fn factorize<T: Integer>(n: T) -> alloc::vec::Vec<(T, u32)> {
+    let mut factors = alloc::vec::Vec::new();
+    for_each_prime_factor(n, |p| factors.push(p));
+    factors.sort_unstable();
+    let mut counted: alloc::vec::Vec<(T, u32)> = alloc::vec::Vec::new();
+    for p in factors {
+        match counted.last_mut() {
+            Some((last, count)) if *last == p => *count += 1,
+            _ => counted.push((p, 1)),
+        }
+    }
+    counted
+}
@@ -37,6 +37,17 @@ macro_rules! impl_bounds {
     };
 
 }
+macro_rules! impl_integer {
+    ($($structure:tt: $wide:tt),*) => {
+        $(
+            impl Integer for $structure {
+                fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                    (self as $wide * rhs as $wide % modulus as $wide) as Self
+                }
+            }
+        )*
+    };
+}
 macro_rules! impl_bounds_ord {
     ($($structure:tt),*) => {
         $(
@@ -87,6 +98,8 @@ pub trait Signed: core::ops::Neg<Output = Self> + Sized + Copy {
     fn is_positive(self) -> bool;
     fn abs(self) -> Self;
     fn flip_sign(self) -> Self;
+    /// The magnitude of `self` with the sign of `sign`. Floats go by the sign
+    /// bit, so the sign of a zero or a NaN counts too.
     fn copysign(self, sign: Self) -> Self {
         if self.is_negative() == sign.is_negative() {
             self
@@ -98,7 +111,7 @@ pub trait Signed: core::ops::Neg<Output = Self> + Sized + Copy {
 impl_signed!(i8, i16, i32, i64, i128, isize);
 impl Signed for f32 {
     fn flip_sign(self) -> Self {
-        self*-1.0
+        -self
     }
     fn is_negative(self) -> bool {
         f32::is_sign_negative(self)
@@ -109,10 +122,13 @@ impl Signed for f32 {
     fn abs(self) -> Self {
         f32::abs(self)
     }
+    fn copysign(self, sign: Self) -> Self {
+        f32::copysign(self, sign)
+    }
 }
 impl Signed for f64 {
     fn flip_sign(self) -> Self {
-        self*-1.0
+        -self
     }
     fn is_negative(self) -> bool {
         f64::is_sign_negative(self)
@@ -123,6 +139,9 @@ impl Signed for f64 {
     fn abs(self) -> Self {
         f64::abs(self)
     }
+    fn copysign(self, sign: Self) -> Self {
+        f64::copysign(self, sign)
+    }
 }
 
 pub trait Number 
@@ -139,6 +158,66 @@ impl<T> Number for T
     Identity<Addition> + Identity<Multiplication> + 
     Bounds + 
     FromPrimitive + IntoPrimitive {}
+/// Implemented for the integer types, signed or not, where division
+/// truncates and equality is exact.
+pub trait Integer: Number + Eq + Ord {
+    /// Greatest common divisor, which is never negative. The gcd of 0 and 0
+    /// is 0.
+    fn gcd(self, other: Self) -> Self {
+        let zero = <Self as Identity<Addition>>::IDENTITY;
+        let (mut a, mut b) = (self, other);
+        while b != zero {
+            (a, b) = (b, a % b);
+        }
+        if a < zero { zero - a } else { a }
+    }
+    /// Least common multiple, which is never negative.
+    fn lcm(self, other: Self) -> Self {
+        let zero = <Self as Identity<Addition>>::IDENTITY;
+        if self == zero || other == zero {
+            return zero;
+        }
+        let lcm = self / self.gcd(other) * other;
+        if lcm < zero { zero - lcm } else { lcm }
+    }
+    /// `self * rhs % modulus`, for `self` and `rhs` in `[0, modulus)`, even
+    /// when the product overflows. The primitives multiply in a wider type,
+    /// and anything else builds the product up by doubling and adding,
+    /// which never goes past `2 * modulus`.
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        let zero = <Self as Identity<Addition>>::IDENTITY;
+        let one = <Self as Identity<Multiplication>>::IDENTITY;
+        let two = one + one;
+        let add_mod = |a: Self, b: Self| if a >= modulus - b { a - (modulus - b) } else { a + b };
+        let (mut a, mut b) = (self, rhs);
+        if b == zero || a <= Self::MAX / b {
+            return a * b % modulus;
+        }
+        let mut result = zero;
+        while b > zero {
+            if b % two == one {
+                result = add_mod(result, a);
+            }
+            a = add_mod(a, a);
+            b /= two;
+        }
+        result
+    }
+}
+impl_integer!(u8: u16, u16: u32, u32: u64, u64: u128, usize: u128, i8: i16, i16: i32, i32: i64, i64: i128, isize: i128);
+// Nothing wider is built in, so these go through 256 bits of limbs.
+impl Integer for u128 {
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        let wide = <crate::U256 as FromPrimitive>::from_u128;
+        (wide(self) * wide(rhs) % wide(modulus)).to_u128()
+    }
+}
+impl Integer for i128 {
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        (self as u128).mul_mod(rhs as u128, modulus as u128) as i128
+    }
+}
+
 /// This trait is implemented for all floating point types:
 /// [`f32`], [`f64`]
 pub trait FloatingPoint: Number + FloatConsts + Signed + FromPrimitive + UniversalOperationsOn<Self> {
@@ -183,7 +262,212 @@ pub trait FloatingPoint: Number + FloatConsts + Signed + FromPrimitive + Univers
     fn is_nan(self) -> bool;
     fn is_normal(self) -> bool;
     fn is_subnormal(self) -> bool;
+
+    // These have generic implementations too, which f32 and f64 replace with
+    // the math library where it does better.
+
+    /// An order over every value, unlike [`PartialOrd`]. For floats this is
+    /// IEEE 754's totalOrder, which puts -0 below +0 and NaNs at either end
+    /// depending on their sign. The generic one doesn't look at NaN payloads,
+    /// so it calls any two NaNs of the same sign equal.
+    fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let rank = |x: &Self| match (x.is_nan(), x.is_negative()) {
+            (true, true) => 0,
+            (false, _) => 1,
+            (true, false) => 2,
+        };
+        rank(self).cmp(&rank(other)).then_with(|| {
+            self.partial_cmp(other)
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then(other.is_negative().cmp(&self.is_negative()))
+        })
+    }
+    /// The smallest value greater than `self`. Infinity and NaN stay as they
+    /// are. The generic one searches for the step by halving, so it's slow
+    /// compared to stepping the bits.
+    fn next_up(self) -> Self {
+        let zero = <Self as Identity<Addition>>::IDENTITY;
+        let two = Self::from_u8(2);
+        if self.is_nan() || self == Self::INFINITY {
+            return self;
+        }
+        if self == -Self::INFINITY {
+            return <Self as Bounds>::MIN;
+        }
+        let mut step = if self == zero { <Self as Identity<Multiplication>>::IDENTITY } else { self.abs() };
+        while self + step / two > self {
+            step /= two;
+        }
+        // from the smallest negative value that's -0, like it is for f64
+        let next = self + step;
+        if next == zero { zero.copysign(self) } else { next }
+    }
+    /// The largest value less than `self`. Negative infinity and NaN stay as
+    /// they are.
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
+    /// Not a number, which is what the functions here return outside of
+    /// their domain. Types without one, like [`Fixed`](crate::Fixed), get
+    /// zero instead.
+    fn nan() -> Self {
+        Self::INFINITY - Self::INFINITY
+    }
+
+    /// `self * a + b`, rounded once where the type supports it.
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+    /// The quotient of Euclidean division, `self / rhs` rounded so that
+    /// [`FloatingPoint::rem_euclid`] is never negative.
+    fn div_euclid(self, rhs: Self) -> Self {
+        let zero = <Self as Identity<Addition>>::IDENTITY;
+        let one = <Self as Identity<Multiplication>>::IDENTITY;
+        let quotient = (self / rhs).trunc();
+        if self % rhs < zero {
+            return if rhs > zero { quotient - one } else { quotient + one };
+        }
+        quotient
+    }
+    /// The remainder of Euclidean division, which is in `[0, |rhs|)` apart
+    /// from rounding.
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self % rhs;
+        if remainder < <Self as Identity<Addition>>::IDENTITY { remainder + rhs.abs() } else { remainder }
+    }
+    /// `self` limited to `[min, max]`. NaN stays NaN.
+    ///
+    /// # Panics
+    /// When `min` is above `max`, or either of them is NaN.
+    fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "min is greater than max, or either is NaN");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+    /// The positive difference, `self - other` if that's positive and zero
+    /// otherwise, or NaN if either is.
+    fn abs_sub(self, other: Self) -> Self {
+        if self <= other { <Self as Identity<Addition>>::IDENTITY } else { self - other }
+    }
+    /// `self * 2ⁿ`.
+    fn ldexp(self, n: i32) -> Self {
+        // In two halves so the power of two doesn't overflow before the
+        // product does.
+        let two = Self::from_u8(2);
+        self * two.powi(n / 2) * two.powi(n - n / 2)
+    }
+    /// Splits `self` into a mantissa with a magnitude in `[0.5, 1)` and an
+    /// exponent, such that `self = mantissa * 2^exponent`. Zero, infinity
+    /// and NaN come back as they are, with an exponent of zero.
+    fn frexp(self) -> (Self, i32) {
+        let zero = <Self as Identity<Addition>>::IDENTITY;
+        if self == zero || !self.is_finite() {
+            return (self, 0);
+        }
+        let half = Self::from_f32(0.5);
+        let one = <Self as Identity<Multiplication>>::IDENTITY;
+        let mut exponent = self.abs().log2().floor().to_i32() + 1;
+        let mut mantissa = self.ldexp(-exponent);
+        // The logarithm can round to either side of a power of two.
+        while mantissa.abs() >= one {
+            mantissa /= Self::from_u8(2);
+            exponent += 1;
+        }
+        while mantissa.abs() < half {
+            mantissa *= Self::from_u8(2);
+            exponent -= 1;
+        }
+        (mantissa, exponent)
+    }
+    /// The smaller of the two, ignoring NaN, so NaN only comes back when both
+    /// are. This is IEEE 754's minNum, which [`Bounds::min`] doesn't promise
+    /// for every type.
+    fn min_num(self, other: Self) -> Self {
+        if self.is_nan() || other < self { other } else { self }
+    }
+    /// The larger of the two, ignoring NaN, so NaN only comes back when both
+    /// are.
+    fn max_num(self, other: Self) -> Self {
+        if self.is_nan() || other > self { other } else { self }
+    }
+
+    // The special functions have generic implementations written in terms of
+    // the methods above, which a type can replace with something better.
+
+    /// The gamma function, which is `(n - 1)!` at every positive integer `n`.
+    fn gamma(self) -> Self {
+        crate::special::gamma(self)
+    }
+    /// The natural logarithm of the absolute value of [`FloatingPoint::gamma`],
+    /// which stays in range long after the gamma function overflows.
+    fn ln_gamma(self) -> Self {
+        crate::special::ln_gamma(self)
+    }
+    /// The logarithmic derivative of the gamma function, `Γ'(x)/Γ(x)`.
+    fn digamma(self) -> Self {
+        crate::special::digamma(self)
+    }
+    /// The beta function `B(a, b) = Γ(a)Γ(b)/Γ(a + b)`, with `self` as `a`.
+    fn beta(self, b: Self) -> Self {
+        crate::special::beta(self, b)
+    }
+    /// The regularized incomplete beta function `Iₓ(a, b)`, with `self` as
+    /// `x`, which is the cumulative distribution function of the beta
+    /// distribution. NaN unless `a` and `b` are positive and `x` is in
+    /// `[0, 1]`.
+    fn incomplete_beta(self, a: Self, b: Self) -> Self {
+        crate::special::incomplete_beta(self, a, b)
+    }
+    /// The error function `2/√π ∫₀ˣ e^(-t²) dt`.
+    fn erf(self) -> Self {
+        crate::special::erf(self)
+    }
+    /// The complementary error function `1 - erf(x)`, without losing
+    /// precision as `erf(x)` gets close to 1.
+    fn erfc(self) -> Self {
+        crate::special::erfc(self)
+    }
+    /// The inverse of [`FloatingPoint::erf`], which is infinite at 1 and -1
+    /// and NaN past them.
+    fn erf_inv(self) -> Self {
+        crate::special::erf_inv(self)
+    }
+    /// The Bessel function of the first kind `Jₙ(x)`, with `self` as `x`.
+    fn bessel_j(self, n: i32) -> Self {
+        crate::special::bessel_j(self, n)
+    }
+    /// The Bessel function of the second kind `Yₙ(x)`, with `self` as `x`,
+    /// which is NaN for negative `x`.
+    fn bessel_y(self, n: i32) -> Self {
+        crate::special::bessel_y(self, n)
+    }
+    /// The complete elliptic integral of the first kind `K(m)`, taking the
+    /// parameter `m = k²` rather than the modulus `k`.
+    fn elliptic_k(self) -> Self {
+        crate::special::elliptic_k(self)
+    }
+    /// The complete elliptic integral of the second kind `E(m)`, taking the
+    /// parameter `m = k²`. An ellipse with semi-major axis `a` and
+    /// eccentricity `e` has a perimeter of `4aE(e²)`.
+    fn elliptic_e(self) -> Self {
+        crate::special::elliptic_e(self)
+    }
+}
+/// The raw representation of a [`FloatingPoint`], for the types that have
+/// one worth exposing.
+pub trait FloatBits: FloatingPoint {
+    /// Whatever the type keeps in memory, such as [`u32`] for [`f32`].
+    type Bits: Copy + Eq + core::hash::Hash + core::fmt::Debug;
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
 }
+
 impl FloatingPoint for f32 {
     fn acos(self) -> Self {
         crate::cmath::acosf(self)
@@ -267,7 +551,19 @@ impl FloatingPoint for f32 {
         crate::cmath::powf(self, n)
     }
     fn powi(self, n: i32) -> Self {
-        crate::cmath::powf(self, n as f32)
+        // Past 2^24 `n` no longer fits in an f32, and rounding it can flip
+        // its parity, so the remainder is raised separately. Rounding toward
+        // zero keeps both halves the same sign, so 0 * inf can't come up.
+        let mut high = n as f32;
+        if (high as i64).abs() > (n as i64).abs() {
+            high = f32::from_bits(high.to_bits() - 1);
+        }
+        let low = (n as i64 - high as i64) as f32;
+        if low == 0.0 {
+            crate::cmath::powf(self, high)
+        } else {
+            crate::cmath::powf(self, high) * crate::cmath::powf(self, low)
+        }
     }
     fn recip(self) -> Self {
         <f32 as Identity<Multiplication>>::IDENTITY/self
@@ -308,6 +604,51 @@ impl FloatingPoint for f32 {
     fn trunc(self) -> Self {
         crate::cmath::truncf(self)
     }
+    fn erf(self) -> Self {
+        crate::cmath::erff(self)
+    }
+    fn erfc(self) -> Self {
+        crate::cmath::erfcf(self)
+    }
+    fn gamma(self) -> Self {
+        crate::cmath::tgammaf(self)
+    }
+    fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        f32::total_cmp(self, other)
+    }
+    fn next_up(self) -> Self {
+        f32::next_up(self)
+    }
+    fn next_down(self) -> Self {
+        f32::next_down(self)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        crate::cmath::fmaf(self, a, b)
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        crate::cmath::fdimf(self, other)
+    }
+    fn ldexp(self, n: i32) -> Self {
+        crate::cmath::ldexpf(self, n)
+    }
+    fn frexp(self) -> (Self, i32) {
+        crate::cmath::frexpf(self)
+    }
+    fn min_num(self, other: Self) -> Self {
+        f32::min(self, other)
+    }
+    fn max_num(self, other: Self) -> Self {
+        f32::max(self, other)
+    }
+}
+impl FloatBits for f32 {
+    type Bits = u32;
+    fn to_bits(self) -> Self::Bits {
+        f32::to_bits(self)
+    }
+    fn from_bits(bits: Self::Bits) -> Self {
+        f32::from_bits(bits)
+    }
 }
 impl FloatingPoint for f64 {
     fn acos(self) -> Self {
@@ -432,10 +773,61 @@ impl FloatingPoint for f64 {
     fn trunc(self) -> Self {
         crate::cmath::trunc(self)
     }
+    fn erf(self) -> Self {
+        crate::cmath::erf(self)
+    }
+    fn erfc(self) -> Self {
+        crate::cmath::erfc(self)
+    }
+    fn gamma(self) -> Self {
+        crate::cmath::tgamma(self)
+    }
+    fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        f64::total_cmp(self, other)
+    }
+    fn next_up(self) -> Self {
+        f64::next_up(self)
+    }
+    fn next_down(self) -> Self {
+        f64::next_down(self)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        crate::cmath::fma(self, a, b)
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        crate::cmath::fdim(self, other)
+    }
+    fn ldexp(self, n: i32) -> Self {
+        crate::cmath::ldexp(self, n)
+    }
+    fn frexp(self) -> (Self, i32) {
+        crate::cmath::frexp(self)
+    }
+    fn min_num(self, other: Self) -> Self {
+        f64::min(self, other)
+    }
+    fn max_num(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+}
+impl FloatBits for f64 {
+    type Bits = u64;
+    fn to_bits(self) -> Self::Bits {
+        f64::to_bits(self)
+    }
+    fn from_bits(bits: Self::Bits) -> Self {
+        f64::from_bits(bits)
+    }
 }
 
 pub trait Bounds {
+    /// The smaller of the two. For [`f32`] and [`f64`] a NaN is ignored
+    /// unless both are NaN, but other types can do as they please with
+    /// whatever they have that's unordered, see [`FloatingPoint::min_num`]
+    /// when that matters.
     fn min(self, other: Self) -> Self;
+    /// The larger of the two, with NaN treated the same as in
+    /// [`Bounds::min`].
     fn max(self, other: Self) -> Self;
     
     const MIN: Self;
@@ -0,0 +1,519 @@
+//! Integers that say what happens when arithmetic overflows, instead of
+//! panicking in debug builds and wrapping in release ones. Each only carries
+//! the algebra markers its arithmetic really has:
+//!
+//! * [`Wrapping`] is the ring of integers modulo 2^n, so it's a
+//!   [`CommutativeRing`](crate::CommutativeRing).
+//! * [`Saturating`] over an unsigned type is a commutative
+//!   [`Semiring`](crate::Semiring). Over a signed one neither operation is
+//!   associative, `(MAX + 1) + -1` isn't `MAX + (1 + -1)`, so all that's left
+//!   is closure, commutativity and the identities.
+//! * [`Checked`] turns into an overflow marker that sticks, like a NaN. Its
+//!   unsigned addition is a commutative monoid, but multiplication isn't
+//!   associative since `(MAX * 2) * 0` stays overflowed while `MAX * (2 * 0)`
+//!   is zero, and over a signed type addition isn't either.
+
+use core::{cmp::Ordering, fmt};
+
+use crate::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FromPrimitive, Identity, Integer, IntoPrimitive, Inverse, Multiplication, Signed, Subtraction};
+
+/// An integer where arithmetic wraps around on overflow.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wrapping<T>(pub T);
+
+/// An integer where arithmetic clamps to the bounds of `T` on overflow.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating<T>(pub T);
+
+/// An integer that becomes `Checked(None)` once any operation on it
+/// overflows or divides by zero, and stays that way. An overflowed value
+/// isn't comparable to anything but another overflowed value.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Checked<T>(pub Option<T>);
+
+impl<T> Checked<T> {
+    pub const fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+    pub const fn is_overflowed(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Wrapping<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for Wrapping<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+impl<T: fmt::Display> fmt::Display for Checked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(value) => fmt::Display::fmt(value, f),
+            None => f.write_str("overflow"),
+        }
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for Checked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(value) => fmt::Debug::fmt(value, f),
+            None => f.write_str("overflow"),
+        }
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Checked<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.partial_cmp(b),
+            (None, None) => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_overflow_common {
+    ($wrapper:ident, $new:path, $($structure:ident),*) => {
+        $(
+            impl core::ops::AddAssign for $wrapper<$structure> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+            impl core::ops::SubAssign for $wrapper<$structure> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+            impl core::ops::MulAssign for $wrapper<$structure> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+            impl core::ops::DivAssign for $wrapper<$structure> {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+            impl core::ops::RemAssign for $wrapper<$structure> {
+                fn rem_assign(&mut self, rhs: Self) {
+                    *self = *self % rhs;
+                }
+            }
+
+            impl ClosedUnder<Addition> for $wrapper<$structure> {}
+            impl CommutativeOver<Addition> for $wrapper<$structure> {}
+            impl ClosedUnder<Multiplication> for $wrapper<$structure> {}
+            impl CommutativeOver<Multiplication> for $wrapper<$structure> {}
+            impl ClosedUnder<Subtraction> for $wrapper<$structure> {}
+            impl Identity<Addition> for $wrapper<$structure> {
+                const IDENTITY: Self = $new(0);
+                fn is_identity(&self) -> bool {
+                    *self == $new(0)
+                }
+            }
+            impl Identity<Subtraction> for $wrapper<$structure> {
+                const IDENTITY: Self = $new(0);
+                fn is_identity(&self) -> bool {
+                    *self == $new(0)
+                }
+            }
+            impl Identity<Multiplication> for $wrapper<$structure> {
+                const IDENTITY: Self = $new(1);
+                fn is_identity(&self) -> bool {
+                    *self == $new(1)
+                }
+            }
+            impl Identity<Division> for $wrapper<$structure> {
+                const IDENTITY: Self = $new(1);
+                fn is_identity(&self) -> bool {
+                    *self == $new(1)
+                }
+            }
+        )*
+    };
+}
+
+/// The arithmetic operators for [`Wrapping`] and [`Saturating`], which both
+/// forward to an inherent method of the integer.
+macro_rules! impl_overflow_ops {
+    ($wrapper:ident, $structure:ident, $add:ident, $sub:ident, $mul:ident, $div:ident, $rem:ident) => {
+        impl core::ops::Add for $wrapper<$structure> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0.$add(rhs.0))
+            }
+        }
+        impl core::ops::Sub for $wrapper<$structure> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0.$sub(rhs.0))
+            }
+        }
+        impl core::ops::Mul for $wrapper<$structure> {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self(self.0.$mul(rhs.0))
+            }
+        }
+        impl core::ops::Div for $wrapper<$structure> {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self::Output {
+                Self(self.0.$div(rhs.0))
+            }
+        }
+        impl core::ops::Rem for $wrapper<$structure> {
+            type Output = Self;
+            fn rem(self, rhs: Self) -> Self::Output {
+                Self(self.0.$rem(rhs.0))
+            }
+        }
+        impl Bounds for $wrapper<$structure> {
+            fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+            const MIN: Self = Self($structure::MIN);
+            const MAX: Self = Self($structure::MAX);
+        }
+        impl Integer for $wrapper<$structure> {}
+    };
+}
+
+/// Calls `$callback` with the names of every conversion in
+/// [`FromPrimitive`]/[`IntoPrimitive`] between integers.
+macro_rules! with_integer_conversions {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback!($($args)*;
+            from_u8, to_u8, u8; from_u16, to_u16, u16; from_u32, to_u32, u32; from_u64, to_u64, u64;
+            from_u128, to_u128, u128; from_usize, to_usize, usize; from_i8, to_i8, i8; from_i16, to_i16, i16;
+            from_i32, to_i32, i32; from_i64, to_i64, i64; from_i128, to_i128, i128; from_isize, to_isize, isize
+        );
+    };
+}
+
+/// Converts like an `as` cast, wrapping integers and saturating floats.
+macro_rules! impl_wrapping_primitive {
+    ($structure:ident; $($from:ident, $to:ident, $ty:ty);*) => {
+        impl FromPrimitive for Wrapping<$structure> {
+            $(
+                fn $from(val: $ty) -> Self {
+                    Self(val as $structure)
+                }
+            )*
+            fn from_f32(val: f32) -> Self {
+                Self(val as $structure)
+            }
+            fn from_f64(val: f64) -> Self {
+                Self(val as $structure)
+            }
+        }
+        impl IntoPrimitive for Wrapping<$structure> {
+            $(
+                fn $to(self) -> $ty {
+                    self.0 as $ty
+                }
+            )*
+            fn to_f32(self) -> f32 {
+                self.0 as f32
+            }
+            fn to_f64(self) -> f64 {
+                self.0 as f64
+            }
+        }
+    };
+}
+
+/// Converts to the closest value that fits.
+macro_rules! impl_saturating_primitive {
+    ($structure:ident; $($from:ident, $to:ident, $ty:ty);*) => {
+        impl FromPrimitive for Saturating<$structure> {
+            $(
+                fn $from(val: $ty) -> Self {
+                    Self(saturate!($structure, val))
+                }
+            )*
+            fn from_f32(val: f32) -> Self {
+                Self(val as $structure)
+            }
+            fn from_f64(val: f64) -> Self {
+                Self(val as $structure)
+            }
+        }
+        impl IntoPrimitive for Saturating<$structure> {
+            $(
+                fn $to(self) -> $ty {
+                    saturate!($ty, self.0)
+                }
+            )*
+            fn to_f32(self) -> f32 {
+                self.0 as f32
+            }
+            fn to_f64(self) -> f64 {
+                self.0 as f64
+            }
+        }
+    };
+}
+
+/// Converts when the value fits, and saturates converting out of an
+/// overflow free value.
+///
+/// # Panics
+/// Converting an overflowed value out.
+macro_rules! impl_checked_primitive {
+    ($structure:ident; $($from:ident, $to:ident, $ty:ty);*) => {
+        impl FromPrimitive for Checked<$structure> {
+            $(
+                fn $from(val: $ty) -> Self {
+                    Self(<$structure>::try_from(val).ok())
+                }
+            )*
+            fn from_f32(val: f32) -> Self {
+                Self::from_f64(val as f64)
+            }
+            fn from_f64(val: f64) -> Self {
+                // Anything that truncates into range fits. Past 2^53 the
+                // `- 1.0` rounds away, hence also accepting MIN itself, and
+                // MAX + 1 is a power of two so it's always exact. NaN fails.
+                let min = $structure::MIN as f64;
+                if (val > min - 1.0 || val >= min) && val < $structure::MAX as f64 + 1.0 {
+                    Self::new(val as $structure)
+                } else {
+                    Self(None)
+                }
+            }
+        }
+        impl IntoPrimitive for Checked<$structure> {
+            $(
+                fn $to(self) -> $ty {
+                    saturate!($ty, self.0.expect("attempt to convert an overflowed integer"))
+                }
+            )*
+            fn to_f32(self) -> f32 {
+                self.0.expect("attempt to convert an overflowed integer") as f32
+            }
+            fn to_f64(self) -> f64 {
+                self.0.expect("attempt to convert an overflowed integer") as f64
+            }
+        }
+    };
+}
+
+/// `value` converted to the integer type `$ty`, clamped to its bounds.
+macro_rules! saturate {
+    ($ty:ty, $value:expr) => {{
+        let value = $value;
+        <$ty>::try_from(value).unwrap_or(if value > 0 { <$ty>::MAX } else { <$ty>::MIN })
+    }};
+}
+
+macro_rules! impl_wrapping {
+    ($($structure:ident),*) => {
+        impl_overflow_common!(Wrapping, Wrapping, $($structure),*);
+        $(
+            impl_overflow_ops!(Wrapping, $structure, wrapping_add, wrapping_sub, wrapping_mul, wrapping_div, wrapping_rem);
+            with_integer_conversions!(impl_wrapping_primitive!($structure));
+            impl core::ops::Neg for Wrapping<$structure> {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self(self.0.wrapping_neg())
+                }
+            }
+            impl AssociativeOver<Addition> for Wrapping<$structure> {}
+            impl AssociativeOver<Multiplication> for Wrapping<$structure> {}
+            impl Distributive<Multiplication, Addition> for Wrapping<$structure> {}
+            impl Distributive<Multiplication, Subtraction> for Wrapping<$structure> {}
+            impl Absorption<Multiplication> for Wrapping<$structure> {
+                const ABSORBING: Self = Self(0);
+                fn is_absorber(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+            impl Inverse<Addition> for Wrapping<$structure> {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+            impl Inverse<Subtraction> for Wrapping<$structure> {
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_saturating {
+    ($($structure:ident),*) => {
+        impl_overflow_common!(Saturating, Saturating, $($structure),*);
+        $(
+            impl_overflow_ops!(Saturating, $structure, saturating_add, saturating_sub, saturating_mul, saturating_div, wrapping_rem);
+            with_integer_conversions!(impl_saturating_primitive!($structure));
+            impl Absorption<Multiplication> for Saturating<$structure> {
+                const ABSORBING: Self = Self(0);
+                fn is_absorber(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+        )*
+    };
+}
+
+/// Everything here is nonnegative, so an operation saturates exactly when
+/// the exact result would be past `MAX`, whatever the grouping.
+macro_rules! impl_saturating_unsigned {
+    ($($structure:ident),*) => {
+        $(
+            impl AssociativeOver<Addition> for Saturating<$structure> {}
+            impl AssociativeOver<Multiplication> for Saturating<$structure> {}
+            impl Distributive<Multiplication, Addition> for Saturating<$structure> {}
+        )*
+    };
+}
+
+macro_rules! impl_checked {
+    ($($structure:ident),*) => {
+        impl_overflow_common!(Checked, Checked::new, $($structure),*);
+        $(
+            impl core::ops::Add for Checked<$structure> {
+                type Output = Self;
+                fn add(self, rhs: Self) -> Self::Output {
+                    Self(self.0.zip(rhs.0).and_then(|(a, b)| a.checked_add(b)))
+                }
+            }
+            impl core::ops::Sub for Checked<$structure> {
+                type Output = Self;
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Self(self.0.zip(rhs.0).and_then(|(a, b)| a.checked_sub(b)))
+                }
+            }
+            impl core::ops::Mul for Checked<$structure> {
+                type Output = Self;
+                fn mul(self, rhs: Self) -> Self::Output {
+                    Self(self.0.zip(rhs.0).and_then(|(a, b)| a.checked_mul(b)))
+                }
+            }
+            impl core::ops::Div for Checked<$structure> {
+                type Output = Self;
+                fn div(self, rhs: Self) -> Self::Output {
+                    Self(self.0.zip(rhs.0).and_then(|(a, b)| a.checked_div(b)))
+                }
+            }
+            impl core::ops::Rem for Checked<$structure> {
+                type Output = Self;
+                fn rem(self, rhs: Self) -> Self::Output {
+                    Self(self.0.zip(rhs.0).and_then(|(a, b)| a.checked_rem(b)))
+                }
+            }
+            impl Bounds for Checked<$structure> {
+                fn min(self, other: Self) -> Self {
+                    Self(self.0.zip(other.0).map(|(a, b)| Ord::min(a, b)))
+                }
+                fn max(self, other: Self) -> Self {
+                    Self(self.0.zip(other.0).map(|(a, b)| Ord::max(a, b)))
+                }
+                const MIN: Self = Self::new($structure::MIN);
+                const MAX: Self = Self::new($structure::MAX);
+            }
+            with_integer_conversions!(impl_checked_primitive!($structure));
+        )*
+    };
+}
+
+/// Partial sums of nonnegative values only grow, so whether adding them up
+/// overflows doesn't depend on the grouping.
+macro_rules! impl_checked_unsigned {
+    ($($structure:ident),*) => {
+        $(
+            impl AssociativeOver<Addition> for Checked<$structure> {}
+        )*
+    };
+}
+
+macro_rules! impl_overflow_signed {
+    ($($structure:ident),*) => {
+        $(
+            impl Signed for Wrapping<$structure> {
+                fn is_negative(self) -> bool {
+                    self.0 < 0
+                }
+                fn is_positive(self) -> bool {
+                    self.0 > 0
+                }
+                fn abs(self) -> Self {
+                    Self(self.0.wrapping_abs())
+                }
+                fn flip_sign(self) -> Self {
+                    -self
+                }
+            }
+
+            impl core::ops::Neg for Saturating<$structure> {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self(self.0.saturating_neg())
+                }
+            }
+            impl Signed for Saturating<$structure> {
+                fn is_negative(self) -> bool {
+                    self.0 < 0
+                }
+                fn is_positive(self) -> bool {
+                    self.0 > 0
+                }
+                fn abs(self) -> Self {
+                    Self(self.0.saturating_abs())
+                }
+                fn flip_sign(self) -> Self {
+                    -self
+                }
+            }
+
+            impl core::ops::Neg for Checked<$structure> {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self(self.0.and_then($structure::checked_neg))
+                }
+            }
+            impl Signed for Checked<$structure> {
+                fn is_negative(self) -> bool {
+                    self.0.is_some_and(|value| value < 0)
+                }
+                fn is_positive(self) -> bool {
+                    self.0.is_some_and(|value| value > 0)
+                }
+                fn abs(self) -> Self {
+                    Self(self.0.and_then($structure::checked_abs))
+                }
+                fn flip_sign(self) -> Self {
+                    -self
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturating!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturating_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_checked!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_checked_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_overflow_signed!(i8, i16, i32, i64, i128, isize);
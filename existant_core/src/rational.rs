@@ -0,0 +1,409 @@
+use core::{cmp::Ordering, fmt, ops::Neg};
+
+use crate::{Absorption, Addition, AssociativeOver, BigUint, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FromPrimitive, Identity, Integer, IntoPrimitive, Inverse, Multiplication, Signed, Subtraction};
+
+/// An exact fraction of two integers. It's always kept in lowest terms with a
+/// positive denominator, so two equal fractions have equal fields.
+///
+/// With a signed `T` this is a [`Field`](crate::Field), so the matrices and
+/// `SolveEquations` give exact answers over it, as long as nothing overflows
+/// `T` along the way.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// Creates the fraction `numerator / denominator` in lowest terms.
+    ///
+    /// # Panics
+    /// When `denominator` is zero.
+    pub fn new(numerator: T, denominator: T) -> Self {
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        assert!(denominator != zero, "denominator of a rational can't be zero");
+        let (numerator, denominator) = if denominator < zero {
+            (zero - numerator, zero - denominator)
+        } else {
+            (numerator, denominator)
+        };
+        let gcd = numerator.gcd(denominator);
+        Self { numerator: numerator / gcd, denominator: denominator / gcd }
+    }
+    /// Like [`new`](Self::new), but `None` instead of a panic when
+    /// `denominator` is zero, or when moving the sign to the numerator
+    /// overflows, as in `i32::MIN / -1`.
+    pub fn checked_new(mut numerator: T, mut denominator: T) -> Option<Self> {
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        let two = <T as Identity<Multiplication>>::IDENTITY + <T as Identity<Multiplication>>::IDENTITY;
+        if denominator == zero {
+            return None;
+        }
+        // MIN only has factors of two, so halving until it's gone either
+        // leaves something that negates or shows that the result can't fit
+        while denominator < zero && (numerator == T::MIN || denominator == T::MIN) {
+            if numerator % two != zero || denominator % two != zero {
+                return None;
+            }
+            (numerator, denominator) = (numerator / two, denominator / two);
+        }
+        Some(Self::new(numerator, denominator))
+    }
+    /// The fraction `value / 1`.
+    pub const fn from_integer(value: T) -> Self {
+        Self { numerator: value, denominator: <T as Identity<Multiplication>>::IDENTITY }
+    }
+    pub const fn numerator(&self) -> T {
+        self.numerator
+    }
+    pub const fn denominator(&self) -> T {
+        self.denominator
+    }
+    pub fn is_integer(&self) -> bool {
+        <T as Identity<Multiplication>>::is_identity(&self.denominator)
+    }
+    /// # Panics
+    /// When `self` is zero.
+    pub fn recip(self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+    /// Rounds toward zero.
+    pub fn trunc(self) -> Self {
+        Self::from_integer(self.numerator / self.denominator)
+    }
+    pub fn fract(self) -> Self {
+        self - self.trunc()
+    }
+    pub fn floor(self) -> Self {
+        let trunc = self.trunc();
+        if self < trunc { trunc - Self::IDENTITY_MUL } else { trunc }
+    }
+    pub fn ceil(self) -> Self {
+        let trunc = self.trunc();
+        if self > trunc { trunc + Self::IDENTITY_MUL } else { trunc }
+    }
+    const IDENTITY_MUL: Self = <Self as Identity<Multiplication>>::IDENTITY;
+}
+
+impl<T: Integer> Rational<T> {
+    /// The closest fraction to `value` that fits in `T`, which is `value`
+    /// itself whenever both of its parts fit. Out of range values saturate
+    /// and NaN becomes zero, like an `as` cast.
+    pub fn from_f64(value: f64) -> Self {
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        let unsigned = T::MIN == zero;
+        if value.is_nan() || (unsigned && value < 0.0) {
+            return Self::from_integer(zero);
+        }
+        let max = T::MAX.to_f64();
+        let (numerator, denominator) = continued_fraction(value.abs(), max);
+        let denominator = biguint_to_integer::<T>(denominator);
+        let numerator = match numerator {
+            Some(numerator) if value < 0.0 => zero - biguint_to_integer::<T>(numerator),
+            Some(numerator) => biguint_to_integer::<T>(numerator),
+            None if value < 0.0 => T::MIN,
+            None => T::MAX,
+        };
+        Self::new(numerator, denominator)
+    }
+}
+
+/// Wide enough for every finite f64 as a fraction, since 2^971 * (2^53 - 1)
+/// is the largest numerator and 2^1074 the largest denominator.
+type Exact = BigUint<17>;
+
+/// Walks the convergents of the continued fraction of `value`, which ends
+/// at `value` exactly since every f64 is a fraction with a power of two
+/// denominator. Stops at the last one where both parts stay below `max`,
+/// returning `None` for the numerator when even the integer part is too big.
+fn continued_fraction(value: f64, max: f64) -> (Option<Exact>, Exact) {
+    if value.is_infinite() {
+        return (None, Exact::ONE);
+    }
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7FF) as i32;
+    let mantissa = bits & ((1 << 52) - 1);
+    // Subnormals have no implicit bit and the exponent of the smallest normal.
+    let (mantissa, exponent) = if exponent == 0 {
+        (mantissa, -1074)
+    } else {
+        (mantissa | (1 << 52), exponent - 1075)
+    };
+    let (mut numerator, mut denominator) = if exponent >= 0 {
+        (Exact::from_u128(mantissa as u128) << exponent as u32, Exact::ONE)
+    } else {
+        (Exact::from_u128(mantissa as u128), Exact::ONE << (-exponent) as u32)
+    };
+    let fits = |x: &Exact| x.to_f64() < max;
+    let (mut previous, mut current) = ((Exact::ZERO, Exact::ONE), (Exact::ONE, Exact::ZERO));
+    let mut best = None;
+    while !denominator.is_zero() {
+        let (term, remainder) = numerator.checked_div_rem(denominator).unwrap();
+        let next = (term * current.0 + previous.0, term * current.1 + previous.1);
+        if !fits(&next.0) || !fits(&next.1) {
+            break;
+        }
+        best = Some(next);
+        (previous, current) = (current, next);
+        (numerator, denominator) = (denominator, remainder);
+    }
+    match best {
+        Some((numerator, denominator)) => (Some(numerator), denominator),
+        None => (None, Exact::ONE),
+    }
+}
+
+/// Rebuilds a value that's known to fit in `T` one limb at a time.
+fn biguint_to_integer<T: Integer>(value: Exact) -> T {
+    // For types narrower than 64 bits this wraps to zero, but then every limb
+    // except the lowest is zero too.
+    let base = T::from_u128(1 << 64);
+    value.limbs().iter().rev().fold(<T as Identity<Addition>>::IDENTITY, |acc, &limb| acc * base + T::from_u64(limb))
+}
+
+impl<T: Integer> Default for Rational<T> {
+    fn default() -> Self {
+        <Self as Identity<Addition>>::IDENTITY
+    }
+}
+
+impl<T: Integer + fmt::Display> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+impl<T: Integer + fmt::Display> fmt::Debug for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross multiplying keeps the order.
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Neg for Rational<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { numerator: -self.numerator, denominator: self.denominator }
+    }
+}
+impl<T: Integer> core::ops::Add for Rational<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let gcd = self.denominator.gcd(rhs.denominator);
+        Self::new(
+            self.numerator * (rhs.denominator / gcd) + rhs.numerator * (self.denominator / gcd),
+            self.denominator / gcd * rhs.denominator,
+        )
+    }
+}
+impl<T: Integer> core::ops::Sub for Rational<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let gcd = self.denominator.gcd(rhs.denominator);
+        Self::new(
+            self.numerator * (rhs.denominator / gcd) - rhs.numerator * (self.denominator / gcd),
+            self.denominator / gcd * rhs.denominator,
+        )
+    }
+}
+impl<T: Integer> core::ops::Mul for Rational<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Cancel crosswise first, so the products stay as small as possible.
+        let a = self.numerator.gcd(rhs.denominator);
+        let b = rhs.numerator.gcd(self.denominator);
+        Self {
+            numerator: (self.numerator / a) * (rhs.numerator / b),
+            denominator: (self.denominator / b) * (rhs.denominator / a),
+        }
+    }
+}
+impl<T: Integer> core::ops::Div for Rational<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!<T as Identity<Addition>>::is_identity(&rhs.numerator), "attempt to divide by zero");
+        self * rhs.recip()
+    }
+}
+impl<T: Integer> core::ops::Rem for Rational<T> {
+    type Output = Self;
+    /// Remainder of truncated division, so it takes the sign of `self`.
+    fn rem(self, rhs: Self) -> Self::Output {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+macro_rules! impl_rational_assign {
+    ($($assign:ident, $assign_fn:ident, $op:ident, $op_fn:ident);* $(;)?) => {
+        $(
+            impl<T: Integer> core::ops::$assign for Rational<T> {
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = core::ops::$op::$op_fn(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+impl_rational_assign!(
+    AddAssign, add_assign, Add, add;
+    SubAssign, sub_assign, Sub, sub;
+    MulAssign, mul_assign, Mul, mul;
+    DivAssign, div_assign, Div, div;
+    RemAssign, rem_assign, Rem, rem;
+);
+
+impl<T: Integer + Signed> Signed for Rational<T> {
+    fn is_negative(self) -> bool {
+        self.numerator.is_negative()
+    }
+    fn is_positive(self) -> bool {
+        self.numerator.is_positive()
+    }
+    fn abs(self) -> Self {
+        Self { numerator: self.numerator.abs(), denominator: self.denominator }
+    }
+    fn flip_sign(self) -> Self {
+        -self
+    }
+}
+
+impl<T: Integer> Bounds for Rational<T> {
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+    const MIN: Self = Self::from_integer(T::MIN);
+    const MAX: Self = Self::from_integer(T::MAX);
+}
+
+impl<T: Integer> AssociativeOver<Addition> for Rational<T> {}
+impl<T: Integer> CommutativeOver<Addition> for Rational<T> {}
+impl<T: Integer> ClosedUnder<Addition> for Rational<T> {}
+impl<T: Integer> AssociativeOver<Multiplication> for Rational<T> {}
+impl<T: Integer> CommutativeOver<Multiplication> for Rational<T> {}
+impl<T: Integer> ClosedUnder<Multiplication> for Rational<T> {}
+impl<T: Integer + Signed> AssociativeOver<Subtraction> for Rational<T> {}
+impl<T: Integer + Signed> ClosedUnder<Subtraction> for Rational<T> {}
+impl<T: Integer> ClosedUnder<Division> for Rational<T> {}
+impl<T: Integer> Distributive<Multiplication, Addition> for Rational<T> {}
+impl<T: Integer> Distributive<Multiplication, Subtraction> for Rational<T> {}
+impl<T: Integer> Distributive<Division, Addition> for Rational<T> {}
+impl<T: Integer> Distributive<Division, Subtraction> for Rational<T> {}
+
+impl<T: Integer> Identity<Addition> for Rational<T> {
+    const IDENTITY: Self = Self::from_integer(<T as Identity<Addition>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <T as Identity<Addition>>::is_identity(&self.numerator)
+    }
+}
+impl<T: Integer> Identity<Subtraction> for Rational<T> {
+    const IDENTITY: Self = Self::from_integer(<T as Identity<Addition>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <T as Identity<Addition>>::is_identity(&self.numerator)
+    }
+}
+impl<T: Integer> Identity<Multiplication> for Rational<T> {
+    const IDENTITY: Self = Self::from_integer(<T as Identity<Multiplication>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <T as Identity<Multiplication>>::is_identity(&self.numerator) && self.is_integer()
+    }
+}
+impl<T: Integer> Identity<Division> for Rational<T> {
+    const IDENTITY: Self = Self::from_integer(<T as Identity<Multiplication>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <Self as Identity<Multiplication>>::is_identity(self)
+    }
+}
+impl<T: Integer> Absorption<Multiplication> for Rational<T> {
+    const ABSORBING: Self = Self::from_integer(<T as Identity<Addition>>::IDENTITY);
+    fn is_absorber(&self) -> bool {
+        <T as Identity<Addition>>::is_identity(&self.numerator)
+    }
+}
+impl<T: Integer + Neg<Output = T>> Inverse<Addition> for Rational<T> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+impl<T: Integer + Neg<Output = T>> Inverse<Subtraction> for Rational<T> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+impl<T: Integer> Inverse<Multiplication> for Rational<T> {
+    fn inverse(self) -> Self {
+        self.recip()
+    }
+}
+
+macro_rules! impl_rational_from_integer {
+    ($($from:ident: $primitive:ty),* $(,)?) => {
+        $(
+            fn $from(val: $primitive) -> Self {
+                Self::from_integer(T::$from(val))
+            }
+        )*
+    };
+}
+impl<T: Integer> FromPrimitive for Rational<T> {
+    impl_rational_from_integer!(
+        from_u8: u8, from_u16: u16, from_u32: u32, from_u64: u64, from_u128: u128, from_usize: usize,
+        from_i8: i8, from_i16: i16, from_i32: i32, from_i64: i64, from_i128: i128, from_isize: isize,
+    );
+    fn from_f32(val: f32) -> Self {
+        Self::from_f64(val as f64)
+    }
+    fn from_f64(val: f64) -> Self {
+        Self::from_f64(val)
+    }
+}
+
+macro_rules! impl_rational_into_integer {
+    ($($to:ident $from:ident: $primitive:ty),* $(,)?) => {
+        $(
+            /// Truncates toward zero and saturates at the bounds of the
+            /// integer, like casting a float.
+            fn $to(self) -> $primitive {
+                let zero = <T as Identity<Addition>>::IDENTITY;
+                let truncated = self.numerator / self.denominator;
+                let cast = truncated.$to();
+                // it fits when it comes back unchanged without flipping sign
+                if T::$from(cast) == truncated && (truncated < zero) == (cast < 0 as $primitive) {
+                    cast
+                } else if truncated < zero {
+                    <$primitive>::MIN
+                } else {
+                    <$primitive>::MAX
+                }
+            }
+        )*
+    };
+}
+impl<T: Integer> IntoPrimitive for Rational<T> {
+    impl_rational_into_integer!(
+        to_u8 from_u8: u8, to_u16 from_u16: u16, to_u32 from_u32: u32, to_u64 from_u64: u64, to_u128 from_u128: u128, to_usize from_usize: usize,
+        to_i8 from_i8: i8, to_i16 from_i16: i16, to_i32 from_i32: i32, to_i64 from_i64: i64, to_i128 from_i128: i128, to_isize from_isize: isize,
+    );
+    fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+    fn to_f64(self) -> f64 {
+        self.numerator.to_f64() / self.denominator.to_f64()
+    }
+}
@@ -0,0 +1,175 @@
+//! `Serialize` and `Deserialize` for the number types in this crate. Each one
+//! is written the way it's stored, and anything that would break an invariant
+//! the constructors keep is turned away when it's read back.
+
+use core::fmt;
+
+use serde::{de::{self, SeqAccess, Visitor}, ser::SerializeTuple, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Addition, BigInt, BigUint, Checked, Fixed, Gf, Gf2n, Identity, Integer, Interval, ModInt, Rational, Saturating, Signed, Tropical, Wrapping};
+
+macro_rules! impl_serde_transparent {
+    ($($structure:ident),*) => {
+        $(
+            impl<T: Serialize> Serialize for $structure<T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(serializer)
+                }
+            }
+            impl<'de, T: Deserialize<'de>> Deserialize<'de> for $structure<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    Deserialize::deserialize(deserializer).map($structure)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_transparent!(Wrapping, Saturating, Checked);
+
+impl<T: Serialize, Op> Serialize for Tropical<T, Op> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+impl<'de, T: Deserialize<'de>, Op> Deserialize<'de> for Tropical<T, Op> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Tropical::new)
+    }
+}
+
+macro_rules! impl_serde_fixed {
+    ($($int:ident),*) => {
+        $(
+            /// The raw bits, so nothing is lost to rounding.
+            impl<const FRAC: u32> Serialize for Fixed<$int, FRAC> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.to_bits().serialize(serializer)
+                }
+            }
+            impl<'de, const FRAC: u32> Deserialize<'de> for Fixed<$int, FRAC> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    $int::deserialize(deserializer).map(Self::from_bits)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_fixed!(i16, i32, i64);
+
+macro_rules! impl_serde_interval {
+    ($($structure:ident),*) => {
+        $(
+            /// `[lo, hi]`.
+            impl Serialize for Interval<$structure> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    (self.lo(), self.hi()).serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for Interval<$structure> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let (lo, hi) = <($structure, $structure)>::deserialize(deserializer)?;
+                    if lo.is_nan() || hi.is_nan() {
+                        return Err(de::Error::custom("the bounds of an interval can't be NaN"));
+                    }
+                    if lo > hi {
+                        return Err(de::Error::custom("the lower bound of an interval can't be above the upper bound"));
+                    }
+                    Ok(Self::new(lo, hi))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_interval!(f32, f64);
+
+/// `[numerator, denominator]`, which is put in lowest terms when read back.
+impl<T: Integer + Serialize> Serialize for Rational<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.numerator(), self.denominator()).serialize(serializer)
+    }
+}
+impl<'de, T: Integer + Deserialize<'de>> Deserialize<'de> for Rational<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (numerator, denominator) = <(T, T)>::deserialize(deserializer)?;
+        if <T as Identity<Addition>>::is_identity(&denominator) {
+            return Err(de::Error::custom("denominator of a rational can't be zero"));
+        }
+        Self::checked_new(numerator, denominator)
+            .ok_or_else(|| de::Error::custom("rational doesn't fit its integer type in lowest terms"))
+    }
+}
+
+macro_rules! impl_serde_modular {
+    ($($structure:ident),*) => {
+        $(
+            impl<const N: u64> Serialize for $structure<N> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.value().serialize(serializer)
+                }
+            }
+            /// Only values that are already reduced, since anything else was
+            /// written by something other than this.
+            impl<'de, const N: u64> Deserialize<'de> for $structure<N> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = u64::deserialize(deserializer)?;
+                    let reduced = Self::new(value);
+                    if reduced.value() != value {
+                        return Err(de::Error::custom(concat!("value isn't reduced for ", stringify!($structure))));
+                    }
+                    Ok(reduced)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_modular!(ModInt, Gf, Gf2n);
+
+/// The limbs, lowest first, as a tuple since `LIMBS` is fixed.
+impl<const LIMBS: usize> Serialize for BigUint<LIMBS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(LIMBS)?;
+        for limb in self.limbs() {
+            tuple.serialize_element(limb)?;
+        }
+        tuple.end()
+    }
+}
+impl<'de, const LIMBS: usize> Deserialize<'de> for BigUint<LIMBS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Limbs<const LIMBS: usize>;
+        impl<'de, const LIMBS: usize> Visitor<'de> for Limbs<LIMBS> {
+            type Value = BigUint<LIMBS>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "{LIMBS} limbs")
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut limbs = [0; LIMBS];
+                for (i, limb) in limbs.iter_mut().enumerate() {
+                    *limb = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(BigUint::from_limbs(limbs))
+            }
+        }
+        deserializer.deserialize_tuple(LIMBS, Limbs::<LIMBS>)
+    }
+}
+
+/// `[negative, magnitude]`, where zero is never negative.
+impl<const LIMBS: usize> Serialize for BigInt<LIMBS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (Signed::is_negative(*self), self.unsigned_abs()).serialize(serializer)
+    }
+}
+impl<'de, const LIMBS: usize> Deserialize<'de> for BigInt<LIMBS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (negative, magnitude) = <(bool, BigUint<LIMBS>)>::deserialize(deserializer)?;
+        if negative && magnitude.is_zero() {
+            return Err(de::Error::custom("zero can't be negative"));
+        }
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
@@ -0,0 +1,466 @@
+//! The generic implementations behind the special functions of `FloatingPoint`.
+//! Everything is written against the trait's own operations, so it works the
+//! same for any type that implements it, and every loop has a cap on its
+//! iterations in case a type never gets within its `EPSILON`.
+
+use crate::{Addition, FloatingPoint, Identity, Multiplication};
+
+/// Coefficients of the Lanczos approximation with g = 7, good to about 15
+/// digits.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+/// Past this the Hankel expansions of the Bessel functions are more accurate
+/// than summing up the recurrence.
+const BESSEL_ASYMPTOTIC: f64 = 25.0;
+const MAX_ITERATIONS: usize = 500;
+
+fn zero<T: FloatingPoint>() -> T {
+    <T as Identity<Addition>>::IDENTITY
+}
+fn one<T: FloatingPoint>() -> T {
+    <T as Identity<Multiplication>>::IDENTITY
+}
+/// Small enough to stand in for zero in a continued fraction without
+/// changing the answer.
+fn tiny<T: FloatingPoint>() -> T {
+    let tiny = T::EPSILON * T::EPSILON * T::EPSILON;
+    if tiny == zero() { T::EPSILON } else { tiny }
+}
+fn is_integer<T: FloatingPoint>(x: T) -> bool {
+    x == x.floor()
+}
+/// `sin(πx)`, with `x` brought into `[-1, 1)` first since that's exact and
+/// `πx` isn't.
+fn sin_pi<T: FloatingPoint>(x: T) -> T {
+    let two = T::from_u8(2);
+    let x = x - two * (x / two).floor();
+    let x = if x >= one() { x - two } else { x };
+    (T::PI * x).sin()
+}
+fn cos_pi<T: FloatingPoint>(x: T) -> T {
+    sin_pi(x + T::from_f64(0.5))
+}
+
+/// The sum of the Lanczos series at `x - 1`.
+fn lanczos_sum<T: FloatingPoint>(x: T) -> T {
+    let x = x - one();
+    LANCZOS[1..].iter().enumerate().fold(T::from_f64(LANCZOS[0]), |sum, (i, c)| sum + T::from_f64(*c) / (x + T::from_usize(i + 1)))
+}
+
+pub(crate) fn gamma<T: FloatingPoint>(x: T) -> T {
+    if x.is_nan() {
+        return x;
+    }
+    if x == zero() {
+        return T::INFINITY.copysign(x);
+    }
+    if x < zero() && is_integer(x) {
+        return T::nan();
+    }
+    let half = T::from_f64(0.5);
+    if x < half {
+        // Γ(x)Γ(1 - x) = π / sin(πx)
+        return T::PI / (sin_pi(x) * gamma(one::<T>() - x));
+    }
+    let t = x + T::from_f64(LANCZOS_G) - half;
+    // tᕽ⁻¹ᐟ² overflows well before Γ(x) does, so it's taken in halves
+    let power = t.powf((x - half) / T::from_u8(2));
+    (T::TAU.sqrt() * lanczos_sum(x)) * power * (power * (-t).exp())
+}
+
+pub(crate) fn ln_gamma<T: FloatingPoint>(x: T) -> T {
+    if x.is_nan() {
+        return x;
+    }
+    if x <= zero() && is_integer(x) {
+        return T::INFINITY;
+    }
+    let half = T::from_f64(0.5);
+    if x < half {
+        return (T::PI / sin_pi(x).abs()).ln() - ln_gamma(one::<T>() - x);
+    }
+    let t = x + T::from_f64(LANCZOS_G) - half;
+    T::TAU.sqrt().ln() + (x - half) * t.ln() - t + lanczos_sum(x).ln()
+}
+
+/// Whether `Γ(x)` is negative, which it is between every odd negative
+/// integer and the even one above it.
+fn gamma_is_negative<T: FloatingPoint>(x: T) -> bool {
+    x < zero() && !is_integer(x.floor() / T::from_u8(2))
+}
+
+pub(crate) fn digamma<T: FloatingPoint>(mut x: T) -> T {
+    if x.is_nan() {
+        return x;
+    }
+    if x <= zero() {
+        if is_integer(x) {
+            return T::nan();
+        }
+        // ψ(1 - x) - ψ(x) = π / tan(πx)
+        return digamma(one::<T>() - x) - T::PI * cos_pi(x) / sin_pi(x);
+    }
+    // ψ(x + 1) = ψ(x) + 1/x, until x is big enough for the asymptotic series
+    let mut result = zero::<T>();
+    while x < T::from_u8(12) {
+        result -= x.recip();
+        x += one();
+    }
+    let z = (x * x).recip();
+    let series = z * (T::from_f64(1.0 / 12.0) - z * (T::from_f64(1.0 / 120.0) - z * (T::from_f64(1.0 / 252.0)
+        - z * (T::from_f64(1.0 / 240.0) - z * (T::from_f64(1.0 / 132.0) - z * T::from_f64(691.0 / 32760.0))))));
+    result + x.ln() - (T::from_u8(2) * x).recip() - series
+}
+
+pub(crate) fn beta<T: FloatingPoint>(a: T, b: T) -> T {
+    let direct = a.gamma() * b.gamma() / (a + b).gamma();
+    if direct.is_finite() && direct != zero() {
+        return direct;
+    }
+    // one of the gammas went out of range, so go through their logarithms
+    let magnitude = (a.ln_gamma() + b.ln_gamma() - (a + b).ln_gamma()).exp();
+    if gamma_is_negative(a) ^ gamma_is_negative(b) ^ gamma_is_negative(a + b) { -magnitude } else { magnitude }
+}
+
+pub(crate) fn incomplete_beta<T: FloatingPoint>(x: T, a: T, b: T) -> T {
+    if x.is_nan() || a.is_nan() || b.is_nan() || a <= zero() || b <= zero() || x < zero() || x > one() {
+        return T::nan();
+    }
+    if x == zero() || x == one() {
+        return x;
+    }
+    let front = |x: T, a: T, b: T| (a * x.ln() + b * (-x).ln_1p() + (a + b).ln_gamma() - a.ln_gamma() - b.ln_gamma()).exp() / a;
+    // the continued fraction converges quickly below the mean, and the
+    // symmetry I₁₋ₓ(b, a) = 1 - Iₓ(a, b) takes care of the rest
+    if x < (a + one()) / (a + b + T::from_u8(2)) {
+        front(x, a, b) * beta_fraction(x, a, b)
+    } else {
+        let y = one::<T>() - x;
+        one::<T>() - front(y, b, a) * beta_fraction(y, b, a)
+    }
+}
+
+/// The continued fraction for the incomplete beta function, by Lentz's method.
+fn beta_fraction<T: FloatingPoint>(x: T, a: T, b: T) -> T {
+    let (one, two) = (one::<T>(), T::from_u8(2));
+    let avoid_zero = |value: T| if value.abs() < tiny() { tiny() } else { value };
+    let mut c = one;
+    let mut d = avoid_zero(one - (a + b) * x / (a + one)).recip();
+    let mut fraction = d;
+    for m in 1..MAX_ITERATIONS {
+        let m = T::from_usize(m);
+        let even = m * (b - m) * x / ((a + two * m - one) * (a + two * m));
+        d = avoid_zero(one + even * d).recip();
+        c = avoid_zero(one + even / c);
+        fraction *= d * c;
+        let odd = -(a + m) * (a + b + m) * x / ((a + two * m) * (a + two * m + one));
+        d = avoid_zero(one + odd * d).recip();
+        c = avoid_zero(one + odd / c);
+        let step = d * c;
+        fraction *= step;
+        if (step - one).abs() <= T::EPSILON {
+            break;
+        }
+    }
+    fraction
+}
+
+pub(crate) fn erf<T: FloatingPoint>(x: T) -> T {
+    if x.is_nan() {
+        return x;
+    }
+    if x.abs() < one() {
+        erf_series(x)
+    } else {
+        (one::<T>() - erfc_fraction(x.abs())).copysign(x)
+    }
+}
+
+pub(crate) fn erfc<T: FloatingPoint>(x: T) -> T {
+    if x.is_nan() {
+        return x;
+    }
+    if x < one() {
+        one::<T>() - erf(x)
+    } else {
+        erfc_fraction(x)
+    }
+}
+
+/// `erf(x) = 2/√π e^(-x²) (x + 2x³/3 + 4x⁵/15 + ...)`, whose terms are all
+/// the same sign so nothing cancels.
+fn erf_series<T: FloatingPoint>(x: T) -> T {
+    let square = x * x;
+    let mut term = x;
+    let mut sum = x;
+    for n in 1..MAX_ITERATIONS {
+        term *= T::from_u8(2) * square / T::from_usize(2 * n + 1);
+        sum += term;
+        if term.abs() <= T::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    T::FRAC_2_SQRT_PI * (-square).exp() * sum
+}
+
+/// `erfc(x) = 2x/√π e^(-x²) / (2x² + 1 - 1·2/(2x² + 5 - 3·4/(2x² + 9 - ...)))`
+/// by Lentz's method, for `x` of at least 1.
+fn erfc_fraction<T: FloatingPoint>(x: T) -> T {
+    if x == T::INFINITY {
+        return zero();
+    }
+    let avoid_zero = |value: T| if value.abs() < tiny() { tiny() } else { value };
+    let base = T::from_u8(2) * x * x + one();
+    let mut fraction = base;
+    let mut c = fraction;
+    let mut d = zero::<T>();
+    for n in 1..MAX_ITERATIONS {
+        let a = -T::from_usize((2 * n - 1) * 2 * n);
+        let b = base + T::from_usize(4 * n);
+        d = avoid_zero(b + a * d).recip();
+        c = avoid_zero(b + a / c);
+        let step = c * d;
+        fraction *= step;
+        if (step - one()).abs() <= T::EPSILON {
+            break;
+        }
+    }
+    T::FRAC_2_SQRT_PI * x * (-x * x).exp() / fraction
+}
+
+pub(crate) fn erf_inv<T: FloatingPoint>(y: T) -> T {
+    if y.is_nan() || y.abs() > one() {
+        return T::nan();
+    }
+    if y.abs() == one() {
+        return T::INFINITY.copysign(y);
+    }
+    let a = y.abs();
+    // Giles' single precision approximation to start from
+    let w = -((one::<T>() - a) * (one::<T>() + a)).ln();
+    let polynomial = |w: T, coefficients: &[f64]| coefficients.iter().fold(zero::<T>(), |p, c| p * w + T::from_f64(*c));
+    let mut x = a * if w < T::from_u8(5) {
+        polynomial(w - T::from_f64(2.5), &[
+            2.810_226_36e-8, 3.432_739_39e-7, -3.523_387_7e-6, -4.391_506_54e-6, 2.185_808_7e-4,
+            -1.253_725_03e-3, -4.177_681_64e-3, 2.466_407_27e-1, 1.501_409_41,
+        ])
+    } else {
+        polynomial(w.sqrt() - T::from_u8(3), &[
+            -2.002_142_57e-4, 1.009_505_58e-4, 1.349_343_22e-3, -3.673_428_44e-3, 5.739_507_73e-3,
+            -7.622_461_3e-3, 9.438_870_47e-3, 1.001_674_06, 2.832_976_82,
+        ])
+    };
+    // then Halley's method, going through erfc near 1 where 1 - a is exact
+    // and erf(x) - a isn't
+    for _ in 0..3 {
+        let residual = if a <= T::from_f64(0.5) { x.erf() - a } else { (one::<T>() - a) - x.erfc() };
+        let step = residual / (T::FRAC_2_SQRT_PI * (-x * x).exp());
+        if !step.is_finite() {
+            break;
+        }
+        x -= step / (one::<T>() + x * step);
+    }
+    x.copysign(y)
+}
+
+/// What Miller's backward recurrence finds out about the Bessel functions at
+/// `x`, all from the one pass.
+struct Miller<T> {
+    j_n: T,
+    y_0: T,
+    y_1: T,
+}
+
+/// Runs the recurrence `Jₖ₋₁ = 2k/x Jₖ - Jₖ₊₁` down from far enough past `n`
+/// and `x` that the starting values don't matter, rescaling whenever it gets
+/// big, and normalizes it with `J₀ + 2J₂ + 2J₄ + ... = 1`. The same values
+/// give `Y₀` and `Y₁` through their Neumann series.
+fn miller<T: FloatingPoint>(n: usize, x: T) -> Miller<T> {
+    let top = n.max(x.ceil().to_usize());
+    let start = 2 * ((top + 20 + (40 * top).isqrt()) / 2);
+    let big = T::EPSILON.sqrt().recip();
+    let (mut next, mut current) = (zero::<T>(), T::EPSILON);
+    let (mut norm, mut y_0_sum, mut y_1_sum) = (zero::<T>(), zero::<T>(), zero::<T>());
+    let mut j_n = zero::<T>();
+    let mut j_1 = zero::<T>();
+    let sign = |m: usize| if m % 2 == 0 { one::<T>() } else { -one::<T>() };
+    for k in (0..=start).rev() {
+        if k == n {
+            j_n = current;
+        }
+        if k == 1 {
+            j_1 = current;
+        }
+        if k % 2 == 0 {
+            norm += if k == 0 { current } else { T::from_u8(2) * current };
+            if k > 0 {
+                y_0_sum += sign(k / 2) * current / T::from_usize(k / 2);
+            }
+        } else {
+            // Jₖ turns up in the Y₁ series as J₂ₘ₋₁ with m = (k + 1)/2 and
+            // as J₂ₘ₊₁ with m = (k - 1)/2
+            y_1_sum += sign(k.div_ceil(2)) * current / T::from_usize(k.div_ceil(2));
+            if k > 1 {
+                y_1_sum -= sign(k / 2) * current / T::from_usize(k / 2);
+            }
+        }
+        if k == 0 {
+            break;
+        }
+        let previous = T::from_usize(2 * k) / x * current - next;
+        (next, current) = (current, previous);
+        if current.abs() > big {
+            let scale = current.abs().recip();
+            next *= scale;
+            current *= scale;
+            norm *= scale;
+            y_0_sum *= scale;
+            y_1_sum *= scale;
+            j_n *= scale;
+            j_1 *= scale;
+        }
+    }
+    let (j_0, j_1, j_n) = (current / norm, j_1 / norm, j_n / norm);
+    let log = (x / T::from_u8(2)).ln() + T::EGAMMA;
+    Miller {
+        j_n,
+        y_0: T::FRAC_2_PI * (log * j_0 - T::from_u8(2) * y_0_sum / norm),
+        y_1: T::FRAC_2_PI * (log * j_1 + y_1_sum / norm - j_0 / x),
+    }
+}
+
+/// `Jₙ(x)` and `Yₙ(x)` for `n` of 0 or 1 and large `x`, from Hankel's
+/// asymptotic expansion.
+fn hankel<T: FloatingPoint>(n: usize, x: T) -> (T, T) {
+    let mu = T::from_usize(4 * n * n);
+    let (mut p, mut q) = (one::<T>(), zero::<T>());
+    let mut term = one::<T>();
+    for k in 1..MAX_ITERATIONS {
+        let next = term * (mu - T::from_usize((2 * k - 1) * (2 * k - 1))) / (T::from_usize(8 * k) * x);
+        // the series diverges, so stop once the terms start to grow
+        if next.abs() >= term.abs() {
+            break;
+        }
+        term = next;
+        match k % 4 {
+            0 => p += term,
+            1 => q += term,
+            2 => p -= term,
+            _ => q -= term,
+        }
+        if term.abs() <= T::EPSILON {
+            break;
+        }
+    }
+    // χ = x - (n/2 + 1/4)π, with its sine and cosine from those of x so the
+    // subtraction doesn't round
+    let (sin, cos) = x.sin_cos();
+    let (phase_sin, phase_cos) = if n == 0 { (T::FRAC_1_SQRT_2, T::FRAC_1_SQRT_2) } else { (T::FRAC_1_SQRT_2, -T::FRAC_1_SQRT_2) };
+    let cos_chi = cos * phase_cos + sin * phase_sin;
+    let sin_chi = sin * phase_cos - cos * phase_sin;
+    let scale = (T::FRAC_2_PI / x).sqrt();
+    (scale * (p * cos_chi - q * sin_chi), scale * (p * sin_chi + q * cos_chi))
+}
+
+pub(crate) fn bessel_j<T: FloatingPoint>(x: T, n: i32) -> T {
+    if x.is_nan() {
+        return x;
+    }
+    // J₋ₙ = (-1)ⁿJₙ and Jₙ(-x) = (-1)ⁿJₙ(x)
+    let negate = n % 2 != 0 && (n < 0) != x.is_negative();
+    let (n, x) = (n.unsigned_abs() as usize, x.abs());
+    let j = if x == zero() {
+        if n == 0 { one() } else { zero() }
+    } else if !x.is_finite() {
+        zero()
+    } else if x >= T::from_f64(BESSEL_ASYMPTOTIC) && T::from_usize(n) < x {
+        // going up from J₀ and J₁ is stable as long as n is below x
+        let (mut previous, mut current) = (hankel(0, x).0, hankel(1, x).0);
+        if n == 0 {
+            previous
+        } else {
+            for k in 1..n {
+                (previous, current) = (current, T::from_usize(2 * k) / x * current - previous);
+            }
+            current
+        }
+    } else {
+        miller(n, x).j_n
+    };
+    if negate { -j } else { j }
+}
+
+pub(crate) fn bessel_y<T: FloatingPoint>(x: T, n: i32) -> T {
+    if x.is_nan() || x < zero() {
+        return T::nan();
+    }
+    if x == zero() {
+        return -T::INFINITY;
+    }
+    if !x.is_finite() {
+        return zero();
+    }
+    // Y₋ₙ = (-1)ⁿYₙ
+    let negate = n < 0 && n % 2 != 0;
+    let n = n.unsigned_abs() as usize;
+    let (mut previous, mut current) = if x >= T::from_f64(BESSEL_ASYMPTOTIC) {
+        (hankel(0, x).1, hankel(1, x).1)
+    } else {
+        let miller = miller(1, x);
+        (miller.y_0, miller.y_1)
+    };
+    // the recurrence is always stable going up for Y
+    let y = if n == 0 {
+        previous
+    } else {
+        for k in 1..n {
+            (previous, current) = (current, T::from_usize(2 * k) / x * current - previous);
+        }
+        current
+    };
+    if negate { -y } else { y }
+}
+
+/// Both complete elliptic integrals of parameter `m` from the arithmetic
+/// geometric mean of 1 and `√(1 - m)`.
+fn elliptic<T: FloatingPoint>(m: T) -> (T, T) {
+    if m.is_nan() || m > one() {
+        return (T::nan(), T::nan());
+    }
+    if m == one() {
+        return (T::INFINITY, one());
+    }
+    let (mut a, mut b) = (one::<T>(), (one::<T>() - m).sqrt());
+    // E = K(1 - Σ 2ⁿ⁻¹cₙ²), with c₀ = √m
+    let mut sum = m / T::from_u8(2);
+    let mut weight = T::from_f64(0.5);
+    for _ in 0..MAX_ITERATIONS {
+        let c = (a - b) / T::from_u8(2);
+        weight *= T::from_u8(2);
+        sum += weight * c * c;
+        (a, b) = ((a + b) / T::from_u8(2), (a * b).sqrt());
+        if c.abs() <= T::EPSILON * a {
+            break;
+        }
+    }
+    let k = T::FRAC_PI_2 / a;
+    (k, k * (one::<T>() - sum))
+}
+
+pub(crate) fn elliptic_k<T: FloatingPoint>(m: T) -> T {
+    elliptic(m).0
+}
+
+pub(crate) fn elliptic_e<T: FloatingPoint>(m: T) -> T {
+    elliptic(m).1
+}
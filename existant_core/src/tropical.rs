@@ -0,0 +1,160 @@
+use core::{cmp::Ordering, fmt, hash::{Hash, Hasher}, marker::PhantomData, ops::{Add, AddAssign}};
+
+use crate::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Identity, Max, Min, Operator, Signed};
+
+/// A number in a tropical semiring, where `Op` takes the place of addition
+/// and addition takes the place of multiplication. So `Tropical<T, Min>` is a
+/// [`Semiring<Addition, Min>`](crate::Semiring), whose zero is ∞ and whose
+/// one is 0, and `Tropical<T, Max>` is the same with -∞ as the zero.
+///
+/// A matrix product over these adds up the weights along each path and keeps
+/// the shortest (or longest) one, so the `n - 1`th power of a matrix of edge
+/// weights, with zeros on the diagonal and [`Tropical::ZERO`] where there's
+/// no edge, holds the shortest paths between every pair of vertices.
+///
+/// The zero absorbs addition even for integers, where it's `T::MAX` (or
+/// `T::MIN`), so a missing edge never overflows into a real weight. For the
+/// same reason there's no max-plus over the unsigned integers, since their
+/// `T::MIN` is 0, which is already the one.
+pub struct Tropical<T, Op = Min> {
+    pub value: T,
+    operator: PhantomData<Op>,
+}
+
+/// The min-plus semiring, for shortest paths.
+pub type MinPlus<T> = Tropical<T, Min>;
+/// The max-plus semiring, for longest paths.
+pub type MaxPlus<T> = Tropical<T, Max>;
+
+/// The types a [`Tropical`] can be over with `Op`, which are the ones where
+/// the identity of `Op` is out of reach of the identity of addition. Addition
+/// has to keep the order too, so it distributes over `Op` once the zero
+/// absorbs it.
+pub trait TropicalOver<Op: Operator>: Identity<Op> + Identity<Addition> {}
+impl<T: Identity<Min> + Identity<Addition>> TropicalOver<Min> for T {}
+impl<T: Identity<Max> + Identity<Addition> + Signed> TropicalOver<Max> for T {}
+
+impl<T, Op> Tropical<T, Op> {
+    pub const fn new(value: T) -> Self {
+        Self { value, operator: PhantomData }
+    }
+}
+
+impl<T: TropicalOver<Op>, Op: Operator> Tropical<T, Op> {
+    /// The identity of `Op`, which stands for an edge that isn't there.
+    pub const ZERO: Self = Self::new(<T as Identity<Op>>::IDENTITY);
+    pub fn is_zero(&self) -> bool {
+        <T as Identity<Op>>::is_identity(&self.value)
+    }
+}
+
+impl<T: Identity<Addition>, Op> Tropical<T, Op> {
+    /// The identity of addition, the weight of staying put.
+    pub const ONE: Self = Self::new(T::IDENTITY);
+}
+
+impl<T: Clone, Op> Clone for Tropical<T, Op> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+impl<T: Copy, Op> Copy for Tropical<T, Op> {}
+impl<T: PartialEq, Op> PartialEq for Tropical<T, Op> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<T: Eq, Op> Eq for Tropical<T, Op> {}
+impl<T: PartialOrd, Op> PartialOrd for Tropical<T, Op> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+impl<T: Ord, Op> Ord for Tropical<T, Op> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+impl<T: Hash, Op> Hash for Tropical<T, Op> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+impl<T: fmt::Debug, Op> fmt::Debug for Tropical<T, Op> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<T: Add<Output = T> + TropicalOver<Op>, Op: Operator> Add for Tropical<T, Op> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            Self::ZERO
+        } else {
+            Self::new(self.value + rhs.value)
+        }
+    }
+}
+impl<T: Add<Output = T> + TropicalOver<Op> + Copy, Op: Operator> AddAssign for Tropical<T, Op> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Bounds, Op> Bounds for Tropical<T, Op> {
+    fn min(self, other: Self) -> Self {
+        Self::new(self.value.min(other.value))
+    }
+    fn max(self, other: Self) -> Self {
+        Self::new(self.value.max(other.value))
+    }
+    const MIN: Self = Self::new(T::MIN);
+    const MAX: Self = Self::new(T::MAX);
+}
+
+impl<T: AssociativeOver<Addition>, Op> AssociativeOver<Addition> for Tropical<T, Op> {}
+impl<T: CommutativeOver<Addition>, Op> CommutativeOver<Addition> for Tropical<T, Op> {}
+impl<T: ClosedUnder<Addition>, Op> ClosedUnder<Addition> for Tropical<T, Op> {}
+impl<T: Identity<Addition>, Op> Identity<Addition> for Tropical<T, Op> {
+    const IDENTITY: Self = Self::ONE;
+    fn is_identity(&self) -> bool {
+        self.value.is_identity()
+    }
+}
+impl<T: TropicalOver<Op>, Op: Operator> Absorption<Addition> for Tropical<T, Op> {
+    const ABSORBING: Self = Self::ZERO;
+    fn is_absorber(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+/// Everything about `Op` comes from `T`, with the sign of the infinity the
+/// zero gets printed as.
+macro_rules! impl_tropical {
+    ($($op:ident $infinity:literal),*) => {
+        $(
+            impl<T: AssociativeOver<$op>> AssociativeOver<$op> for Tropical<T, $op> {}
+            impl<T: CommutativeOver<$op>> CommutativeOver<$op> for Tropical<T, $op> {}
+            impl<T: ClosedUnder<$op>> ClosedUnder<$op> for Tropical<T, $op> {}
+            impl<T: TropicalOver<$op>> Distributive<Addition, $op> for Tropical<T, $op> {}
+            impl<T: TropicalOver<$op>> Identity<$op> for Tropical<T, $op> {
+                const IDENTITY: Self = Self::ZERO;
+                fn is_identity(&self) -> bool {
+                    self.is_zero()
+                }
+            }
+            impl<T: fmt::Display + TropicalOver<$op>> fmt::Display for Tropical<T, $op> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if self.is_zero() {
+                        f.write_str($infinity)
+                    } else {
+                        fmt::Display::fmt(&self.value, f)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_tropical!(Min "∞", Max "-∞");
@@ -0,0 +1,65 @@
+//! The three tolerance modes of `ApproxEq` on the floats, and the assertions
+//! built on them.
+
+use existant_core::{assert_approx_eq, assert_approx_ne, ApproxEq, Tolerance};
+
+#[test]
+fn absolute() {
+    assert!(1e-9f64.abs_diff_eq(&0.0, 1e-8));
+    assert!(!1e-7f64.abs_diff_eq(&0.0, 1e-8));
+    assert!(1000.0f32.abs_diff_eq(&1000.5, 0.5));
+    assert!(!1000.0f32.abs_diff_eq(&1000.5, 0.25));
+}
+
+#[test]
+fn relative() {
+    assert!(1e6f64.relative_eq(&(1e6 + 1e-4), 1e-9));
+    assert!(!1.0f64.relative_eq(&(1.0 + 1e-4), 1e-9));
+    assert!(!1e-9f32.relative_eq(&0.0, 0.5));
+    assert!((-2.0f32).relative_eq(&-2.1, 0.05));
+}
+
+#[test]
+fn ulps() {
+    let one = 1.0f32;
+    let next = f32::from_bits(one.to_bits() + 3);
+    assert!(one.ulps_eq(&next, 3));
+    assert!(!one.ulps_eq(&next, 2));
+    assert!(0.0f64.ulps_eq(&-0.0, 0));
+    assert!(!f64::from_bits(1).ulps_eq(&-f64::from_bits(1), 4));
+    assert!(f64::MAX.ulps_eq(&f64::from_bits(f64::MAX.to_bits() - 1), 1));
+}
+
+#[test]
+fn non_finite() {
+    for tolerance in [Tolerance::Absolute(f32::MAX), Tolerance::Relative(1.0), Tolerance::Ulps(u32::MAX)] {
+        assert!(f32::INFINITY.approx_eq(&f32::INFINITY, tolerance));
+        assert!(!f32::INFINITY.approx_eq(&f32::MAX, tolerance));
+        assert!(!f32::INFINITY.approx_eq(&f32::NEG_INFINITY, tolerance));
+        assert!(!f32::NAN.approx_eq(&f32::NAN, tolerance));
+        assert!(!f32::NAN.approx_eq(&1.0, tolerance));
+    }
+}
+
+#[test]
+fn slices() {
+    let a = [0.1f64 + 0.2, 1.0];
+    assert!(a.approx_eq(&[0.3, 1.0], Tolerance::Ulps(1)));
+    assert!(!a.approx_eq(&[0.3, 1.1], Tolerance::Ulps(1)));
+    assert!(!a[..].approx_eq(&[0.3], Tolerance::Ulps(1)));
+}
+
+#[test]
+fn assertions() {
+    assert_approx_eq!(0.1f64 + 0.2, 0.3);
+    assert_approx_eq!(1e-10f32, 0.0, Tolerance::Absolute(1e-9));
+    assert_approx_eq!([1.0f32, 2.0], [1.0, 2.0000002], Tolerance::Ulps(4), "with a message {}", 1);
+    assert_approx_ne!(1.0f64, 1.001);
+    assert_approx_ne!(1.0f64, 1.001, Tolerance::Relative(1e-4));
+}
+
+#[test]
+#[should_panic(expected = "assertion `left ≈ right` failed: off by 0.1\n     left: 1.0\n    right: 1.1\ntolerance: Relative(0.01)")]
+fn failed_assertion() {
+    assert_approx_eq!(1.0f64, 1.1, Tolerance::Relative(0.01), "off by {}", 0.1);
+}
@@ -0,0 +1,91 @@
+//! `BigUint` and `BigInt` with two limbs against `u128` and `i128`, which
+//! they should agree with everywhere the primitives don't overflow.
+
+use existant_core::{BigInt, BigUint, ParseBigIntError, U256};
+
+/// Values around the limb boundary and the ends of the range.
+const UNSIGNED: [u128; 12] = [0, 1, 2, 10, u64::MAX as u128 - 1, u64::MAX as u128, 1 << 64, (1 << 64) + 1, 0xDEAD_BEEF_0123_4567_89AB_CDEF_FEDC_BA98, (1 << 127) - 1, 1 << 127, u128::MAX];
+const SIGNED: [i128; 12] = [0, 1, -1, 7, -7, i64::MIN as i128, i64::MAX as i128, i64::MIN as i128 - 1, 1 << 64, -(1 << 64), i128::MAX, i128::MIN + 1];
+
+#[test]
+fn unsigned_against_u128() {
+    let big = BigUint::<2>::from_u128;
+    for a in UNSIGNED {
+        for b in UNSIGNED {
+            assert_eq!(big(a).checked_add(big(b)), a.checked_add(b).map(big), "{a} + {b}");
+            assert_eq!(big(a).checked_sub(big(b)), a.checked_sub(b).map(big), "{a} - {b}");
+            assert_eq!(big(a).checked_mul(big(b)), a.checked_mul(b).map(big), "{a} * {b}");
+            assert_eq!(big(a).checked_div_rem(big(b)), a.checked_div(b).map(|q| (big(q), big(a % b))), "{a} / {b}");
+            assert_eq!(big(a).cmp(&big(b)), a.cmp(&b));
+        }
+        assert_eq!(big(a).to_string(), a.to_string());
+        assert_eq!(a.to_string().parse(), Ok(big(a)));
+        assert_eq!(big(a).to_f64(), a as f64);
+        assert_eq!(big(a).to_f32(), a as f32);
+        assert_eq!(BigUint::<2>::from_f64(a as f64).low_u128(), (a as f64) as u128);
+        assert_eq!(big(a).bits(), 128 - a.leading_zeros());
+        assert_eq!(big(a) << 3 >> 3, big(a << 3 >> 3));
+    }
+}
+
+#[test]
+fn signed_against_i128() {
+    let big = BigInt::<2>::from_i128;
+    for a in SIGNED {
+        for b in SIGNED {
+            assert_eq!(big(a).checked_add(big(b)).unwrap().low_i128(), a.wrapping_add(b), "{a} + {b}");
+            assert_eq!(big(a).checked_sub(big(b)).unwrap().low_i128(), a.wrapping_sub(b), "{a} - {b}");
+            if let Some(product) = a.checked_mul(b) {
+                assert_eq!(big(a) * big(b), big(product), "{a} * {b}");
+            }
+            if let (Some(quotient), Some(remainder)) = (a.checked_div(b), a.checked_rem(b)) {
+                assert_eq!(big(a).checked_div_rem(big(b)), Some((big(quotient), big(remainder))), "{a} / {b}");
+            }
+            assert_eq!(big(a).cmp(&big(b)), a.cmp(&b));
+        }
+        assert_eq!(big(a).to_string(), a.to_string());
+        assert_eq!(a.to_string().parse(), Ok(big(a)));
+        assert_eq!(big(a).to_f64(), a as f64);
+        assert_eq!(-big(a), big(-a));
+    }
+}
+
+#[test]
+fn carries() {
+    // every limb full, so a carry runs the whole length
+    let all_ones = U256::from_limbs([u64::MAX; 4]);
+    assert_eq!(all_ones.checked_add(U256::ONE), None);
+    assert_eq!(U256::from_limbs([u64::MAX, u64::MAX, u64::MAX, 0]) + U256::ONE, U256::from_limbs([0, 0, 0, 1]));
+    assert_eq!(U256::from_limbs([0, 0, 0, 1]) - U256::ONE, U256::from_limbs([u64::MAX, u64::MAX, u64::MAX, 0]));
+    assert_eq!(U256::ZERO.checked_sub(U256::ONE), None);
+    // (2^128 - 1)² = 2^256 - 2^129 + 1
+    let square = U256::from_u128(u128::MAX).pow(2);
+    assert_eq!(square, U256::from_limbs([1, 0, u64::MAX - 1, u64::MAX]));
+    assert_eq!(U256::from_u128(1 << 64).checked_pow(4), None);
+    assert_eq!(square / U256::from_u128(u128::MAX), U256::from_u128(u128::MAX));
+    assert_eq!(square % U256::from_u128(u128::MAX - 1), U256::ONE);
+    assert_eq!(U256::ONE << 255 >> 255, U256::ONE);
+    assert_eq!((U256::ONE << 200).bits(), 201);
+    assert_eq!(U256::ONE.checked_div_rem(U256::ZERO), None);
+}
+
+#[test]
+fn signed_edges() {
+    type I = BigInt<1>;
+    // the magnitude has a whole limb, so there's no asymmetric minimum
+    assert_eq!(-I::MIN, I::MAX);
+    assert_eq!(I::MIN.to_string(), format!("-{}", u64::MAX));
+    assert_eq!(I::from_i128(i64::MIN as i128).unsigned_abs(), BigUint::from_u128(1 << 63));
+    assert_eq!(I::from_i128(i64::MIN as i128).low_i128(), i64::MIN as i128);
+    assert_eq!(I::MAX.checked_add(I::ONE), None);
+    assert_eq!(I::MIN.checked_sub(I::ONE), None);
+    assert_eq!(I::MIN.checked_add(I::MAX), Some(I::ZERO));
+    assert_eq!(-I::ZERO, I::ZERO);
+    assert_eq!(I::from_i128(-7).signum(), -I::ONE);
+    assert_eq!(I::from_f64(-1e30), I::MIN);
+    assert_eq!(I::from_f64(-2.9), I::from_i128(-2));
+    assert_eq!("-0".parse::<I>(), Ok(I::ZERO));
+    assert_eq!("-".parse::<I>(), Err(ParseBigIntError::Empty));
+    assert_eq!("18446744073709551616".parse::<I>(), Err(ParseBigIntError::Overflow));
+    assert_eq!("12a".parse::<BigUint<1>>(), Err(ParseBigIntError::InvalidDigit));
+}
@@ -0,0 +1,25 @@
+//! `Bounds` against std.
+
+macro_rules! bounds {
+    ($float:ident) => {
+        mod $float {
+            use existant_core::Bounds;
+
+            use crate::{float::Tolerance::Exact, inputs, report::Report};
+
+            #[test]
+            fn bounds() {
+                let inputs = inputs::binary::<$float>();
+                let mut report = Report::new();
+                report.check(concat!(stringify!($float), "::min"), &inputs, Exact, |(x, y)| Bounds::min(x, y), |(x, y): ($float, $float)| x.min(y));
+                report.check(concat!(stringify!($float), "::max"), &inputs, Exact, |(x, y)| Bounds::max(x, y), |(x, y): ($float, $float)| x.max(y));
+                report.finish();
+                assert_eq!(<$float as Bounds>::MIN.to_bits(), $float::MIN.to_bits());
+                assert_eq!(<$float as Bounds>::MAX.to_bits(), $float::MAX.to_bits());
+            }
+        }
+    };
+}
+
+bounds!(f32);
+bounds!(f64);
@@ -0,0 +1,113 @@
+/// Bit level access to the floating point types under test, used to measure
+/// the distance between two results in units in the last place (ULPs).
+pub trait TestFloat: Copy + PartialEq + PartialOrd + core::fmt::Debug + 'static {
+    const ZERO: Self;
+    /// Values where implementations tend to disagree.
+    const EDGE_CASES: &'static [Self];
+    fn to_raw(self) -> u64;
+    fn from_raw(bits: u64) -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn nan(self) -> bool;
+    fn sign_negative(self) -> bool;
+    /// Maps the float onto a line of integers where neighbouring floats are
+    /// one apart, with both zeros mapped to the same point.
+    fn ordinal(self) -> i128;
+}
+
+macro_rules! impl_test_float {
+    ($float:ident, $bits:ident, $mantissa_bits:expr) => {
+        impl TestFloat for $float {
+            const ZERO: Self = 0.0;
+            const EDGE_CASES: &'static [Self] = &{
+                const SMALLEST_SUBNORMAL: $float = $float::from_bits(1);
+                const LARGEST_SUBNORMAL: $float = $float::from_bits($float::MIN_POSITIVE.to_bits() - 1);
+                const HALF_BELOW: $float = $float::from_bits((0.5 as $float).to_bits() - 1);
+                // The largest values which still have a fractional part.
+                const LAST_FRACTION: $float = ((1 as $bits) << ($mantissa_bits - 1)) as $float - 0.5;
+                const FIRST_INTEGRAL: $float = ((1 as $bits) << $mantissa_bits) as $float + 2.0;
+                let positive = [
+                    0.0, SMALLEST_SUBNORMAL, LARGEST_SUBNORMAL, $float::MIN_POSITIVE,
+                    $float::EPSILON, HALF_BELOW, 0.5, 1.0 - $float::EPSILON / 2.0, 1.0,
+                    1.0 + $float::EPSILON, 1.5, 2.0, 2.5, 3.0, 3.5, 10.0, 100.0,
+                    core::$float::consts::FRAC_PI_4, core::$float::consts::FRAC_PI_2,
+                    core::$float::consts::PI, core::$float::consts::TAU, core::$float::consts::E,
+                    LAST_FRACTION, FIRST_INTEGRAL, 1.0e10, 1.0e20, 1.0e30,
+                    $float::MAX / 4.0, $float::MAX / 2.0, $float::MAX, $float::INFINITY, $float::NAN,
+                ];
+                let mut cases = [0.0; 64];
+                let mut i = 0;
+                while i < positive.len() {
+                    cases[2*i] = positive[i];
+                    cases[2*i + 1] = -positive[i];
+                    i += 1;
+                }
+                cases
+            };
+            fn to_raw(self) -> u64 {
+                self.to_bits() as u64
+            }
+            fn from_raw(bits: u64) -> Self {
+                $float::from_bits(bits as $bits)
+            }
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn nan(self) -> bool {
+                self.is_nan()
+            }
+            fn sign_negative(self) -> bool {
+                self.is_sign_negative()
+            }
+            fn ordinal(self) -> i128 {
+                let magnitude = (self.to_bits() & !(1 << ($bits::BITS - 1))) as i128;
+                if self.is_sign_negative() { -magnitude } else { magnitude }
+            }
+        }
+    };
+}
+
+impl_test_float!(f32, u32, 23);
+impl_test_float!(f64, u64, 52);
+
+/// Distance between two floats in ULPs, or `None` if only one of them is NaN.
+/// Two NaNs are considered equal, regardless of payload.
+pub fn ulp_distance<F: TestFloat>(a: F, b: F) -> Option<u128> {
+    match (a.nan(), b.nan()) {
+        (true, true) => Some(0),
+        (false, false) => Some(a.ordinal().abs_diff(b.ordinal())),
+        _ => None,
+    }
+}
+
+/// How closely a result has to follow the result from std.
+#[derive(Debug, Clone, Copy)]
+pub enum Tolerance {
+    /// The bit patterns must be identical, NaN payloads included.
+    Bitwise,
+    /// The bit patterns must be identical, the sign of zero included. Any NaN
+    /// matches any other NaN.
+    Exact,
+    /// The results may be up to this many ULPs apart, but zeros must still
+    /// have the same sign.
+    Ulps(u128),
+}
+
+pub fn conforms<F: TestFloat>(ours: F, reference: F, tolerance: Tolerance) -> bool {
+    if let Tolerance::Bitwise = tolerance {
+        return ours.to_raw() == reference.to_raw();
+    }
+    if ours.nan() || reference.nan() {
+        return ours.nan() && reference.nan();
+    }
+    if ours == F::ZERO && reference == F::ZERO && ours.sign_negative() != reference.sign_negative() {
+        return false;
+    }
+    match tolerance {
+        Tolerance::Bitwise | Tolerance::Exact => ours.to_raw() == reference.to_raw(),
+        Tolerance::Ulps(ulps) => ulp_distance(ours, reference).is_some_and(|distance| distance <= ulps),
+    }
+}
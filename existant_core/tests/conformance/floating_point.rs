@@ -0,0 +1,248 @@
+//! Every `FloatingPoint` method against its std counterpart.
+
+use crate::float::Tolerance::{self, Bitwise, Exact, Ulps};
+
+/// Methods backed by the C math library. By default these call the very same
+/// symbols std does and have to match bit for bit, while the `libm` crate is a
+/// separate implementation which is allowed to round differently.
+#[cfg(not(feature = "libm"))]
+const LIBM: Tolerance = Exact;
+#[cfg(feature = "libm")]
+const LIBM: Tolerance = Ulps(3);
+
+macro_rules! unary {
+    ($report:ident, $inputs:ident, $float:ident; $($method:ident: $tolerance:expr),* $(,)?) => {
+        $(
+            $report.check(
+                concat!(stringify!($float), "::", stringify!($method)),
+                &$inputs,
+                $tolerance,
+                |x| FloatingPoint::$method(x),
+                |x: $float| x.$method(),
+            );
+        )*
+    };
+}
+macro_rules! binary {
+    ($report:ident, $inputs:ident, $float:ident; $($method:ident: $tolerance:expr),* $(,)?) => {
+        $(
+            $report.check(
+                concat!(stringify!($float), "::", stringify!($method)),
+                &$inputs,
+                $tolerance,
+                |(x, y)| FloatingPoint::$method(x, y),
+                |(x, y): ($float, $float)| x.$method(y),
+            );
+        )*
+    };
+}
+macro_rules! classification {
+    ($report:ident, $inputs:ident, $float:ident; $($method:ident),* $(,)?) => {
+        $(
+            $report.check_eq(
+                concat!(stringify!($float), "::", stringify!($method)),
+                &$inputs,
+                |x| FloatingPoint::$method(x),
+                |x: $float| x.$method(),
+            );
+        )*
+    };
+}
+macro_rules! constants {
+    ($float:ident; $($constant:ident),* $(,)?) => {
+        $(
+            assert_eq!(
+                <$float as FloatConsts>::$constant.to_bits(),
+                core::$float::consts::$constant.to_bits(),
+                concat!(stringify!($float), "::", stringify!($constant)),
+            );
+        )*
+    };
+}
+
+macro_rules! floating_point {
+    ($float:ident, powi_reference: $powi_reference:expr, powi_tolerance: $powi_tolerance:expr) => {
+        mod $float {
+            use existant_core::{FloatConsts, FloatingPoint};
+
+            use crate::{inputs, report::Report};
+            use super::*;
+
+            #[test]
+            fn unary_methods() {
+                let inputs = inputs::unary::<$float>();
+                let mut report = Report::new();
+                unary!(report, inputs, $float;
+                    acos: LIBM,
+                    asin: LIBM,
+                    atan: LIBM,
+                    cbrt: LIBM,
+                    cos: LIBM,
+                    exp: LIBM,
+                    exp2: LIBM,
+                    exp_m1: LIBM,
+                    ln: LIBM,
+                    ln_1p: LIBM,
+                    log10: LIBM,
+                    log2: LIBM,
+                    sin: LIBM,
+                    sinh: LIBM,
+                    tan: LIBM,
+                    tanh: LIBM,
+                    ceil: Exact,
+                    floor: Exact,
+                    fract: Exact,
+                    recip: Exact,
+                    round: Exact,
+                    signum: Exact,
+                    sqrt: Exact,
+                    to_degrees: Exact,
+                    to_radians: Exact,
+                    trunc: Exact,
+                );
+                report.check(
+                    concat!(stringify!($float), "::sin_cos().0"),
+                    &inputs,
+                    LIBM,
+                    |x| FloatingPoint::sin_cos(x).0,
+                    |x: $float| x.sin_cos().0,
+                );
+                report.check(
+                    concat!(stringify!($float), "::sin_cos().1"),
+                    &inputs,
+                    LIBM,
+                    |x| FloatingPoint::sin_cos(x).1,
+                    |x: $float| x.sin_cos().1,
+                );
+                report.finish();
+            }
+
+            /// std computes these from `ln` and `ln_1p` instead of calling the
+            /// C library, which costs it a few ULPs everywhere and a lot where
+            /// its formulas break down: `acosh` and `asinh` overflow past
+            /// `MAX / 2`, and `acosh` and `atanh` cancel on the way to 1 and -1
+            /// respectively. Those ranges are left out.
+            #[test]
+            fn inverse_hyperbolic() {
+                let inputs = inputs::unary::<$float>();
+                let domain = |keep: fn($float) -> bool| inputs.iter().copied().filter(|&x| keep(x)).collect::<Vec<_>>();
+                let acosh = domain(|x| !((1.0..2.0).contains(&x) || x >= $float::MAX / 2.0));
+                let asinh = domain(|x| x.is_nan() || x.abs() < $float::MAX / 2.0);
+                let atanh = domain(|x| !(x > -1.0 && x < -0.5));
+                let mut report = Report::new();
+                unary!(report, acosh, $float; acosh: Ulps(3));
+                unary!(report, asinh, $float; asinh: Ulps(3));
+                unary!(report, atanh, $float; atanh: Ulps(3));
+                report.finish();
+            }
+
+            #[test]
+            fn binary_methods() {
+                let inputs = inputs::binary::<$float>();
+                let mut report = Report::new();
+                binary!(report, inputs, $float;
+                    atan2: LIBM,
+                    hypot: LIBM,
+                    powf: LIBM,
+                    log: LIBM,
+                    div_euclid: Exact,
+                    rem_euclid: Exact,
+                );
+                report.check(
+                    concat!(stringify!($float), "::min_num"),
+                    &inputs,
+                    Exact,
+                    |(x, y)| FloatingPoint::min_num(x, y),
+                    |(x, y): ($float, $float)| x.min(y),
+                );
+                report.check(
+                    concat!(stringify!($float), "::max_num"),
+                    &inputs,
+                    Exact,
+                    |(x, y)| FloatingPoint::max_num(x, y),
+                    |(x, y): ($float, $float)| x.max(y),
+                );
+                report.check_eq(
+                    concat!(stringify!($float), "::total_cmp"),
+                    &inputs,
+                    |(x, y)| FloatingPoint::total_cmp(&x, &y),
+                    |(x, y): ($float, $float)| x.total_cmp(&y),
+                );
+                // every pair against the reverse of another, for a third argument
+                let triples: Vec<_> = inputs.iter().zip(inputs.iter().rev()).map(|(&(x, y), &(z, _))| (x, y, z)).collect();
+                report.check(
+                    concat!(stringify!($float), "::mul_add"),
+                    &triples,
+                    LIBM,
+                    |(x, y, z)| FloatingPoint::mul_add(x, y, z),
+                    |(x, y, z): ($float, $float, $float)| x.mul_add(y, z),
+                );
+                report.finish();
+            }
+
+            #[test]
+            fn neighbours() {
+                let inputs = inputs::unary::<$float>();
+                let mut report = Report::new();
+                unary!(report, inputs, $float;
+                    next_up: Bitwise,
+                    next_down: Bitwise,
+                );
+                report.finish();
+            }
+
+            /// std's `powi` is repeated multiplication with unspecified
+            /// precision, so `powi` is held to `powf` instead.
+            #[test]
+            fn powi() {
+                let inputs = inputs::powi::<$float>();
+                let mut report = Report::new();
+                report.check(
+                    concat!(stringify!($float), "::powi"),
+                    &inputs,
+                    $powi_tolerance,
+                    |(x, n)| FloatingPoint::powi(x, n),
+                    $powi_reference,
+                );
+                report.finish();
+            }
+
+            #[test]
+            fn classification() {
+                let inputs = inputs::unary::<$float>();
+                let mut report = Report::new();
+                classification!(report, inputs, $float;
+                    is_finite,
+                    is_infinite,
+                    is_nan,
+                    is_normal,
+                    is_subnormal,
+                );
+                report.finish();
+            }
+
+            #[test]
+            fn constants() {
+                constants!($float;
+                    PI, TAU, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8,
+                    FRAC_1_PI, FRAC_2_PI, FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2,
+                    E, LOG2_E, LOG2_10, LOG10_E, LOG10_2, LN_2, LN_10,
+                );
+                assert_eq!(<$float as FloatConsts>::EPSILON, $float::EPSILON);
+                assert_eq!(<$float as FloatConsts>::INFINITY, $float::INFINITY);
+            }
+        }
+    };
+}
+
+// The f32 reference is computed in f64, which is close enough to exact that
+// rounding it once more gives the nearest f32 in all but halfway cases. Past
+// 2^24 our `powi` raises the exponent in two parts, which rounds once more.
+floating_point!(f32,
+    powi_reference: |(x, n): (f32, i32)| (x as f64).powf(n as f64) as f32,
+    powi_tolerance: Ulps(2)
+);
+floating_point!(f64,
+    powi_reference: |(x, n): (f64, i32)| x.powf(n as f64),
+    powi_tolerance: LIBM
+);
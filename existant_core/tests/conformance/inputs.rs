@@ -0,0 +1,89 @@
+use crate::float::TestFloat;
+
+/// Seeded xorshift64* generator, so a failing sweep can be reproduced.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+    /// A float drawn from one of three distributions: any bit pattern, a
+    /// magnitude spread evenly over the exponent range, or a small value
+    /// around the origin where most functions are interesting.
+    pub fn next_float<F: TestFloat>(&mut self) -> F {
+        let raw = self.next_u64();
+        match raw % 3 {
+            0 => F::from_raw(self.next_u64()),
+            1 => {
+                let exponent = (self.next_u64() % 200) as i32 - 100;
+                let mantissa = 1.0 + (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+                let sign = if raw & 8 == 0 { 1.0 } else { -1.0 };
+                F::from_f64(sign * mantissa * 2f64.powi(exponent))
+            }
+            _ => F::from_f64(((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 16.0),
+        }
+    }
+}
+
+const SWEEP_LEN: usize = 20_000;
+
+/// Every edge case followed by the random sweep.
+pub fn unary<F: TestFloat>() -> Vec<F> {
+    unary_sweep(SWEEP_LEN)
+}
+
+pub fn unary_sweep<F: TestFloat>(len: usize) -> Vec<F> {
+    let mut rng = Rng::new(0x5EED_0001);
+    let mut values = F::EDGE_CASES.to_vec();
+    values.extend((0..len).map(|_| rng.next_float::<F>()));
+    values
+}
+
+/// Every pair of edge cases followed by random pairs.
+pub fn binary<F: TestFloat>() -> Vec<(F, F)> {
+    binary_sweep(SWEEP_LEN)
+}
+
+pub fn binary_sweep<F: TestFloat>(len: usize) -> Vec<(F, F)> {
+    let mut rng = Rng::new(0x5EED_0002);
+    let mut pairs = Vec::new();
+    for &a in F::EDGE_CASES {
+        for &b in F::EDGE_CASES {
+            pairs.push((a, b));
+        }
+    }
+    pairs.extend((0..len).map(|_| (rng.next_float::<F>(), rng.next_float::<F>())));
+    pairs
+}
+
+/// Exponents for `powi`, including the ones which can't be represented
+/// exactly as an f32.
+pub const EXPONENTS: &[i32] = &[
+    0, 1, -1, 2, -2, 3, -3, 7, -7, 16, -16, 31, -31, 127, -127, 128, -128, 1000, -1000,
+    (1 << 24) - 1, -(1 << 24) + 1, (1 << 24) + 1, -(1 << 24) - 1, (1 << 25) + 3, -(1 << 25) - 3,
+    (1 << 30) + 1, -(1 << 30) - 1, i32::MAX, i32::MIN + 1, i32::MIN,
+];
+
+/// Pairs for `powi`. Bases near one keep huge exponents from always
+/// overflowing or flushing to zero.
+pub fn powi<F: TestFloat>() -> Vec<(F, i32)> {
+    let mut rng = Rng::new(0x5EED_0003);
+    let mut bases = F::EDGE_CASES.to_vec();
+    for step in 1..=8u64 {
+        let one = F::from_f64(1.0).to_raw();
+        bases.extend([F::from_raw(one + step), F::from_raw(one - step)]);
+        bases.extend([F::from_raw(one + step), F::from_raw(one - step)].map(|x| F::from_f64(-x.to_f64())));
+    }
+    let mut pairs: Vec<(F, i32)> = bases.iter().flat_map(|&x| EXPONENTS.iter().map(move |&n| (x, n))).collect();
+    pairs.extend((0..SWEEP_LEN).map(|_| {
+        let n = (rng.next_u64() % 64) as i32 - 32;
+        (rng.next_float::<F>(), n)
+    }));
+    pairs
+}
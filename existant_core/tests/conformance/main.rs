@@ -0,0 +1,17 @@
+//! Conformance harness for the hand written number trait implementations of
+//! [`f32`] and [`f64`]. Every method of `FloatingPoint`, `Signed`, `Bounds`,
+//! `FromPrimitive` and `IntoPrimitive` is swept over the edge cases of each
+//! type (NaN, ±inf, subnormals, ±0, large magnitudes) along with a seeded
+//! random sweep, and compared to the equivalent method from std within a
+//! tolerance measured in ULPs. On top of that, the f32 methods have to call
+//! the f32 functions of the math library rather than the f64 ones.
+
+mod float;
+mod inputs;
+mod report;
+
+mod bounds;
+mod floating_point;
+mod primitive;
+mod signed;
+mod single_precision;
@@ -0,0 +1,76 @@
+//! `FromPrimitive` and `IntoPrimitive` against `as` casts, which is what std
+//! offers for converting between primitives.
+
+use crate::inputs::Rng;
+
+/// The extremes of `T`, the values around the points where f32 and f64 run
+/// out of precision, and a random sweep.
+fn integers<T: Copy>(min: T, max: T, cast: impl Fn(u128) -> T) -> Vec<T> {
+    let mut rng = Rng::new(0x5EED_0004);
+    let mut values = vec![min, max, cast(0), cast(1), cast(u128::MAX)];
+    for bits in [24, 25, 53, 54, 64, 127] {
+        let power = 1u128 << bits;
+        values.extend([power - 1, power, power + 1, power + 3].map(&cast));
+    }
+    values.extend((0..4096).map(|_| cast((rng.next_u64() as u128) << 64 | rng.next_u64() as u128)));
+    values
+}
+
+macro_rules! primitive {
+    ($float:ident; $($from:ident: $integer:ident),* ; $($to:ident: $target:ident),* $(,)?) => {
+        mod $float {
+            use existant_core::{FromPrimitive, IntoPrimitive};
+
+            use crate::{float::Tolerance::Exact, inputs, report::Report};
+
+            #[test]
+            fn from_primitive() {
+                let mut report = Report::new();
+                $(
+                    let integers = super::integers($integer::MIN, $integer::MAX, |x| x as $integer);
+                    report.check(
+                        concat!(stringify!($float), "::", stringify!($from)),
+                        &integers,
+                        Exact,
+                        |x| <$float as FromPrimitive>::$from(x),
+                        |x| x as $float,
+                    );
+                )*
+                let floats = inputs::unary::<f32>();
+                report.check(concat!(stringify!($float), "::from_f32"), &floats, Exact, |x| <$float as FromPrimitive>::from_f32(x), |x| x as $float);
+                let floats = inputs::unary::<f64>();
+                report.check(concat!(stringify!($float), "::from_f64"), &floats, Exact, |x| <$float as FromPrimitive>::from_f64(x), |x| x as $float);
+                report.finish();
+            }
+
+            #[test]
+            fn into_primitive() {
+                let inputs = inputs::unary::<$float>();
+                let mut report = Report::new();
+                $(
+                    report.check_eq(
+                        concat!(stringify!($float), "::", stringify!($to)),
+                        &inputs,
+                        |x| IntoPrimitive::$to(x),
+                        |x: $float| x as $target,
+                    );
+                )*
+                report.check(concat!(stringify!($float), "::to_f32"), &inputs, Exact, |x| IntoPrimitive::to_f32(x), |x: $float| x as f32);
+                report.check(concat!(stringify!($float), "::to_f64"), &inputs, Exact, |x| IntoPrimitive::to_f64(x), |x: $float| x as f64);
+                report.finish();
+            }
+        }
+    };
+    ($($float:ident),*) => {
+        $(
+            primitive!($float;
+                from_u8: u8, from_u16: u16, from_u32: u32, from_u64: u64, from_u128: u128, from_usize: usize,
+                from_i8: i8, from_i16: i16, from_i32: i32, from_i64: i64, from_i128: i128, from_isize: isize;
+                to_u8: u8, to_u16: u16, to_u32: u32, to_u64: u64, to_u128: u128, to_usize: usize,
+                to_i8: i8, to_i16: i16, to_i32: i32, to_i64: i64, to_i128: i128, to_isize: isize,
+            );
+        )*
+    };
+}
+
+primitive!(f32, f64);
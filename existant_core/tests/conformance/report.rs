@@ -0,0 +1,84 @@
+use std::fmt::Debug;
+
+use crate::float::{conforms, ulp_distance, TestFloat, Tolerance};
+
+const SHOWN: usize = 8;
+
+/// NaNs are printed with their bits, since the payload and sign can matter.
+fn show<F: TestFloat>(value: F) -> String {
+    if value.nan() { format!("NaN ({:#x})", value.to_raw()) } else { format!("{value:?}") }
+}
+
+/// Collects every input where a method disagrees with std, so one run shows
+/// all of the broken methods instead of only the first.
+#[derive(Default)]
+pub struct Report {
+    failures: Vec<String>,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Runs `ours` and `reference` over every input and records mismatches
+    /// under `method`.
+    pub fn check<I: Debug + Copy, F: TestFloat>(
+        &mut self,
+        method: &str,
+        inputs: &[I],
+        tolerance: Tolerance,
+        ours: impl Fn(I) -> F,
+        reference: impl Fn(I) -> F,
+    ) {
+        let mut mismatches = 0;
+        let mut shown = String::new();
+        for &input in inputs {
+            let input = std::hint::black_box(input);
+            let (got, expected) = (ours(input), reference(input));
+            if !conforms(got, expected, tolerance) {
+                mismatches += 1;
+                if mismatches <= SHOWN {
+                    let distance = ulp_distance(got, expected).map_or("NaN".into(), |d| format!("{d} ULPs"));
+                    let (got, expected) = (show(got), show(expected));
+                    shown += &format!("\n    {input:?}: got {got}, std gives {expected} ({distance})");
+                }
+            }
+        }
+        if mismatches > 0 {
+            self.failures.push(format!(
+                "{method} ({tolerance:?}) disagrees with std on {mismatches} of {} inputs:{shown}",
+                inputs.len(),
+            ));
+        }
+    }
+    /// Same as [`Report::check`] for anything that isn't a float, where the
+    /// results have to be equal.
+    pub fn check_eq<I: Debug + Copy, O: Debug + PartialEq>(
+        &mut self,
+        method: &str,
+        inputs: &[I],
+        ours: impl Fn(I) -> O,
+        reference: impl Fn(I) -> O,
+    ) {
+        let mut mismatches = 0;
+        let mut shown = String::new();
+        for &input in inputs {
+            let input = std::hint::black_box(input);
+            let (got, expected) = (ours(input), reference(input));
+            if got != expected {
+                mismatches += 1;
+                if mismatches <= SHOWN {
+                    shown += &format!("\n    {input:?}: got {got:?}, std gives {expected:?}");
+                }
+            }
+        }
+        if mismatches > 0 {
+            self.failures.push(format!("{method} disagrees with std on {mismatches} of {} inputs:{shown}", inputs.len()));
+        }
+    }
+    pub fn finish(self) {
+        if !self.failures.is_empty() {
+            panic!("{} method(s) failed conformance:\n\n{}", self.failures.len(), self.failures.join("\n\n"));
+        }
+    }
+}
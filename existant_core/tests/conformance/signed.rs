@@ -0,0 +1,44 @@
+//! `Signed` against std. These only touch the sign bit, so they have to match
+//! bit for bit, NaN payloads included.
+
+macro_rules! signed {
+    ($float:ident) => {
+        mod $float {
+            use existant_core::Signed;
+
+            use crate::{float::Tolerance::Bitwise, inputs, report::Report};
+
+            #[test]
+            fn signed() {
+                let unary = inputs::unary::<$float>();
+                let binary = inputs::binary::<$float>();
+                let mut report = Report::new();
+                report.check(concat!(stringify!($float), "::abs"), &unary, Bitwise, |x| Signed::abs(x), |x: $float| x.abs());
+                report.check(concat!(stringify!($float), "::flip_sign"), &unary, Bitwise, |x| Signed::flip_sign(x), |x: $float| -x);
+                report.check(
+                    concat!(stringify!($float), "::copysign"),
+                    &binary,
+                    Bitwise,
+                    |(x, y)| Signed::copysign(x, y),
+                    |(x, y): ($float, $float)| x.copysign(y),
+                );
+                report.check_eq(
+                    concat!(stringify!($float), "::is_negative"),
+                    &unary,
+                    |x| Signed::is_negative(x),
+                    |x: $float| x.is_sign_negative(),
+                );
+                report.check_eq(
+                    concat!(stringify!($float), "::is_positive"),
+                    &unary,
+                    |x| Signed::is_positive(x),
+                    |x: $float| x.is_sign_positive(),
+                );
+                report.finish();
+            }
+        }
+    };
+}
+
+signed!(f32);
+signed!(f64);
@@ -0,0 +1,104 @@
+//! Catches f32 methods which compute in f64 and round the result, instead of
+//! calling the f32 function of the math library. Both are close to the true
+//! result, so std can't tell them apart within a tolerance; the inputs where
+//! the f32 function and the rounded f64 function disagree can.
+//!
+//! Rounding, `sqrt` and the other correctly rounded operations give the same
+//! result either way, and so do `cbrt` and `hypot` with the platform library,
+//! so they aren't checked here.
+
+use existant_core::FloatingPoint;
+
+use crate::{float::{conforms, Tolerance::Exact}, inputs};
+
+const SWEEP_LEN: usize = 200_000;
+
+/// Tries `ours` on every input where `single` and `double` disagree, and
+/// returns a description of the first one it gets wrong. Also fails if there
+/// are no such inputs, since then nothing was actually tested.
+fn check<I: core::fmt::Debug + Copy>(
+    method: &str,
+    inputs: &[I],
+    ours: impl Fn(I) -> f32,
+    single: impl Fn(I) -> f32,
+    double: impl Fn(I) -> f32,
+) -> Option<String> {
+    let mut witnesses = 0;
+    for &input in inputs {
+        let input = std::hint::black_box(input);
+        let (single, double) = (single(input), double(input));
+        if conforms(single, double, Exact) {
+            continue;
+        }
+        witnesses += 1;
+        let ours = ours(input);
+        if conforms(ours, double, Exact) {
+            return Some(format!("f32::{method} goes through f64: {input:?} gives {ours:?} instead of {single:?}"));
+        }
+        if !conforms(ours, single, Exact) {
+            return Some(format!("f32::{method} doesn't match the math library: {input:?} gives {ours:?} instead of {single:?}"));
+        }
+    }
+    (witnesses == 0).then(|| format!("f32::{method}: no input in the sweep tells f32 and f64 apart"))
+}
+
+macro_rules! unary {
+    ($failures:ident, $inputs:ident; $($method:ident: $single:ident / $double:ident),* $(,)?) => {
+        $(
+            $failures.extend(check(
+                stringify!($method),
+                &$inputs,
+                |x| FloatingPoint::$method(x),
+                |x| existant_core::$single(x),
+                |x| existant_core::$double(x as f64) as f32,
+            ));
+        )*
+    };
+}
+macro_rules! binary {
+    ($failures:ident, $inputs:ident; $($method:ident: $single:ident / $double:ident),* $(,)?) => {
+        $(
+            $failures.extend(check(
+                stringify!($method),
+                &$inputs,
+                |(x, y)| FloatingPoint::$method(x, y),
+                |(x, y)| existant_core::$single(x, y),
+                |(x, y)| existant_core::$double(x as f64, y as f64) as f32,
+            ));
+        )*
+    };
+}
+
+#[test]
+fn f32_stays_in_f32() {
+    let unary = inputs::unary_sweep::<f32>(SWEEP_LEN);
+    let binary = inputs::binary_sweep::<f32>(SWEEP_LEN);
+    let mut failures = Vec::new();
+    unary!(failures, unary;
+        acos: acosf / acos,
+        acosh: acoshf / acosh,
+        asin: asinf / asin,
+        asinh: asinhf / asinh,
+        atan: atanf / atan,
+        atanh: atanhf / atanh,
+        cos: cosf / cos,
+        erf: erff / erf,
+        erfc: erfcf / erfc,
+        exp: expf / exp,
+        exp2: exp2f / exp2,
+        exp_m1: expm1f / expm1,
+        ln: logf / log,
+        ln_1p: log1pf / log1p,
+        log10: log10f / log10,
+        log2: log2f / log2,
+        sin: sinf / sin,
+        sinh: sinhf / sinh,
+        tan: tanf / tan,
+        tanh: tanhf / tanh,
+    );
+    binary!(failures, binary;
+        atan2: atan2f / atan2,
+        powf: powf / pow,
+    );
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
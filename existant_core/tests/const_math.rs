@@ -0,0 +1,91 @@
+//! `const_math` against std, on a sweep of every size of argument, and in
+//! `const` items to make sure it stays evaluable there.
+
+use existant_core::{assert_approx_eq, const_math, ApproxEq, FloatingPoint, Tolerance};
+
+const ROOT_2: f64 = const_math::sqrt(2.0);
+const SINES: [f32; 4] = {
+    let mut table = [0.0; 4];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = const_math::sinf(i as f32 * core::f32::consts::FRAC_PI_6);
+        i += 1;
+    }
+    table
+};
+
+/// A spread of doubles from subnormal to huge, both signs.
+fn sweep() -> impl Iterator<Item = f64> {
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    (0..200_000).map(move |i| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        match i % 3 {
+            0 => f64::from_bits(state),
+            _ => (state as f64 / u64::MAX as f64 - 0.5) * 1400.0,
+        }
+    })
+}
+
+#[test]
+fn in_const() {
+    assert_eq!(ROOT_2, 2.0f64.sqrt());
+    assert_eq!(SINES[0], 0.0);
+    assert_approx_eq!(SINES[1], 0.5, Tolerance::Ulps(1));
+    assert_approx_eq!(SINES[3], 1.0, Tolerance::Ulps(1));
+}
+
+/// Within `ulps` of std, or NaN where std is.
+fn check<T: ApproxEq<Epsilon = T> + FloatingPoint + core::fmt::Debug>(name: &str, x: T, ours: T, std: T, ulps: u32) {
+    if std.is_nan() {
+        assert!(ours.is_nan(), "{name}({x:?}) gives {ours:?} instead of NaN");
+    } else {
+        assert_approx_eq!(ours, std, Tolerance::Ulps(ulps), "{name}({x:?})");
+    }
+}
+
+#[test]
+fn against_std() {
+    for x in sweep() {
+        check("sqrt", x, const_math::sqrt(x), x.sqrt(), 0);
+        check("sin", x, const_math::sin(x), x.sin(), 1);
+        check("cos", x, const_math::cos(x), x.cos(), 1);
+        check("tan", x, const_math::tan(x), x.tan(), 2);
+        check("atan", x, const_math::atan(x), x.atan(), 1);
+        check("atan2", x, const_math::atan2(x, 3.0 - x), x.atan2(3.0 - x), 1);
+        check("exp", x, const_math::exp(x), x.exp(), 1);
+        check("ln", x, const_math::ln(x), x.ln(), 1);
+    }
+}
+
+#[test]
+fn single_precision() {
+    for x in sweep().map(|x| x as f32) {
+        check("sqrtf", x, const_math::sqrtf(x), x.sqrt(), 0);
+        check("sinf", x, const_math::sinf(x), x.sin(), 1);
+        check("expf", x, const_math::expf(x), x.exp(), 1);
+        check("lnf", x, const_math::lnf(x), x.ln(), 1);
+    }
+}
+
+#[test]
+fn edge_cases() {
+    assert!(const_math::sqrt(-1.0).is_nan());
+    assert_eq!(const_math::sqrt(-0.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(const_math::sqrt(f64::from_bits(1)), 2.0f64.powi(-537));
+    assert_eq!(const_math::ln(0.0), f64::NEG_INFINITY);
+    assert!(const_math::ln(-1.0).is_nan());
+    assert_eq!(const_math::exp(-800.0), 0.0);
+    assert_eq!(const_math::exp(710.0), f64::INFINITY);
+    assert_eq!(const_math::exp(-745.0), f64::from_bits(1));
+    assert!(const_math::sin(f64::INFINITY).is_nan());
+    assert_eq!(const_math::sin(-0.0).to_bits(), (-0.0f64).to_bits());
+    // the closest double to a multiple of π/2, where the reduction cancels
+    // the most
+    let x = 6381956970095103.0 * 2.0f64.powi(797);
+    assert_approx_eq!(const_math::sin(x), x.sin(), Tolerance::Ulps(1));
+    assert_eq!(const_math::atan2(0.0, -0.0), core::f64::consts::PI);
+    assert_eq!(const_math::atan2(-1.0, f64::NEG_INFINITY), -core::f64::consts::PI);
+    assert_eq!(const_math::atan2(f64::INFINITY, f64::INFINITY), core::f64::consts::FRAC_PI_4);
+}
@@ -0,0 +1,141 @@
+//! `Fixed` against f64, which holds every Q16.16 and Q8.24 value exactly,
+//! so the basic operations have to land within half a step of it and the
+//! integer-only transcendental functions within a few steps.
+
+use existant_core::{Bounds, FloatConsts, FloatingPoint, FromPrimitive, IntoPrimitive, Q16_16, Q32_32, Q8_24, Signed};
+
+/// Spread over the range, with the tiny and the saturating ones included.
+const VALUES: [f64; 14] = [0.0, 1.0, -1.0, 0.5, -0.25, 3.141_592, -2.718_281, 100.125, -1234.5, 0.001, -0.000_015_258_789_062_5, 32_767.0, -32_768.0, 12.345_678];
+
+fn q(value: f64) -> Q16_16 {
+    Q16_16::from_f64(value)
+}
+
+/// How many steps of 2^-16 `actual` is away from `expected`.
+fn steps(actual: Q16_16, expected: f64) -> f64 {
+    (actual.to_f64() - expected).abs() * 65536.0
+}
+
+/// `expected` clamped to what Q16.16 holds, since the operations saturate.
+fn saturated(expected: f64) -> f64 {
+    expected.clamp(Q16_16::MIN.to_f64(), Q16_16::MAX.to_f64())
+}
+
+#[test]
+fn arithmetic() {
+    for a in VALUES {
+        for b in VALUES {
+            let (x, y) = (q(a), q(b));
+            let (a, b) = (x.to_f64(), y.to_f64());
+            assert_eq!((x + y).to_f64(), saturated(a + b), "{a} + {b}");
+            assert_eq!((x - y).to_f64(), saturated(a - b), "{a} - {b}");
+            assert!(steps(x * y, saturated(a * b)) <= 0.5, "{a} * {b}");
+            if b != 0.0 {
+                assert!(steps(x / y, saturated(a / b)) <= 0.5, "{a} / {b}");
+                assert_eq!((x % y).to_f64(), a % b, "{a} % {b}");
+            }
+            assert_eq!(x < y, a < b);
+        }
+    }
+}
+
+#[test]
+fn functions() {
+    for a in VALUES {
+        let x = q(a);
+        let a = x.to_f64();
+        assert!(steps(x.sin(), a.sin()) <= 4.0, "sin {a}");
+        assert!(steps(x.cos(), a.cos()) <= 4.0, "cos {a}");
+        assert!(steps(x.atan(), a.atan()) <= 2.0, "atan {a}");
+        assert!(steps(x.abs().sqrt(), a.abs().sqrt()) <= 1.0, "sqrt {a}");
+        assert!(steps(x.abs().cbrt(), a.abs().cbrt()) <= 2.0, "cbrt {a}");
+        if a > 0.0 {
+            assert!(steps(x.ln(), a.ln()) <= 2.0, "ln {a}");
+            assert!(steps(x.log2(), a.log2()) <= 2.0, "log2 {a}");
+        }
+        if a < 10.0 {
+            // e^a keeps the relative error, so compare against its size
+            let exp = x.exp().to_f64();
+            assert!((exp - a.exp()).abs() <= 4.0 / 65536.0 * a.exp().max(1.0), "exp {a}");
+        }
+        if a.abs() <= 1.0 {
+            assert!(steps(x.asin(), a.asin()) <= 4.0, "asin {a}");
+            assert!(steps(x.acos(), a.acos()) <= 4.0, "acos {a}");
+        }
+        for b in VALUES {
+            let y = q(b);
+            assert!(steps(x.atan2(y), a.atan2(y.to_f64())) <= 4.0, "atan2 {a} {b}");
+        }
+    }
+    assert!(steps(Q16_16::PI, core::f64::consts::PI) <= 0.5);
+    assert!(steps(Q16_16::E, core::f64::consts::E) <= 0.5);
+    let precise = Q8_24::from_f64(0.5).sin().to_f64();
+    assert!((precise - 0.5f64.sin()).abs() <= 4.0 / 16_777_216.0);
+    let wide = Q32_32::from_f64(1e6).sqrt().to_f64();
+    assert!((wide - 1000.0).abs() <= 1.0 / 4_294_967_296.0);
+}
+
+#[test]
+fn powers() {
+    for a in VALUES {
+        let x = q(a);
+        let a = x.to_f64();
+        for n in [-200, -17, -3, -2, -1, 0, 1, 2, 3, 15, 16, 129, 200] {
+            if a == 0.0 && n < 0 {
+                continue;
+            }
+            assert!(steps(x.powi(n), saturated(a.powi(n))) <= 0.5, "{a}^{n}");
+        }
+    }
+    assert_eq!(q(0.0).powi(-1), Q16_16::MAX);
+    assert_eq!(q(-2.0).powi(15), Q16_16::MIN);
+    assert_eq!(q(-1.0).powi(129), -Q16_16::ONE);
+    assert_eq!(Q8_24::from_f64(-1.0).powi(i32::MIN), Q8_24::ONE);
+    // 1.01 isn't exact in Q8.24, so this is compared against what it is
+    let x = Q8_24::from_f64(1.01);
+    let expected = x.to_f64().powi(200);
+    assert!((x.powi(200).to_f64() - expected).abs() <= 0.5 / 16_777_216.0, "{expected}");
+}
+
+#[test]
+fn saturation() {
+    assert_eq!(Q16_16::MAX + Q16_16::ONE, Q16_16::MAX);
+    assert_eq!(Q16_16::MIN - Q16_16::ONE, Q16_16::MIN);
+    assert_eq!(-Q16_16::MIN, Q16_16::MAX);
+    assert_eq!(Q16_16::MIN.abs(), Q16_16::MAX);
+    assert_eq!(q(300.0) * q(300.0), Q16_16::MAX);
+    assert_eq!(q(-300.0) * q(300.0), Q16_16::MIN);
+    assert_eq!(q(1.0) / q(0.000_015_258_789_062_5) / q(2.0), q(32_768.0) / q(2.0));
+    assert_eq!(q(1.0) / Q16_16::ZERO, Q16_16::MAX);
+    assert_eq!(q(-1.0) / Q16_16::ZERO, Q16_16::MIN);
+    assert_eq!(Q16_16::ZERO / Q16_16::ZERO, Q16_16::ZERO);
+    assert_eq!(q(5.0) % Q16_16::ZERO, Q16_16::ZERO);
+    assert_eq!(Q16_16::MIN % q(-0.000_015_258_789_062_5), Q16_16::ZERO);
+    assert_eq!(q(1e10), Q16_16::MAX);
+    assert_eq!(q(f64::NEG_INFINITY), Q16_16::MIN);
+    assert_eq!(q(f64::NAN), Q16_16::ZERO);
+    assert_eq!(Q16_16::from_i64(i64::MIN), Q16_16::MIN);
+    assert_eq!(Q16_16::from_u128(u128::MAX), Q16_16::MAX);
+    assert_eq!(q(-2.5).to_i32(), -2);
+    assert_eq!(Q16_16::MAX.to_i8(), i8::MAX);
+    assert_eq!(Q16_16::MIN.to_u8(), 0);
+    // outside of the domain there's no NaN to return
+    assert_eq!(q(-1.0).sqrt(), Q16_16::ZERO);
+    assert_eq!(q(-1.0).ln(), Q16_16::ZERO);
+    assert_eq!(q(2.0).asin(), Q16_16::ZERO);
+    assert_eq!(q(100.0).exp(), Q16_16::MAX);
+}
+
+#[test]
+fn rounding() {
+    // half a step rounds away from zero
+    assert_eq!(Q16_16::from_f64(0.5 / 65536.0).to_bits(), 1);
+    assert_eq!(Q16_16::from_f64(-0.5 / 65536.0).to_bits(), -1);
+    assert_eq!(Q16_16::from_f64(0.49 / 65536.0).to_bits(), 0);
+    assert_eq!((Q16_16::from_bits(1) / q(2.0)).to_bits(), 1);
+    assert_eq!((Q16_16::from_bits(1) / q(3.0)).to_bits(), 0);
+    assert_eq!(format!("{}", q(-1234.5)), "-1234.5");
+    assert_eq!(format!("{:.2}", Q16_16::from_bits(1)), "0.00");
+    assert_eq!(format!("{:.1}", q(9.96)), "10.0");
+    assert_eq!(Q16_16::EPSILON.to_f64(), 1.0 / 65536.0);
+}
@@ -0,0 +1,144 @@
+//! `f16` and `bf16` through the traits here, against f64 and against `half`
+//! itself. There are only 2^16 of each, so most of this goes through every
+//! one of them.
+
+use existant_core::{FloatConsts, FloatingPoint, FromPrimitive, IntoPrimitive};
+use half::{bf16, f16};
+
+fn finite_f16() -> impl Iterator<Item = f16> {
+    (0..=u16::MAX).map(f16::from_bits).filter(|x| x.is_finite())
+}
+
+fn finite_bf16() -> impl Iterator<Item = bf16> {
+    (0..=u16::MAX).map(bf16::from_bits).filter(|x| x.is_finite())
+}
+
+/// The magnitude of `n` as an f64, with anything past 53 bits folded into
+/// the last one so rounding it again doesn't hit a false tie.
+fn sticky(n: i128) -> f64 {
+    let magnitude = n.unsigned_abs();
+    let shift = (128 - magnitude.leading_zeros()).saturating_sub(53);
+    let kept = magnitude >> shift | (magnitude & ((1 << shift) - 1) != 0) as u128;
+    let value = kept as f64 * (1u128 << shift) as f64;
+    if n < 0 { -value } else { value }
+}
+
+#[test]
+fn rounding_from_f64() {
+    // Right around every midpoint, where rounding through f32 first would
+    // lose which side of it the value was on.
+    macro_rules! check {
+        ($structure:ident, $values:expr) => {
+            let values: Vec<$structure> = $values.filter(|x| x.is_sign_positive() && *x != $structure::MAX).collect();
+            for x in values {
+                let y = $structure::from_bits(x.to_bits() + 1);
+                let midpoint = (x.to_f64() + y.to_f64()) / 2.0;
+                let even = if x.to_bits() & 1 == 0 { x } else { y };
+                assert_eq!(<$structure as FromPrimitive>::from_f64(midpoint), even, "{x}");
+                assert_eq!(<$structure as FromPrimitive>::from_f64(midpoint.next_up()), y, "{x}");
+                assert_eq!(<$structure as FromPrimitive>::from_f64(midpoint.next_down()), x, "{x}");
+                assert_eq!(<$structure as FromPrimitive>::from_f64(-midpoint.next_up()), -y, "{x}");
+            }
+        };
+    }
+    check!(f16, finite_f16());
+    check!(bf16, finite_bf16());
+
+    assert_eq!(<f16 as FromPrimitive>::from_f64(65519.99), f16::MAX);
+    assert_eq!(<f16 as FromPrimitive>::from_f64(65520.0), f16::INFINITY);
+    assert_eq!(<f16 as FromPrimitive>::from_f64(2.0f64.powi(-25)), f16::ZERO);
+    assert_eq!(<f16 as FromPrimitive>::from_f64(2.0f64.powi(-25).next_up()), f16::from_bits(1));
+    assert!(<bf16 as FromPrimitive>::from_f64(f64::NAN).is_nan());
+    assert_eq!(<bf16 as FromPrimitive>::from_f64(1e300), bf16::INFINITY);
+}
+
+#[test]
+fn integers() {
+    assert_eq!(f16::from_u64(65519), f16::MAX);
+    assert_eq!(f16::from_u64(65520), f16::INFINITY);
+    assert_eq!(f16::from_i32(-2049), f16::from_f32(-2048.0));
+    assert_eq!(f16::from_i32(-2051), f16::from_f32(-2052.0));
+    assert_eq!(f16::from_u128(u128::MAX), f16::INFINITY);
+    assert_eq!(bf16::from_u128(u128::MAX), bf16::from_f32(2.0f32.powi(127) * 2.0));
+    // a tie in the top bits, broken by the lowest one
+    assert_eq!(bf16::from_u128(1 << 120 | 1 << 112), bf16::from_f32(2.0f32.powi(120)));
+    assert_eq!(bf16::from_u128(1 << 120 | 1 << 112 | 1), bf16::from_f32(2.0f32.powi(120) + 2.0f32.powi(113)));
+    assert_eq!(bf16::from_i128(i128::MIN), bf16::from_f32(-2.0f32.powi(127)));
+    for n in -5000i32..5000 {
+        assert_eq!(f16::from_i32(n), <f16 as FromPrimitive>::from_f64(n as f64));
+        assert_eq!(bf16::from_i32(n), <bf16 as FromPrimitive>::from_f64(n as f64));
+    }
+
+    assert_eq!(f16::MAX.to_i32(), 65504);
+    assert_eq!(f16::MAX.to_u8(), u8::MAX);
+    assert_eq!(f16::from_f32(-1.5).to_u8(), 0);
+    assert_eq!(f16::from_f32(-1.5).to_i8(), -1);
+    assert_eq!(f16::NAN.to_i32(), 0);
+    assert_eq!(bf16::INFINITY.to_u64(), u64::MAX);
+    assert_eq!(bf16::NEG_INFINITY.to_i128(), i128::MIN);
+}
+
+#[test]
+fn fused_multiply_add() {
+    // Every f16 is an integer times 2^-24, so products and sums of them are
+    // exact as integers times 2^-48.
+    fn scaled(x: f16) -> i128 {
+        (x.to_f64() * 2.0f64.powi(24)) as i128
+    }
+    let values: Vec<f16> = finite_f16().step_by(97).collect();
+    let addends = [f16::ZERO, f16::from_bits(1), -f16::ONE, f16::from_f32(0.1), f16::from_f32(-300.0), f16::MAX];
+    for &a in &values {
+        for &b in values.iter().step_by(7) {
+            for c in addends {
+                let exact = scaled(a) * scaled(b) + (scaled(c) << 24);
+                let expected = <f16 as FromPrimitive>::from_f64(sticky(exact) * 2.0f64.powi(-48));
+                let actual = FloatingPoint::mul_add(a, b, c);
+                assert!(actual == expected || (actual == f16::ZERO && expected == f16::ZERO), "{a} * {b} + {c}");
+            }
+        }
+    }
+    assert!(FloatingPoint::mul_add(f16::INFINITY, f16::ZERO, f16::ONE).is_nan());
+    assert_eq!(FloatingPoint::mul_add(f16::MAX, f16::from_f32(2.0), -f16::MAX), f16::MAX);
+}
+
+#[test]
+fn functions() {
+    // Done in f32, so within a step of the f64 answer, and exact for the
+    // ones where f32 has more than twice the precision.
+    for x in finite_f16() {
+        let value = x.to_f64();
+        let round = <f16 as FromPrimitive>::from_f64;
+        let steps = |actual: f16, expected: f64| (actual.to_bits() as i32 - round(expected).to_bits() as i32).abs();
+        if value >= 0.0 {
+            assert_eq!(FloatingPoint::sqrt(x), round(value.sqrt()), "sqrt {x}");
+            if value > 0.0 {
+                assert!(steps(FloatingPoint::ln(x), value.ln()) <= 1, "ln {x}");
+            }
+        }
+        assert_eq!(FloatingPoint::floor(x), round(value.floor()));
+        assert_eq!(FloatingPoint::recip(x), round(1.0 / value));
+        assert!(steps(FloatingPoint::sin(x), value.sin()) <= 1, "sin {x}");
+        assert!(steps(FloatingPoint::exp(x), value.exp()) <= 1, "exp {x}");
+        assert!(steps(FloatingPoint::atan(x), value.atan()) <= 1, "atan {x}");
+    }
+    for x in finite_bf16().step_by(3) {
+        let value = x.to_f64();
+        if value >= 0.0 {
+            assert_eq!(FloatingPoint::sqrt(x), <bf16 as FromPrimitive>::from_f64(value.sqrt()), "sqrt {x}");
+        }
+    }
+    assert!(FloatingPoint::sqrt(f16::from_f32(-1.0)).is_nan());
+    assert_eq!(FloatingPoint::powi(f16::from_f32(2.0), 16), f16::INFINITY);
+    assert_eq!(FloatingPoint::powi(f16::from_f32(2.0), -24), f16::from_bits(1));
+}
+
+#[test]
+fn constants() {
+    assert_eq!(<f16 as FloatConsts>::PI, f16::PI);
+    assert_eq!(<f16 as FloatConsts>::E, f16::E);
+    assert_eq!(<f16 as FloatConsts>::LN_2, f16::LN_2);
+    assert_eq!(<bf16 as FloatConsts>::SQRT_2, bf16::SQRT_2);
+    assert_eq!(<bf16 as FloatConsts>::FRAC_1_PI, bf16::FRAC_1_PI);
+    assert_eq!(<f16 as FloatConsts>::TAU, <f16 as FromPrimitive>::from_f64(core::f64::consts::TAU));
+    assert_eq!(<bf16 as FloatConsts>::EPSILON, bf16::EPSILON);
+}
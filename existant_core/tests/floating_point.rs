@@ -0,0 +1,96 @@
+//! The parts of `FloatingPoint` std has nothing to compare against, and the
+//! types other than f32 and f64.
+
+use existant_core::{Bounds, FloatBits, FloatingPoint, FromPrimitive, Interval, Q16_16};
+
+#[test]
+fn exponents() {
+    assert_eq!(FloatingPoint::ldexp(0.75f64, 4), 12.0);
+    assert_eq!(FloatingPoint::ldexp(1.0f64, -1074), f64::from_bits(1));
+    assert_eq!(FloatingPoint::ldexp(1.0f64, 1024), f64::INFINITY);
+    assert_eq!(FloatingPoint::frexp(12.0f64), (0.75, 4));
+    assert_eq!(FloatingPoint::frexp(-f64::from_bits(1)), (-0.5, -1073));
+    assert_eq!(FloatingPoint::frexp(0.0f32), (0.0, 0));
+    for x in [1e-300, 0.1, 1.0, 3.0, 1e300] {
+        let (mantissa, exponent) = FloatingPoint::frexp(x);
+        assert!((0.5..1.0).contains(&mantissa));
+        assert_eq!(FloatingPoint::ldexp(mantissa, exponent), x);
+    }
+
+    let x = Q16_16::from_f32(3.25);
+    assert_eq!(x.ldexp(3), Q16_16::from_f32(26.0));
+    assert_eq!(x.ldexp(-2), Q16_16::from_f32(0.8125));
+    assert_eq!(x.ldexp(20), Q16_16::MAX);
+    assert_eq!(x.frexp(), (Q16_16::from_f32(0.8125), 2));
+}
+
+#[test]
+fn arithmetic() {
+    // 0.1 isn't exact, and a fused multiply-add shows by how much
+    assert_eq!(0.1f64.mul_add(10.0, -1.0), 2.0f64.powi(-54));
+    assert_eq!(0.1f64 * 10.0 - 1.0, 0.0);
+    assert_eq!(FloatingPoint::abs_sub(5.0f64, 3.0), 2.0);
+    assert_eq!(FloatingPoint::abs_sub(3.0f64, 5.0), 0.0);
+    assert!(FloatingPoint::abs_sub(f64::NAN, 5.0).is_nan());
+    assert!(FloatingPoint::clamp(f64::NAN, 0.0, 1.0).is_nan());
+    assert_eq!(FloatingPoint::clamp(-2.0f32, -1.0, 1.0), -1.0);
+    assert_eq!(FloatingPoint::min_num(f64::NAN, 1.0), 1.0);
+    assert_eq!(FloatingPoint::max_num(1.0, f64::NAN), 1.0);
+
+    // 200² saturates on its own, but not once the sum is taken
+    let (a, b) = (Q16_16::from_f32(200.0), Q16_16::from_f32(-32_000.0));
+    assert_eq!(a.mul_add(a, b), Q16_16::from_f32(8_000.0));
+    assert_eq!(a * a + b, Q16_16::MAX + b);
+}
+
+#[test]
+#[should_panic]
+fn clamp_with_nan_bounds() {
+    FloatingPoint::clamp(0.5f64, f64::NAN, 1.0);
+}
+
+#[test]
+fn ordering() {
+    let mut values = [1.0, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN, -1.0];
+    values.sort_by(FloatingPoint::total_cmp);
+    let bits = values.map(f64::to_bits);
+    let expected = [-f64::NAN, f64::NEG_INFINITY, -1.0, -0.0, 0.0, 1.0, f64::NAN].map(f64::to_bits);
+    assert_eq!(bits, expected);
+
+    let x = Interval::<f64>::new(1.0, 2.0);
+    assert_eq!(x.next_up(), Interval::<f64>::new(1.0f64.next_up(), 2.0f64.next_up()));
+    assert_eq!(Interval::<f64>::from_bits(x.to_bits()), x);
+
+    let y = Q16_16::from_f32(-1.5);
+    assert_eq!(FloatingPoint::next_up(y), Q16_16::from_bits(y.to_bits() + 1));
+    assert_eq!(FloatingPoint::next_down(Q16_16::MIN), Q16_16::MIN);
+    assert!(FloatingPoint::total_cmp(&y, &Q16_16::ONE).is_lt());
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn half_precision() {
+    use half::{bf16, f16};
+
+    assert_eq!(FloatingPoint::next_up(f16::ZERO), f16::from_bits(1));
+    assert_eq!(FloatingPoint::next_down(f16::ZERO), f16::from_bits(0x8001));
+    assert_eq!(FloatingPoint::next_up(f16::NEG_INFINITY), f16::MIN);
+    assert_eq!(FloatingPoint::next_up(f16::MAX), f16::INFINITY);
+    assert_eq!(FloatingPoint::next_down(f16::ONE), f16::from_f32(1.0 - 2.0f32.powi(-11)));
+    assert_eq!(FloatingPoint::next_up(bf16::ONE), bf16::from_f32(1.0 + 2.0f32.powi(-7)));
+    assert!(FloatingPoint::next_up(f16::NAN).is_nan());
+
+    assert_eq!(FloatingPoint::ldexp(f16::ONE, -24), f16::from_bits(1));
+    assert_eq!(FloatingPoint::frexp(f16::from_f32(12.0)), (f16::from_f32(0.75), 4));
+    assert_eq!(FloatingPoint::min_num(f16::NAN, f16::ONE), f16::ONE);
+    assert_eq!(FloatBits::to_bits(bf16::ONE), 0x3F80);
+
+    // 1.5 * 1.359375 is halfway between two bf16s, so the tiny addend decides
+    // which way it goes even though an f64 sum would drop it
+    let (a, b, tiny) = (bf16::from_f32(1.5), bf16::from_f32(1.359375), bf16::from_f32(2.0f32.powi(-100)));
+    assert_eq!(FloatingPoint::mul_add(a, b, tiny), bf16::from_f32(2.046875));
+    assert_eq!(FloatingPoint::mul_add(a, b, -tiny), bf16::from_f32(2.03125));
+    let tenth = f16::from_f32(0.1);
+    assert_eq!(FloatingPoint::mul_add(tenth, f16::from_f32(10.0), -f16::ONE), f16::from_f32(-2.0f32.powi(-12)));
+    assert_eq!(tenth * f16::from_f32(10.0) - f16::ONE, f16::ZERO);
+}
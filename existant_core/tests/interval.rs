@@ -0,0 +1,158 @@
+//! `Interval` against f64 and exact answers, checking each result encloses
+//! the true value.
+
+use existant_core::{FloatingPoint, Interval, Signed};
+
+/// f32 bounds, whose sums and products are exact in f64, so an f32 interval
+/// can be checked against the f64 answer at every corner.
+const BOUNDS: [f32; 10] = [-1000.5, -3.0, -1.0, -0.1, 0.0, 1e-3, 0.3, 1.0, 7.25, 65_536.1];
+
+fn intervals() -> Vec<Interval<f32>> {
+    BOUNDS.iter().flat_map(|&lo| BOUNDS.iter().filter(move |&&hi| hi >= lo).map(move |&hi| Interval::<f32>::new(lo, hi))).collect()
+}
+
+/// A few points spread over `x`, the bounds included.
+fn points(x: Interval<f32>) -> [f64; 5] {
+    let (lo, hi) = (x.lo() as f64, x.hi() as f64);
+    [lo, hi, (lo + hi) / 2.0, lo + (hi - lo) / 3.0, hi - (hi - lo) / 7.0]
+}
+
+fn encloses(x: Interval<f32>, value: f64) -> bool {
+    value.is_nan() || (x.lo() as f64 <= value && value <= x.hi() as f64)
+}
+
+#[test]
+fn arithmetic() {
+    for x in intervals() {
+        for y in intervals() {
+            for a in points(x) {
+                for b in points(y) {
+                    // Rounding a and b to f32 keeps them in the interval.
+                    let (a, b) = (a as f32 as f64, b as f32 as f64);
+                    assert!(encloses(x + y, a + b), "{x} + {y}");
+                    assert!(encloses(x - y, a - b), "{x} - {y}");
+                    assert!(encloses(x * y, a * b), "{x} * {y}");
+                    assert!(encloses(x / y, a / b), "{x} / {y}");
+                }
+            }
+        }
+    }
+    // Sums that fit are as tight as they can be.
+    assert_eq!(Interval::<f64>::point(1.0) + Interval::<f64>::point(2.0), Interval::<f64>::point(3.0));
+    // 0.1 + 0.2 rounds up to 0.30000000000000004, so the low bound steps
+    // back down to 0.3, which is below the exact sum.
+    assert_eq!(Interval::<f64>::point(0.1) + Interval::<f64>::point(0.2), Interval::<f64>::new(0.3, 0.1 + 0.2));
+}
+
+#[test]
+fn powers() {
+    // Past 2^24 an f32 exponent would have rounded to an even one.
+    let odd = 16777217;
+    let power = Interval::<f32>::point(-1.0).powi(odd);
+    assert!(power.lo() <= -1.0 && -1.0 <= power.hi(), "{power}");
+    let power = Interval::<f32>::new(-1.0000001, -1.0).powi(odd);
+    assert!(power.lo() <= power.hi() && power.hi() >= -1.0 && power.hi() < 0.0, "{power}");
+    assert!(power.lo() <= (-1.0000001f32 as f64).powi(odd) as f32);
+
+    let power = Interval::<f64>::new(-2.0, 3.0).powi(3);
+    assert!(power.lo() <= -8.0 && power.hi() >= 27.0 && power.hi() < 27.1);
+    let power = Interval::<f64>::new(-2.0, 3.0).powi(2);
+    assert!(power.lo() == 0.0 && power.hi() >= 9.0);
+    let power = Interval::<f64>::new(2.0, 4.0).powi(-2);
+    assert!(power.lo() <= 0.0625 && power.hi() >= 0.25);
+    assert_eq!(Interval::<f32>::new(-3.0, 5.0).powi(0), Interval::<f32>::point(1.0));
+    let power = Interval::<f32>::new(-1.0, 1.0).powi(i32::MIN);
+    assert!(power.hi() == f32::INFINITY);
+
+    // Whole exponents bigger than an i32 keep their parity too.
+    let huge = Interval::<f64>::point(4294967297.0);
+    let power = Interval::<f64>::point(-1.0).powf(huge);
+    assert!(power.lo() <= -1.0 && -1.0 <= power.hi(), "{power}");
+    let power = Interval::<f32>::point(-1.0).powf(Interval::<f32>::point(2147483648.0));
+    assert!(power.lo() <= 1.0 && 1.0 <= power.hi(), "{power}");
+    let power = Interval::<f64>::point(-2.0).powf(Interval::<f64>::point(3.0));
+    assert!(power.lo() <= -8.0 && -8.0 <= power.hi());
+    assert!(Interval::<f64>::point(-2.0).powf(Interval::<f64>::point(0.5)).is_nan());
+    let power = Interval::<f64>::new(1.0, 4.0).powf(Interval::<f64>::point(0.5));
+    assert!(power.lo() <= 1.0 && power.hi() >= 2.0);
+}
+
+#[test]
+fn functions() {
+    for x in intervals() {
+        for a in points(x) {
+            let a = a as f32 as f64;
+            assert!(encloses(x.sin(), a.sin()), "sin {x}");
+            assert!(encloses(x.cos(), a.cos()), "cos {x}");
+            assert!(encloses(x.exp(), a.exp()), "exp {x}");
+            assert!(encloses(x.atan(), a.atan()), "atan {x}");
+            assert!(encloses(x.abs(), a.abs()), "abs {x}");
+            assert!(encloses(x.powi(3), a.powi(3)), "powi {x}");
+            if a >= 0.0 {
+                assert!(encloses(x.sqrt(), a.sqrt()), "sqrt {x}");
+                assert!(encloses(x.ln(), a.ln()), "ln {x}");
+            }
+            if x.lo() > 0.0 {
+                assert!(encloses(x.powf(Interval::<f32>::new(0.5, 1.5)), a.powf(0.5)), "powf {x}");
+            }
+        }
+    }
+}
+
+#[test]
+fn euclidean() {
+    for x in intervals() {
+        for y in intervals().into_iter().filter(|y| !y.contains(0.0)) {
+            for a in points(x) {
+                for b in points(y) {
+                    let (a, b) = (a as f32 as f64, b as f32 as f64);
+                    assert!(encloses(x.div_euclid(y), a.div_euclid(b)), "{x} div_euclid {y}");
+                    assert!(encloses(x.rem_euclid(y), a.rem_euclid(b)), "{x} rem_euclid {y}");
+                    assert!(encloses(x.abs_sub(y), FloatingPoint::abs_sub(a, b)), "{x} abs_sub {y}");
+                }
+            }
+        }
+    }
+    type I = Interval<f64>;
+    let three = I::point(3.0);
+    assert_eq!(I::new(-1.0, 1.0).div_euclid(three), I::new(-1.0, 0.0));
+    assert_eq!(I::new(-1.0, 1.0).rem_euclid(three), I::new(0.0, 3.0));
+    // One period narrows it down to about 4 - 3 and 5 - 3.
+    let remainder = I::new(4.0, 5.0).rem_euclid(-three);
+    assert!(remainder.contains(1.0) && remainder.contains(2.0) && remainder.width() < 1.1);
+    assert_eq!(I::new(0.0, 5.0).clamp(I::ONE, I::point(2.0)), I::new(1.0, 2.0));
+    assert_eq!(I::new(-1.0, 1.5).clamp(I::ZERO, I::new(1.0, 2.0)), I::new(0.0, 1.5));
+    assert_eq!(I::new(1.0, 4.0).abs_sub(I::point(2.0)), I::new(0.0, 2.0));
+    assert!(I::NAN.clamp(I::ZERO, I::ONE).is_nan());
+}
+
+#[test]
+fn edges() {
+    type I = Interval<f64>;
+    assert_eq!(I::point(1.0) / I::new(-1.0, 1.0), I::ENTIRE);
+    assert_eq!(I::point(1.0) / I::new(0.0, 1.0), I::ENTIRE);
+    assert_eq!(I::ZERO * I::ENTIRE, I::ZERO);
+    let sum = I::point(f64::MAX) + I::point(f64::MAX);
+    assert_eq!((sum.lo(), sum.hi()), (f64::MAX, f64::INFINITY));
+    assert!(I::new(-4.0, -1.0).sqrt().is_nan());
+    assert_eq!(I::new(-4.0, 4.0).sqrt().lo(), 0.0);
+    assert!(I::new(-4.0, 4.0).sqrt().hi() >= 2.0);
+    assert_eq!(I::new(0.0, 1.0).ln().lo(), f64::NEG_INFINITY);
+    assert_eq!(I::new(-10.0, 10.0).sin(), I::new(-1.0, 1.0));
+    assert_eq!(I::new(-0.1, 0.1).cos().hi(), 1.0);
+    assert_eq!(I::new(0.0, 1000.0).exp().hi(), f64::INFINITY);
+    assert_eq!(I::new(1.0, 2.0).tan(), I::ENTIRE);
+    assert!(I::new(1.0, 2.0).certainly_lt(&I::new(2.5, 3.0)));
+    assert!(!I::new(1.0, 2.0).certainly_lt(&I::new(2.0, 3.0)));
+    assert_eq!(I::new(1.0, 2.0).partial_cmp(&I::new(2.0, 3.0)), None);
+    assert!(I::new(1.0, 2.0).possibly_eq(&I::new(2.0, 3.0)));
+    assert_eq!(I::new(1.0, 2.0).partial_cmp(&I::new(1.0, 2.0)), None);
+    assert_eq!(I::point(2.0).partial_cmp(&I::point(2.0)), Some(core::cmp::Ordering::Equal));
+    // The same number twice gives a wider answer, since an interval doesn't
+    // know both sides are the same value.
+    let x = I::new(1.0, 2.0);
+    assert_eq!(x - x, I::new(-1.0, 1.0));
+    assert!(I::point(1.0 / 3.0).contains(1.0 / 3.0));
+    let third = I::ONE / I::point(3.0);
+    assert!(third.lo() < third.hi() && third.contains(1.0 / 3.0));
+}
@@ -0,0 +1,124 @@
+//! Runs the law checker over the types in this crate, and over a type that
+//! claims a law it doesn't have to make sure that gets caught.
+
+use core::{fmt::Debug, ops::Add};
+
+use existant_core::{laws::{self, Arbitrary, Law, LawEq}, Absorption, Addition, And, BooleanAlgebra, Checked, ClosedUnder, CommutativeOver, Max, MaxPlus, Min, MinPlus, Multiplication, Or, Saturating, Wrapping, Xor};
+use rand::{rngs::SmallRng, Rng, RngExt, SeedableRng};
+
+const SAMPLES: usize = 10_000;
+
+fn rng() -> SmallRng {
+    SmallRng::seed_from_u64(0x1A55)
+}
+
+macro_rules! commutative_rings {
+    ($($name:ident: $structure:ty),*) => {
+        $(
+            #[test]
+            fn $name() {
+                laws::check_commutative_ring::<$structure, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+                laws::check_absorption::<$structure, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+            }
+        )*
+    };
+}
+
+commutative_rings!(
+    i8_ring: i8, i32_ring: i32, i128_ring: i128, isize_ring: isize,
+    wrapping_u8_ring: Wrapping<u8>, wrapping_i32_ring: Wrapping<i32>, wrapping_u64_ring: Wrapping<u64>, wrapping_i128_ring: Wrapping<i128>
+);
+
+#[test]
+fn unsigned_semirings() {
+    laws::check_semiring::<u16, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<usize, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<Saturating<u8>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<Saturating<u64>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn float_fields() {
+    laws::check_field::<f32, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_field::<f64, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn checked_addition() {
+    laws::check_commutative_monoid::<Checked<u32>, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_commutativity::<Checked<i32>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_identity::<Checked<i32>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn lattices() {
+    laws::check_commutative_monoid::<i32, Min, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_commutative_monoid::<u8, Max, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_absorption::<f64, Min, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_distributivity::<i64, Min, Max, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_distributivity::<f32, Max, Min, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn tropical_semirings() {
+    laws::check_semiring::<f64, Addition, Min, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<MinPlus<u32>, Addition, Min, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<MinPlus<i64>, Addition, Min, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<MinPlus<f64>, Addition, Min, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<MaxPlus<i32>, Addition, Max, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<MaxPlus<f32>, Addition, Max, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_absorption::<MinPlus<u32>, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_absorption::<MaxPlus<i8>, Addition, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+fn boolean_algebra<T: BooleanAlgebra + Absorption<And> + Absorption<Or> + Arbitrary + LawEq + Debug>() {
+    laws::check_commutative_ring::<T, And, Xor, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<T, And, Or, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_commutativity::<T, Or, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_distributivity::<T, Or, And, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_absorption::<T, And, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_absorption::<T, Or, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn boolean_algebras() {
+    boolean_algebra::<bool>();
+    boolean_algebra::<u8>();
+    boolean_algebra::<u64>();
+    boolean_algebra::<usize>();
+    laws::check_field::<bool, And, Xor, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+/// Adds by subtracting, while claiming to be commutative.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Lying(i32);
+
+impl Add for Lying {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+impl ClosedUnder<Addition> for Lying {}
+impl CommutativeOver<Addition> for Lying {}
+impl Arbitrary for Lying {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self(rng.random_range(-100..=100))
+    }
+}
+impl LawEq for Lying {
+    fn law_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+#[test]
+fn catches_a_false_claim() {
+    let counterexample = laws::check_commutativity::<Lying, Addition, _>(&mut rng(), SAMPLES).unwrap_err();
+    assert_eq!(counterexample.law, Law::Commutativity);
+    assert_eq!(counterexample.operator, "Addition");
+    let (a, b) = (counterexample.a, counterexample.b.unwrap());
+    assert_eq!((counterexample.left, counterexample.right), (a + b, b + a));
+    assert!(counterexample.to_string().starts_with("commutativity of Addition failed: a ⨁ b = "));
+}
@@ -0,0 +1,126 @@
+//! `ModInt`, `Gf` and `Gf2n` against `u128` arithmetic and carry-less
+//! multiplication done bit by bit, with moduli right below 2^64 so the
+//! additions overflow `u64`.
+
+use existant_core::{Gf, Gf256, Gf2n, Gf65536, ModInt};
+
+/// The largest prime below 2^64.
+const BIG_PRIME: u64 = u64::MAX - 58;
+
+fn samples(modulus: u64) -> [u64; 8] {
+    [0, 1, 2, 3, modulus / 2, modulus / 2 + 1, modulus - 2, modulus - 1]
+}
+
+#[test]
+fn against_u128() {
+    fn check<const N: u64>() {
+        let m = N as u128;
+        for a in samples(N) {
+            for b in samples(N) {
+                let (x, y) = (ModInt::<N>::new(a), ModInt::<N>::new(b));
+                let (a, b) = (a as u128, b as u128);
+                assert_eq!((x + y).value() as u128, (a + b) % m, "{a} + {b}");
+                assert_eq!((x - y).value() as u128, (a + m - b) % m, "{a} - {b}");
+                assert_eq!((x * y).value() as u128, a * b % m, "{a} * {b}");
+            }
+            assert_eq!((-ModInt::<N>::new(a)).value() as u128, (m - a as u128) % m);
+        }
+    }
+    check::<BIG_PRIME>();
+    check::<{ u64::MAX }>();
+    check::<{ 1 << 63 }>();
+    check::<7>();
+    assert_eq!(ModInt::<{ u64::MAX }>::new(u64::MAX), ModInt::new(0));
+    assert_eq!(ModInt::<10>::from_i64(-3).value(), 7);
+    assert_eq!(ModInt::<{ u64::MAX }>::from_i64(i64::MIN).value(), u64::MAX - (1 << 63));
+    assert_eq!(ModInt::<1>::new(5), ModInt::ONE);
+    assert_eq!(Gf::<BIG_PRIME>::new(3).pow(BIG_PRIME - 1), Gf::ONE);
+}
+
+#[test]
+fn zero_divisors() {
+    type Z12 = ModInt<12>;
+    assert_eq!(Z12::new(3) * Z12::new(4), Z12::ZERO);
+    for a in 0..12 {
+        let inverse = Z12::new(a).checked_inverse();
+        assert_eq!(inverse.is_some(), [1, 5, 7, 11].contains(&a), "{a}");
+        if let Some(inverse) = inverse {
+            assert_eq!(Z12::new(a) * inverse, Z12::ONE);
+        }
+    }
+    assert_eq!(ModInt::<{ 1 << 63 }>::new(2).checked_inverse(), None);
+    assert_eq!(ModInt::<1>::new(0).checked_inverse(), Some(ModInt::ZERO));
+}
+
+#[test]
+fn prime_fields() {
+    fn check<const P: u64>() {
+        for a in samples(P).into_iter().filter(|&a| a % P != 0) {
+            let x = Gf::<P>::new(a);
+            assert_eq!(x * x.inverse(), Gf::ONE, "{a}");
+            assert_eq!(x / x, Gf::ONE);
+            assert_eq!(x.pow(P - 2), x.inverse());
+            assert_eq!(Gf::<P>::ONE / x * x, Gf::ONE);
+        }
+    }
+    check::<2>();
+    check::<65_537>();
+    check::<{ (1 << 61) - 1 }>();
+    check::<BIG_PRIME>();
+    assert_eq!(Gf::<7>::new(3) / Gf::new(5), Gf::new(2));
+    assert_eq!(Gf::<7>::from(ModInt::<7>::new(4)).value(), 4);
+    assert_eq!(Gf::<7>::default(), Gf::ZERO);
+    assert_eq!(ModInt::<12>::default(), ModInt::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn prime_division_by_zero() {
+    let _ = Gf::<7>::new(3) / Gf::ZERO;
+}
+
+/// Carry-less multiplication modulo `poly`, one bit at a time.
+fn slow_product(a: u64, b: u64, poly: u64) -> u64 {
+    let degree = 63 - poly.leading_zeros();
+    let mut product = 0u128;
+    for i in 0..64 {
+        if b >> i & 1 == 1 {
+            product ^= (a as u128) << i;
+        }
+    }
+    for bit in (degree..128).rev() {
+        if product >> bit & 1 == 1 {
+            product ^= (poly as u128) << (bit - degree);
+        }
+    }
+    product as u64
+}
+
+#[test]
+fn binary_fields() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!((Gf256::new(a) * Gf256::new(b)).value(), slow_product(a, b, 0x11B), "{a} * {b}");
+        }
+        if a != 0 {
+            assert_eq!(Gf256::new(a) * Gf256::new(a).inverse(), Gf256::ONE);
+        }
+    }
+    // the examples from FIPS 197
+    assert_eq!(Gf256::new(0x57) * Gf256::new(0x83), Gf256::new(0xC1));
+    assert_eq!(Gf256::new(0x53).inverse(), Gf256::new(0xCA));
+    assert_eq!(Gf256::new(0x57) + Gf256::new(0x83), Gf256::new(0xD4));
+    assert_eq!(Gf256::new(0x1FF), Gf256::new(0xE4));
+    assert_eq!(Gf256::new(0x02).pow(255), Gf256::ONE);
+    assert_eq!(Gf256::ZERO.checked_inverse(), None);
+    for a in [1, 2, 0x1234, 0xFFFF] {
+        let x = Gf65536::new(a);
+        assert_eq!(x * x.inverse(), Gf65536::ONE);
+        assert_eq!((x * Gf65536::new(0xBEEF)).value(), slow_product(a, 0xBEEF, 0x1100B));
+    }
+    // x^63 + x^4 + x^3 + x + 1, the widest degree there's room for
+    type Wide = Gf2n<{ 1 << 63 | 0x1B }>;
+    let x = Wide::new(u64::MAX >> 1);
+    assert_eq!((x * x).value(), slow_product(u64::MAX >> 1, u64::MAX >> 1, 1 << 63 | 0x1B));
+    assert_eq!(-x, x);
+}
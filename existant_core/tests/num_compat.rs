@@ -0,0 +1,151 @@
+//! The adapters to and from `num_traits`, by running code written against
+//! one side on numbers from the other.
+
+use core::cmp::Ordering;
+
+use existant_core::{assert_approx_eq, Addition, Bounds, Existant, FloatBits, FloatConsts, FloatExtras, FloatingPoint, Fixed, Identity, Interval, NumTraits, ParseNumberError, Rational};
+use num_traits::{Float, FloatConst, Num, NumCast, ToPrimitive};
+
+/// Written against `num_traits` only, like most of the ecosystem.
+fn circle_area<F: Float + FloatConst>(radius: F) -> F {
+    F::PI() * radius.powi(2)
+}
+
+/// Written against the traits here only.
+fn length<T: FloatingPoint>(x: T, y: T) -> T {
+    (x * x + y * y).sqrt()
+}
+
+#[test]
+fn existant_floats() {
+    assert_approx_eq!(circle_area(Existant(2.0f64)).0, 4.0 * core::f64::consts::PI);
+    let interval = circle_area(Existant(Interval::<f64>::new(1.0, 2.0))).0;
+    assert!(interval.lo() <= core::f64::consts::PI && interval.hi() >= 4.0 * core::f64::consts::PI);
+    let fixed = circle_area(Existant(Fixed::<i32, 16>::from_bits(1 << 16))).0;
+    assert!((fixed.to_bits() as f64 / 65536.0 - core::f64::consts::PI).abs() < 1e-4);
+
+    assert_eq!(Existant(1.0f64).integer_decode(), 1.0f64.integer_decode());
+    assert_eq!(Existant(-0.375f32).integer_decode(), (-0.375f32).integer_decode());
+    assert_eq!(Existant::<f64>::min_positive_value().0, f64::MIN_POSITIVE);
+    assert_eq!(Existant::<f32>::min_positive_value().0, f32::MIN_POSITIVE);
+    assert!(Existant::<f64>::nan().is_nan());
+    assert_eq!(Existant(-3.0f64).classify(), core::num::FpCategory::Normal);
+    assert_approx_eq!(Existant(0.5f64).cosh().0, 0.5f64.cosh());
+    assert_approx_eq!(Existant(-710.0f64).cosh().0, 710.0f64.cosh());
+    assert!(Existant::<Interval<f64>>::nan().0.is_nan());
+}
+
+#[test]
+fn parsing() {
+    type Q = Existant<Rational<i64>>;
+    assert_eq!(Q::from_str_radix("-1.25", 10), Ok(Existant(Rational::new(-5, 4))));
+    assert_eq!(Q::from_str_radix("+.1", 2), Ok(Existant(Rational::new(1, 2))));
+    assert_eq!(Existant::<f64>::from_str_radix("ff.8", 16), Ok(Existant(255.5)));
+    assert_eq!(Existant::<i8>::from_str_radix("-128", 10), Ok(Existant(-128)));
+    assert_eq!(Existant::<i8>::from_str_radix("128", 10), Err(ParseNumberError::Overflow));
+    assert_eq!(Existant::<u8>::from_str_radix("-1", 10), Err(ParseNumberError::Overflow));
+    assert_eq!(Existant::<i32>::from_str_radix("1.5", 10), Err(ParseNumberError::InvalidDigit));
+    assert_eq!(Existant::<i32>::from_str_radix("12z", 10), Err(ParseNumberError::InvalidDigit));
+    assert_eq!(Existant::<i32>::from_str_radix("-", 10), Err(ParseNumberError::Empty));
+}
+
+#[test]
+fn casts() {
+    assert_eq!(<Existant<u8> as NumCast>::from(300), None);
+    assert_eq!(<Existant<u8> as NumCast>::from(200.7), Some(Existant(200)));
+    assert_eq!(<Existant<i64> as NumCast>::from(u64::MAX), None);
+    assert_eq!(<Existant<f32> as NumCast>::from(f64::INFINITY), Some(Existant(f32::INFINITY)));
+    assert_eq!(Existant(1.5f64).to_i32(), Some(1));
+    assert_eq!(Existant(-0.5f64).to_u8(), Some(0));
+    assert_eq!(Existant(256.0f64).to_u8(), None);
+    assert_eq!(Existant(f64::NAN).to_i64(), None);
+    assert_eq!(Existant(i64::MAX).to_i64(), Some(i64::MAX));
+    assert_eq!(Existant(Rational::new(7i32, 2)).to_u32(), Some(3));
+}
+
+#[test]
+fn cast_edges() {
+    use num_traits::FromPrimitive;
+    assert_eq!(Existant::<i64>::from_u64(1 << 63), None);
+    assert_eq!(Existant::<i64>::from_u64(i64::MAX as u64), Some(Existant(i64::MAX)));
+    assert_eq!(Existant::<u64>::from_u128(1 << 64), None);
+    assert_eq!(Existant::<u64>::from_u128(u64::MAX as u128), Some(Existant(u64::MAX)));
+    assert_eq!(Existant::<i128>::from_u128(1 << 127), None);
+    assert_eq!(Existant::<u128>::from_i128(-1), None);
+    assert_eq!(Existant::<i64>::from_f64(9223372036854775808.0), None);
+    assert_eq!(Existant::<i64>::from_f64(-9223372036854775808.0), Some(Existant(i64::MIN)));
+    assert_eq!(Existant::<u128>::from_f64(340282366920938463463374607431768211456.0), None);
+    assert_eq!(Existant::<f32>::from_u128(u128::MAX), None);
+    assert_eq!(Existant::<f32>::from_f64(1e39), None);
+    assert_eq!(<Existant<u64> as NumCast>::from(1u128 << 64), None);
+    assert_eq!(<Existant<u128> as NumCast>::from(u128::MAX), Some(Existant(u128::MAX)));
+    assert_eq!(<Existant<u8> as NumCast>::from(-0.5), Some(Existant(0)));
+
+    assert_eq!(Existant(u64::MAX).to_i64(), None);
+    assert_eq!(Existant(1u64 << 63).to_i64(), None);
+    assert_eq!(Existant(-1i64).to_u64(), None);
+    assert_eq!(Existant(i64::MIN).to_i64(), Some(i64::MIN));
+    assert_eq!(Existant(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!(Existant(u128::MAX).to_i128(), None);
+    assert_eq!(Existant(i128::MIN).to_i64(), None);
+    assert_eq!(Existant(9223372036854775808.0f64).to_i64(), None);
+    assert_eq!(Existant(-9223372036854775808.0f64).to_i64(), Some(i64::MIN));
+    assert_eq!(Existant(18446744073709551616.0f64).to_u64(), None);
+    assert_eq!(Existant(f32::MAX).to_u128(), Some(f32::MAX as u128));
+    assert_eq!(Existant(Rational::new(-257i64, 2)).to_i8(), Some(-128));
+    assert_eq!(Existant(Rational::new(-259i64, 2)).to_i8(), None);
+}
+
+#[test]
+fn num_traits_floats() {
+    let x = NumTraits::<f64>::new(3.0);
+    assert_eq!(length(x, NumTraits::new(4.0)).value, 5.0);
+    assert_eq!(<NumTraits<f64> as Identity<Addition>>::IDENTITY.value, 0.0);
+    assert_eq!(<NumTraits<f32> as FloatConsts>::PI.value, core::f32::consts::PI);
+    assert_eq!(<NumTraits<f64> as Bounds>::MAX.value, f64::MAX);
+    assert_approx_eq!(NumTraits::<f64>::new(0.1) + NumTraits::new(0.2), NumTraits::new(0.3));
+    assert_eq!(x.next_up().value, 3.0f64.next_up());
+}
+
+/// What a crate with a float that only implements `num_traits` would write,
+/// here for `Existant<f32>`.
+struct Extras;
+
+macro_rules! extras_consts {
+    ($($name:ident),*) => {
+        $(
+            const $name: Existant<f32> = Existant(<f32 as FloatConsts>::$name);
+        )*
+    };
+}
+
+impl FloatExtras<Existant<f32>> for Extras {
+    const MIN: Existant<f32> = Existant(f32::MIN);
+    const MAX: Existant<f32> = Existant(f32::MAX);
+    extras_consts!(PI, TAU, PHI, EGAMMA, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, FRAC_1_PI, FRAC_1_SQRT_PI, FRAC_2_PI, FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2, SQRT_3, FRAC_1_SQRT_3, E, LOG2_E, LOG2_10, LOG10_E, LOG10_2, LN_2, LN_10, EPSILON, INFINITY);
+    type Bits = u32;
+    fn to_bits(value: Existant<f32>) -> u32 {
+        value.0.to_bits()
+    }
+    fn from_bits(bits: u32) -> Existant<f32> {
+        Existant(f32::from_bits(bits))
+    }
+    fn total_cmp(a: &Existant<f32>, b: &Existant<f32>) -> Ordering {
+        a.0.total_cmp(&b.0)
+    }
+    fn next_up(value: Existant<f32>) -> Existant<f32> {
+        Existant(value.0.next_up())
+    }
+    fn next_down(value: Existant<f32>) -> Existant<f32> {
+        Existant(value.0.next_down())
+    }
+}
+
+#[test]
+fn round_trip() {
+    type There = NumTraits<Existant<f32>, Extras>;
+    let hypotenuse = length(There::new(Existant(5.0)), There::new(Existant(12.0)));
+    assert_eq!(hypotenuse.value, Existant(13.0));
+    assert_approx_eq!(<There as FloatConsts>::FRAC_PI_2.sin(), There::new(Existant(1.0)));
+    assert_eq!(There::new(Existant(1.0)).to_bits(), 1.0f32.to_bits());
+}
@@ -0,0 +1,129 @@
+//! `number_theory` against brute force on the small types, and against
+//! known primes and pseudoprimes on the wide ones.
+
+use existant_core::{number_theory::*, BigInt, BigUint, Wrapping, U256};
+
+/// Every prime below `n`, by sieving.
+fn sieve(n: usize) -> Vec<bool> {
+    let mut prime = vec![true; n];
+    prime[0] = false;
+    prime[1] = false;
+    for p in 2..n {
+        if prime[p] {
+            for multiple in (p * p..n).step_by(p) {
+                prime[multiple] = false;
+            }
+        }
+    }
+    prime
+}
+
+#[test]
+fn euclid() {
+    for a in -60i16..60 {
+        for b in -60i16..60 {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g, "{a}, {b}");
+            assert!(g >= 0);
+        }
+    }
+    assert_eq!(lcm(4u8, 6), 12);
+    let big = BigInt::<2>::from_i128;
+    assert_eq!(extended_gcd(big(240), big(46)), (big(2), big(-9), big(47)));
+}
+
+#[test]
+fn modular() {
+    for m in 1u8..=255 {
+        for a in 0u8..=255 {
+            assert_eq!(mod_mul(a, 255 - a, m), (a as u32 * (255 - a) as u32 % m as u32) as u8);
+            assert_eq!(mod_pow(a, 5, m), ((a as u64).pow(5) % m as u64) as u8);
+            match mod_inverse(a, m) {
+                Some(inverse) => assert_eq!(a as u32 * inverse as u32 % m as u32, 1 % m as u32),
+                None => assert_ne!(gcd(a, m), 1),
+            }
+        }
+    }
+    assert_eq!(modulo(-7i32, 3), 2);
+    assert_eq!(mod_inverse(-3i64, 7), Some(2));
+    // products that overflow u64 and u128
+    assert_eq!(mod_mul(u64::MAX - 1, u64::MAX - 2, u64::MAX), 2);
+    assert_eq!(mod_mul(u128::MAX - 1, u128::MAX - 2, u128::MAX), 2);
+    assert_eq!(mod_mul(i128::MAX - 1, i128::MAX - 2, i128::MAX), 2);
+    assert_eq!(mod_mul(-1i8, -1, 127), 1);
+    assert_eq!(mod_pow(3u128, u128::MAX, u128::MAX - 158), mod_pow(3u128, u128::MAX % (u128::MAX - 159), u128::MAX - 158));
+    assert_eq!(mod_pow(Wrapping(2u64), Wrapping(64), Wrapping(u64::MAX)), Wrapping(1));
+}
+
+#[test]
+fn primes() {
+    let prime = sieve(200_000);
+    for n in 0..200_000u32 {
+        assert_eq!(is_prime(n), prime[n as usize], "{n}");
+    }
+    for n in i8::MIN..=i8::MAX {
+        assert_eq!(is_prime(n), n >= 0 && prime[n as usize], "{n}");
+    }
+    // Mersenne primes, a Carmichael number, and strong pseudoprimes to every
+    // base up to 23 and up to 37, which 41 catches
+    assert!(is_prime((1u64 << 61) - 1));
+    assert!(is_prime(u64::MAX - 58));
+    assert!(!is_prime(561u32));
+    assert!(!is_prime(3_825_123_056_546_413_051u64));
+    assert!(!is_prime(318_665_857_834_031_151_167_461u128));
+    assert!(is_prime((1u128 << 89) - 1));
+    assert!(is_prime((1u128 << 127) - 1));
+    assert!(!is_prime(((1u128 << 61) - 1) * ((1u128 << 31) - 1)));
+    assert!(is_prime(U256::from_u128((1u128 << 127) - 1)));
+    assert!(!is_prime(U256::from_u128((1u128 << 127) - 1) * U256::from_u128((1u128 << 61) - 1)));
+}
+
+#[test]
+fn roots() {
+    for n in 0..=u16::MAX {
+        let root = isqrt(n) as u32;
+        assert!(root * root <= n as u32 && (root + 1) * (root + 1) > n as u32, "{n}");
+        let root = icbrt(n) as u64;
+        assert!(root.pow(3) <= n as u64 && (root + 1).pow(3) > n as u64, "{n}");
+    }
+    for n in i16::MIN..=i16::MAX {
+        let root = icbrt(n) as i64;
+        assert!(root.pow(3).abs() <= (n as i64).abs() && (root.abs() + 1).pow(3) > (n as i64).abs(), "{n}");
+        assert_eq!(root.signum(), (n as i64).signum());
+    }
+    assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    assert_eq!(icbrt(u64::MAX), 2_642_245);
+    assert_eq!(icbrt(i64::MIN), -2_097_152);
+    assert_eq!(icbrt(i8::MIN), -5);
+    assert_eq!(isqrt(BigUint::<4>::from_u128(1 << 100)), BigUint::from_u128(1 << 50));
+}
+
+fn prime_factors<T: existant_core::Integer>(n: T) -> Vec<T> {
+    let mut factors = Vec::new();
+    for_each_prime_factor(n, |p| factors.push(p));
+    factors.sort();
+    factors
+}
+
+#[test]
+fn factors() {
+    for n in 0..5_000u32 {
+        let factors = prime_factors(n);
+        assert!(factors.iter().all(|p| is_prime(*p)));
+        assert_eq!(factors.iter().product::<u32>(), n.max(1) * (n >= 2) as u32 + (n < 2) as u32);
+    }
+    assert_eq!(prime_factors(600_851_475_143u64), [71, 839, 1471, 6857]);
+    assert_eq!(prime_factors(4_294_967_291u64 * 4_294_967_279), [4_294_967_279, 4_294_967_291]);
+    assert_eq!(prime_factors(((1u128 << 61) - 1) * ((1u128 << 31) - 1) * 61), [61, (1 << 31) - 1, (1 << 61) - 1]);
+    assert_eq!(prime_factors(1u8 << 7), [2; 7]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn factorize_counts() {
+    assert_eq!(factorize(360u32), [(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(factorize(1u64), []);
+    assert_eq!(factorize(2u128.pow(64) + 1), [(274_177, 1), (67_280_421_310_721, 1)]);
+}
@@ -0,0 +1,42 @@
+//! The order traits on the primitives, and that they agree with `Min` and
+//! `Max` from the algebra side.
+
+use core::cmp::Ordering;
+
+use existant_core::{BoundedLattice, Lattice, OrderedField, PartialOrderSet};
+
+/// Clamps through the lattice, so it only needs an [`OrderedField`].
+fn clamp<T: OrderedField>(x: T, lo: T, hi: T) -> T {
+    x.join(lo).meet(hi)
+}
+
+#[test]
+fn primitives() {
+    assert_eq!(3i32.meet(-4), -4);
+    assert_eq!(3u8.join(200), 200);
+    assert_eq!(i64::BOTTOM.join(7), 7);
+    assert_eq!(u16::TOP.meet(7), 7);
+    assert_eq!(f64::BOTTOM, f64::NEG_INFINITY);
+    assert_eq!(2.5f32.partial_order(&2.5), Some(Ordering::Equal));
+    assert!(5u32.dominates(&4));
+    assert!(!5u32.dominates(&5));
+
+    assert!(!true.meet(false));
+    assert!(true.join(false));
+    assert!(bool::BOTTOM.leq(&bool::TOP));
+}
+
+#[test]
+fn nan_is_unordered() {
+    assert!(!f64::NAN.leq(&f64::NAN));
+    assert!(!f64::NAN.comparable(&1.0));
+    assert_eq!(1.0f32.partial_order(&f32::NAN), None);
+    // meet and join skip the NaN the same way `min` and `max` do
+    assert_eq!(f64::NAN.meet(1.0), 1.0);
+}
+
+#[test]
+fn ordered_fields() {
+    assert_eq!(clamp(1.5f64, 0.0, 1.0), 1.0);
+    assert_eq!(clamp(-0.25f32, -1.0, 1.0), -0.25);
+}
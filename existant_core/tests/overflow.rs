@@ -0,0 +1,124 @@
+//! `Wrapping`, `Saturating` and `Checked` against the same arithmetic in
+//! std, on every pair of 8 bit integers and the ends of the 64 bit ones.
+
+use existant_core::{Checked, FromPrimitive, IntoPrimitive, Saturating, Signed, Wrapping};
+
+/// Every operation on `$a` and `$b` against `core::num` and the `checked_*`
+/// methods. Division by zero panics in std for the first two, so that's
+/// left to `Checked`.
+macro_rules! check_pair {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        let (std_a, std_b) = (core::num::Wrapping(a), core::num::Wrapping(b));
+        assert_eq!((Wrapping(a) + Wrapping(b)).0, (std_a + std_b).0, "{a} + {b}");
+        assert_eq!((Wrapping(a) - Wrapping(b)).0, (std_a - std_b).0, "{a} - {b}");
+        assert_eq!((Wrapping(a) * Wrapping(b)).0, (std_a * std_b).0, "{a} * {b}");
+        let (std_a, std_b) = (core::num::Saturating(a), core::num::Saturating(b));
+        assert_eq!((Saturating(a) + Saturating(b)).0, (std_a + std_b).0, "{a} + {b}");
+        assert_eq!((Saturating(a) - Saturating(b)).0, (std_a - std_b).0, "{a} - {b}");
+        assert_eq!((Saturating(a) * Saturating(b)).0, (std_a * std_b).0, "{a} * {b}");
+        if b != 0 {
+            assert_eq!((Wrapping(a) / Wrapping(b)).0, a.wrapping_div(b), "{a} / {b}");
+            assert_eq!((Wrapping(a) % Wrapping(b)).0, a.wrapping_rem(b), "{a} % {b}");
+            assert_eq!((Saturating(a) / Saturating(b)).0, (std_a / std_b).0, "{a} / {b}");
+        }
+        let (x, y) = (Checked::new(a), Checked::new(b));
+        assert_eq!((x + y).0, a.checked_add(b), "{a} + {b}");
+        assert_eq!((x - y).0, a.checked_sub(b), "{a} - {b}");
+        assert_eq!((x * y).0, a.checked_mul(b), "{a} * {b}");
+        assert_eq!((x / y).0, a.checked_div(b), "{a} / {b}");
+        assert_eq!((x % y).0, a.checked_rem(b), "{a} % {b}");
+    }};
+}
+
+#[test]
+fn against_std() {
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            check_pair!(a, b);
+        }
+        assert_eq!((-Wrapping(a)).0, a.wrapping_neg());
+        assert_eq!((-Saturating(a)).0, a.saturating_neg());
+        assert_eq!((-Checked::new(a)).0, a.checked_neg());
+        assert_eq!(Checked::new(a).abs().0, a.checked_abs());
+    }
+    for a in u8::MIN..=u8::MAX {
+        for b in u8::MIN..=u8::MAX {
+            check_pair!(a, b);
+        }
+    }
+    let signed = [i64::MIN, i64::MIN + 1, -1, 0, 1, 3_037_000_500, i64::MAX - 1, i64::MAX];
+    for a in signed {
+        for b in signed {
+            check_pair!(a, b);
+        }
+    }
+    let unsigned = [0, 1, 2, 4_294_967_296, u64::MAX / 2, u64::MAX - 1, u64::MAX];
+    for a in unsigned {
+        for b in unsigned {
+            check_pair!(a, b);
+        }
+    }
+}
+
+#[test]
+fn edges() {
+    assert_eq!(Wrapping(i64::MIN) / Wrapping(-1), Wrapping(i64::MIN));
+    assert_eq!(Wrapping(i64::MIN) % Wrapping(-1), Wrapping(0));
+    assert_eq!(-Wrapping(i64::MIN), Wrapping(i64::MIN));
+    assert_eq!(Wrapping(i64::MIN).abs(), Wrapping(i64::MIN));
+    assert_eq!(Saturating(i64::MIN) / Saturating(-1), Saturating(i64::MAX));
+    assert_eq!(-Saturating(i64::MIN), Saturating(i64::MAX));
+    assert_eq!(Saturating(i64::MIN).abs(), Saturating(i64::MAX));
+    assert_eq!(Checked::new(i64::MIN) / Checked::new(-1), Checked(None));
+    assert_eq!(Checked::new(i64::MIN).abs(), Checked(None));
+    assert_eq!(Checked::new(5u32) / Checked::new(0), Checked(None));
+    // Overflow sticks, even when the next step would bring it back in range.
+    let overflowed = Checked::new(u8::MAX) + Checked::new(1);
+    assert!(overflowed.is_overflowed());
+    assert_eq!(overflowed * Checked::new(0), Checked(None));
+    assert_eq!(overflowed.partial_cmp(&Checked::new(0)), None);
+    assert_eq!(overflowed, Checked(None));
+    assert_eq!(format!("{overflowed}"), "overflow");
+}
+
+#[test]
+#[should_panic]
+fn wrapping_division_by_zero() {
+    let _ = Wrapping(1u8) / Wrapping(0);
+}
+
+#[test]
+#[should_panic]
+fn converting_an_overflow() {
+    (Checked::new(i8::MAX) + Checked::new(1)).to_i32();
+}
+
+#[test]
+fn conversions() {
+    // Like `as`, the closest value, and only when it fits.
+    assert_eq!(Wrapping::<u8>::from_i32(300), Wrapping(44));
+    assert_eq!(Saturating::<u8>::from_i32(300), Saturating(255));
+    assert_eq!(Saturating::<u8>::from_i32(-5), Saturating(0));
+    assert_eq!(Checked::<u8>::from_i32(300), Checked(None));
+    assert_eq!(Checked::<u8>::from_i32(255), Checked::new(255));
+    assert_eq!(Wrapping::<i8>::from_u128(u128::MAX), Wrapping(-1));
+    assert_eq!(Saturating::<i64>::from_u128(u128::MAX), Saturating(i64::MAX));
+    assert_eq!(Wrapping(-1i32).to_u32(), u32::MAX);
+    assert_eq!(Saturating(-1i32).to_u32(), 0);
+    assert_eq!(Saturating(i128::MIN).to_i64(), i64::MIN);
+    assert_eq!(Checked::new(-1i32).to_u32(), 0);
+    assert_eq!(Wrapping::<u8>::from_f64(-1.5), Wrapping(0));
+    assert_eq!(Saturating::<i8>::from_f64(1e10), Saturating(i8::MAX));
+
+    // The exact bounds of the floats, where ±1.0 rounds away past 2^53.
+    assert_eq!(Checked::<i64>::from_f64(-9_223_372_036_854_775_808.0), Checked::new(i64::MIN));
+    assert_eq!(Checked::<i64>::from_f64(9_223_372_036_854_775_808.0), Checked(None));
+    assert_eq!(Checked::<i64>::from_f64(9_223_372_036_854_774_784.0), Checked::new(9_223_372_036_854_774_784));
+    assert_eq!(Checked::<u64>::from_f64(18_446_744_073_709_551_616.0), Checked(None));
+    assert_eq!(Checked::<u8>::from_f64(-0.9), Checked::new(0));
+    assert_eq!(Checked::<u8>::from_f64(-1.0), Checked(None));
+    assert_eq!(Checked::<u8>::from_f64(255.9), Checked::new(255));
+    assert_eq!(Checked::<i32>::from_f64(f64::NAN), Checked(None));
+    assert_eq!(Checked::<i32>::from_f32(2_147_483_648.0), Checked(None));
+}
@@ -0,0 +1,111 @@
+//! `Rational` against fractions worked out in `i128` by hand, and against
+//! f64 where the fraction is exact in binary.
+
+use existant_core::{BigInt, IntoPrimitive, Rational};
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// `numerator / denominator` in lowest terms with a positive denominator.
+fn reduced(numerator: i128, denominator: i128) -> (i64, i64) {
+    let sign = denominator.signum();
+    let gcd = gcd(numerator, denominator);
+    ((sign * numerator / gcd) as i64, (sign * denominator / gcd) as i64)
+}
+
+fn parts(x: Rational<i64>) -> (i64, i64) {
+    (x.numerator(), x.denominator())
+}
+
+#[test]
+fn against_i128() {
+    let fractions: Vec<(i128, i128)> = (-6..=6).flat_map(|n| [1, 2, 3, 4, 6, 9, -5].map(|d| (n, d))).collect();
+    for &(a, b) in &fractions {
+        let x = Rational::new(a as i64, b as i64);
+        assert_eq!(parts(x), reduced(a, b));
+        for &(c, d) in &fractions {
+            let y = Rational::new(c as i64, d as i64);
+            assert_eq!(parts(x + y), reduced(a * d + c * b, b * d), "{x} + {y}");
+            assert_eq!(parts(x - y), reduced(a * d - c * b, b * d), "{x} - {y}");
+            assert_eq!(parts(x * y), reduced(a * c, b * d), "{x} * {y}");
+            if c != 0 {
+                assert_eq!(parts(x / y), reduced(a * d, b * c), "{x} / {y}");
+                let quotient = a * d / (b * c);
+                assert_eq!(parts(x % y), reduced(a * d - quotient * c * b, b * d), "{x} % {y}");
+            }
+            assert_eq!(x.cmp(&y), (a * d * b.signum() * d.signum()).cmp(&(c * b * b.signum() * d.signum())));
+        }
+        let value = a as f64 / b as f64;
+        assert_eq!(x.floor().to_f64(), value.floor());
+        assert_eq!(x.ceil().to_f64(), value.ceil());
+        assert_eq!(x.trunc().to_f64(), value.trunc());
+    }
+}
+
+#[test]
+fn floats() {
+    // 0.1 is really 3602879701896397 / 2^55, which fits in an i64
+    assert_eq!(parts(Rational::from_f64(0.1)), (3_602_879_701_896_397, 1 << 55));
+    assert_eq!(Rational::<i64>::from_f64(0.1).to_f64(), 0.1);
+    assert_eq!(parts(Rational::from_f64(-2.75)), (-11, 4));
+    assert_eq!(parts(Rational::from_f64(f64::NAN)), (0, 1));
+    assert_eq!(parts(Rational::from_f64(1e30)), (i64::MAX, 1));
+    assert_eq!(parts(Rational::from_f64(-1e30)), (i64::MIN, 1));
+    assert_eq!(Rational::<u8>::from_f64(-3.0), Rational::from_integer(0));
+    // π doesn't fit, so the closest convergent with parts below 256 is 22/7
+    assert_eq!(Rational::<u8>::from_f64(core::f64::consts::PI), Rational::new(22, 7));
+    assert_eq!(Rational::<i16>::from_f64(core::f64::consts::PI), Rational::new(355, 113));
+    assert_eq!(Rational::<i64>::from_f64(f64::MIN_POSITIVE), Rational::from_integer(0));
+    let third = Rational::new(1i64, 3);
+    assert_eq!(third.to_f64(), 1.0 / 3.0);
+    assert_eq!((third * Rational::from_integer(3)).to_f64(), 1.0);
+}
+
+#[test]
+fn edges() {
+    assert_eq!(parts(Rational::new(i64::MIN, 2)), (i64::MIN / 2, 1));
+    assert_eq!(parts(Rational::new(i64::MIN, i64::MAX)), (i64::MIN, i64::MAX));
+    assert_eq!(parts(Rational::new(i64::MAX, -i64::MAX)), (-1, 1));
+    assert_eq!(Rational::new(i64::MIN, 1).to_i64(), i64::MIN);
+    assert_eq!(Rational::new(0i64, -5), Rational::from_integer(0));
+    assert_eq!(Rational::new(-7i64, 2).to_i32(), -3);
+    assert_eq!(Rational::new(1000i64, 3).to_u8(), u8::MAX);
+    assert_eq!(Rational::new(-1000i64, 3).to_u8(), 0);
+    assert_eq!(Rational::new(-1000i64, 3).to_i8(), i8::MIN);
+    assert_eq!(Rational::new(-1i8, 2).to_u64(), 0);
+    assert_eq!(Rational::new(-3i8, 2).to_u64(), 0);
+    assert_eq!(Rational::from_integer(u64::MAX).to_i64(), i64::MAX);
+    assert_eq!(Rational::from_integer(i64::MIN).to_i128(), i64::MIN as i128);
+    assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+    assert_eq!(Rational::checked_new(3, i64::MIN), None);
+    assert_eq!(Rational::checked_new(i64::MIN, i64::MIN), Some(Rational::from_integer(1)));
+    assert_eq!(Rational::checked_new(5u8, 0), None);
+    // wide enough that i128 would overflow on the cross products
+    let big = |value: i128| BigInt::<4>::from_i128(value);
+    let x = Rational::new(big(i128::MAX), big(3));
+    let y = Rational::new(big(i128::MIN + 1), big(7));
+    assert_eq!(x + y, Rational::new(big(i128::MAX) * big(4), big(21)));
+    assert!(y < x);
+}
+
+#[test]
+#[should_panic]
+fn zero_denominator() {
+    Rational::new(1i32, 0);
+}
+
+#[test]
+#[should_panic]
+fn division_by_zero() {
+    let _ = Rational::new(1i32, 2) / Rational::from_integer(0);
+}
+
+#[test]
+#[should_panic]
+fn reciprocal_of_zero() {
+    Rational::<i32>::from_integer(0).recip();
+}
@@ -0,0 +1,52 @@
+//! Round trips through JSON, and the values that shouldn't make it back.
+
+use existant_core::{BigInt, BigUint, Checked, Fixed, Gf, Gf256, Interval, MaxPlus, ModInt, Rational, Wrapping};
+use serde::{de::{value::{self, SeqDeserializer}, DeserializeOwned}, Deserialize, Serialize};
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn round_trips() {
+    assert!(round_trip(&Rational::new(6i32, -4)) == Rational::new(-3, 2));
+    assert!(round_trip(&ModInt::<12>::new(7)) == ModInt::new(7));
+    assert!(round_trip(&Gf::<13>::new(11)) == Gf::new(11));
+    assert!(round_trip(&Gf256::new(0x53)) == Gf256::new(0x53));
+    assert!(round_trip(&Interval::<f64>::new(-1.5, 2.0)) == Interval::<f64>::new(-1.5, 2.0));
+    assert!(round_trip(&Fixed::<i32, 16>::from_bits(-98304)).to_bits() == -98304);
+    assert!(round_trip(&Wrapping(250u8)) == Wrapping(250));
+    assert!(round_trip(&Checked::<u8>(None)) == Checked(None));
+    assert_eq!(round_trip(&MaxPlus::new(3i64)).value, 3);
+
+    let big = BigUint::<2>::from_limbs([1, u64::MAX]);
+    assert!(round_trip(&big) == big);
+    let negative = BigInt::from_parts(true, big);
+    assert!(round_trip(&negative) == negative);
+}
+
+#[test]
+fn compact() {
+    assert_eq!(serde_json::to_string(&Rational::new(1i32, 3)).unwrap(), "[1,3]");
+    assert_eq!(serde_json::to_string(&Wrapping(5u8)).unwrap(), "5");
+    assert_eq!(serde_json::to_string(&BigInt::<2>::from_parts(true, BigUint::from_limbs([2, 0]))).unwrap(), "[true,[2,0]]");
+}
+
+#[test]
+fn invalid() {
+    assert!(serde_json::from_str::<Rational<i32>>("[1,0]").is_err());
+    assert!(serde_json::from_str::<Rational<i32>>("[1,-2147483648]").is_err());
+    assert!(serde_json::from_str::<Rational<i32>>("[-2147483648,-1]").is_err());
+    assert_eq!(serde_json::from_str::<Rational<i32>>("[-2147483648,-4]").unwrap(), Rational::new(1 << 29, 1));
+    assert_eq!(serde_json::from_str::<Rational<i32>>("[6,-2147483648]").unwrap(), Rational::new(-3, 1 << 30));
+    assert!(serde_json::from_str::<ModInt<12>>("12").is_err());
+    assert!(serde_json::from_str::<Gf256>("256").is_err());
+    assert!(serde_json::from_str::<Interval<f32>>("[2.0,1.0]").is_err());
+    // JSON has no NaN, so this goes through serde's own deserializers
+    let nan = SeqDeserializer::<_, value::Error>::new([f64::NAN, 1.0].into_iter());
+    assert!(Interval::<f64>::deserialize(nan).is_err());
+    let fine = SeqDeserializer::<_, value::Error>::new([0.0f32, 1.0].into_iter());
+    assert!(Interval::<f32>::deserialize(fine).is_ok());
+    assert!(serde_json::from_str::<BigUint<2>>("[1]").is_err());
+    assert!(serde_json::from_str::<BigInt<1>>("[true,[0]]").is_err());
+}
@@ -0,0 +1,131 @@
+//! The special functions of `FloatingPoint` against values from mpmath.
+
+// The references are kept at the precision mpmath printed them.
+#![allow(clippy::excessive_precision)]
+
+use existant_core::{assert_approx_eq, FloatingPoint, Interval, Tolerance};
+
+const CLOSE: Tolerance<f64> = Tolerance::Relative(1e-13);
+
+#[test]
+fn gamma() {
+    assert_eq!(FloatingPoint::gamma(5.0f64), 24.0);
+    assert_approx_eq!(FloatingPoint::gamma(4.5f64), 11.631728396567448929, CLOSE);
+    assert_approx_eq!(FloatingPoint::gamma(-2.5f64), -0.94530872048294188123, CLOSE);
+    assert_approx_eq!(FloatingPoint::gamma(0.1f64), 9.5135076986687312858, CLOSE);
+    assert_eq!(FloatingPoint::gamma(0.0f64), f64::INFINITY);
+    assert!(FloatingPoint::gamma(-3.0f64).is_nan());
+
+    assert_approx_eq!(FloatingPoint::ln_gamma(100.0f64), 359.13420536957539878, CLOSE);
+    assert_approx_eq!(FloatingPoint::ln_gamma(-3.5f64), -1.3090066849930420464, CLOSE);
+    assert_approx_eq!(FloatingPoint::ln_gamma(1.0f64), 0.0, Tolerance::Absolute(1e-15));
+    assert_eq!(FloatingPoint::ln_gamma(-2.0f64), f64::INFINITY);
+
+    assert_approx_eq!(1.0f64.digamma(), -0.57721566490153286061, CLOSE);
+    assert_approx_eq!(2.5f64.digamma(), 0.70315664064524318723, CLOSE);
+    assert_approx_eq!((-0.5f64).digamma(), 0.036489973978576520559, Tolerance::Relative(1e-12));
+    assert!(0.0f64.digamma().is_nan());
+}
+
+#[test]
+fn beta() {
+    assert_approx_eq!(2.5f64.beta(3.5), 0.036815538909255389513, CLOSE);
+    assert_approx_eq!((-0.5f64).beta(2.25), -4.3700959238201996841, CLOSE);
+    // Γ(200) and Γ(300) overflow on their own
+    assert_approx_eq!(200.0f64.beta(300.0).ln(), FloatingPoint::ln_gamma(200.0f64) + FloatingPoint::ln_gamma(300.0f64) - FloatingPoint::ln_gamma(500.0f64), CLOSE);
+
+    assert_approx_eq!(0.4f64.incomplete_beta(2.0, 3.0), 0.5248, CLOSE);
+    assert_approx_eq!(0.9f64.incomplete_beta(0.5, 0.5), 0.79516723530086657191, CLOSE);
+    assert_approx_eq!(0.7f64.incomplete_beta(30.0, 20.0), 0.93001290669949251928, Tolerance::Relative(1e-12));
+    assert_eq!(0.0f64.incomplete_beta(2.0, 3.0), 0.0);
+    assert_eq!(1.0f64.incomplete_beta(2.0, 3.0), 1.0);
+    assert!(1.5f64.incomplete_beta(2.0, 3.0).is_nan());
+    assert!(0.5f64.incomplete_beta(-1.0, 3.0).is_nan());
+}
+
+#[test]
+fn error_function() {
+    assert_approx_eq!(FloatingPoint::erf(0.5f64), 0.52049987781304653768, CLOSE);
+    assert_approx_eq!(FloatingPoint::erf(2.0f64), 0.99532226501895273416, CLOSE);
+    assert_approx_eq!(FloatingPoint::erfc(3.0f64), 0.000022090496998585441373, CLOSE);
+    assert_approx_eq!(FloatingPoint::erfc(10.0f64), 2.088487583762544757e-45, CLOSE);
+    assert_approx_eq!(FloatingPoint::erfc(-1.0f64), 1.8427007929497148693, CLOSE);
+    assert_approx_eq!(FloatingPoint::erf(0.5f32), 0.5204999, Tolerance::Ulps(1));
+
+    assert_approx_eq!(0.5f64.erf_inv(), 0.47693627620446987338, CLOSE);
+    assert_approx_eq!((-0.5f64).erf_inv(), -0.47693627620446987338, CLOSE);
+    assert_approx_eq!((1.0 - 2.0f64.powi(-40)).erf_inv(), 5.0512540852493899671, CLOSE);
+    assert_eq!(1.0f64.erf_inv(), f64::INFINITY);
+    assert!(1.5f64.erf_inv().is_nan());
+    for x in [-1.5, -0.25, 0.0, 1e-10, 0.7, 2.0] {
+        assert_approx_eq!(FloatingPoint::erf(x).erf_inv(), x, CLOSE);
+    }
+
+    let x = Interval::<f64>::new(0.5, 2.0);
+    assert!(x.erf().contains(FloatingPoint::erf(0.5f64)) && x.erf().contains(FloatingPoint::erf(2.0f64)));
+    assert!(x.erfc().contains(FloatingPoint::erfc(0.5f64)) && x.erfc().contains(FloatingPoint::erfc(2.0f64)));
+}
+
+#[test]
+fn bessel() {
+    assert_approx_eq!(2.5f64.bessel_j(0), -0.048383776468197996327, CLOSE);
+    assert_approx_eq!(10.0f64.bessel_j(1), 0.04347274616886143667, CLOSE);
+    assert_approx_eq!(3.0f64.bessel_j(5), 0.043028434877047583925, CLOSE);
+    assert_approx_eq!((-2.0f64).bessel_j(3), -0.1289432494744020511, CLOSE);
+    assert_approx_eq!(2.0f64.bessel_j(-3), -0.1289432494744020511, CLOSE);
+    assert_approx_eq!(50.0f64.bessel_j(0), 0.055812327669251815005, CLOSE);
+    assert_approx_eq!(5.0f64.bessel_j(20), 2.7703300521289416874e-11, CLOSE);
+    assert_eq!(0.0f64.bessel_j(0), 1.0);
+    assert_eq!(0.0f64.bessel_j(2), 0.0);
+
+    assert_approx_eq!(1.0f64.bessel_y(0), 0.088256964215676957983, CLOSE);
+    assert_approx_eq!(0.5f64.bessel_y(1), -1.4714723926702430692, CLOSE);
+    assert_approx_eq!(8.0f64.bessel_y(3), 0.02654215932105844721, CLOSE);
+    assert_approx_eq!(40.0f64.bessel_y(1), -0.0057935058215496329412, CLOSE);
+    assert_eq!(0.0f64.bessel_y(1), f64::NEG_INFINITY);
+    assert!((-1.0f64).bessel_y(0).is_nan());
+}
+
+#[test]
+fn elliptic() {
+    assert_approx_eq!(0.5f64.elliptic_k(), 1.8540746773013719184, CLOSE);
+    assert_approx_eq!(0.5f64.elliptic_e(), 1.3506438810476755025, CLOSE);
+    assert_approx_eq!(0.99f64.elliptic_k(), 3.6956373629898742386, CLOSE);
+    assert_approx_eq!((-2.0f64).elliptic_e(), 2.1844381427462011854, CLOSE);
+    assert_eq!(0.0f64.elliptic_k(), core::f64::consts::FRAC_PI_2);
+    assert_eq!(1.0f64.elliptic_k(), f64::INFINITY);
+    assert_eq!(1.0f64.elliptic_e(), 1.0);
+    assert!(1.5f64.elliptic_k().is_nan());
+
+    // the perimeter of an ellipse with semi-axes 3 and 2
+    let (a, b) = (3.0f64, 2.0f64);
+    let eccentricity_squared = 1.0 - (b / a) * (b / a);
+    assert_approx_eq!(4.0 * a * eccentricity_squared.elliptic_e(), 15.865439589290589636, CLOSE);
+    // and of a circle
+    assert_approx_eq!(4.0 * a * 0.0f64.elliptic_e(), core::f64::consts::TAU * a, CLOSE);
+}
+
+/// f32 goes through the same generic code, within its own precision.
+#[test]
+fn single_precision() {
+    let close = Tolerance::Relative(1e-5);
+    assert_approx_eq!(FloatingPoint::gamma(4.5f32), 11.631728, close);
+    assert_approx_eq!(2.5f32.digamma(), 0.70315664, close);
+    assert_approx_eq!(2.5f32.beta(3.5), 0.03681554, close);
+    assert_approx_eq!(0.5f32.erf_inv(), 0.47693628, close);
+    assert_approx_eq!(3.0f32.bessel_j(5), 0.043028435, close);
+    assert_approx_eq!(8.0f32.bessel_y(3), 0.026542159, close);
+    assert_approx_eq!(0.5f32.elliptic_k(), 1.8540747, close);
+}
+
+#[test]
+fn interval_domain() {
+    // outside of the domain the result is NaN, not a point at zero
+    let point = Interval::<f64>::point;
+    assert!(point(-2.0).gamma().is_nan());
+    assert!(point(2.0).incomplete_beta(point(1.0), point(1.0)).is_nan());
+    assert!(point(1.5).erf_inv().is_nan());
+    assert!(point(-1.0).bessel_y(0).is_nan());
+    assert!(point(1.5).elliptic_k().is_nan());
+    assert!(<Interval<f32> as FloatingPoint>::nan().is_nan());
+}
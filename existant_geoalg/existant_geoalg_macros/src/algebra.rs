@@ -0,0 +1,305 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{DeriveInput, Expr, Generics, Member, Type, parse_macro_input};
+
+/// A field of the struct the traits are forwarded through. `PhantomData`
+/// fields are only there to be filled in, so unit markers don't need to
+/// implement anything.
+struct Field {
+    member: Member,
+    ty: Type,
+    phantom: bool,
+}
+
+struct Algebra {
+    name: syn::Ident,
+    generics: Generics,
+    fields: Vec<Field>,
+    zero: Option<Expr>,
+    one: Option<Expr>,
+    commutative: bool,
+}
+
+fn is_phantom(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last().is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+fn parse_algebra(structure: &DeriveInput) -> Algebra {
+    let fields = match &structure.data {
+        syn::Data::Struct(data) => {
+            data.fields.iter().enumerate().map(|(i, field)| Field {
+                member: match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(i.into()),
+                },
+                ty: field.ty.clone(),
+                phantom: is_phantom(&field.ty),
+            }).collect::<Vec<_>>()
+        }
+        syn::Data::Union(_) => {
+            panic!("Expected struct, found union");
+        }
+        syn::Data::Enum(_) => {
+            panic!("Expected struct, found enum");
+        }
+    };
+    if fields.iter().all(|field| field.phantom) {
+        panic!("Expected a field to forward through");
+    }
+    let mut algebra = Algebra {
+        name: structure.ident.clone(),
+        generics: structure.generics.clone(),
+        fields,
+        zero: None,
+        one: None,
+        commutative: false,
+    };
+    for attribute in structure.attrs.iter().filter(|attribute| attribute.path().is_ident("algebra")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("zero") {
+                algebra.zero = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("one") {
+                algebra.one = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("commutative") {
+                algebra.commutative = true;
+            } else {
+                return Err(meta.error("Attribute not recognized, expected `zero`, `one` or `commutative`"));
+            }
+            Ok(())
+        }).unwrap();
+    }
+    algebra
+}
+
+impl Algebra {
+    /// The types of the fields that aren't `PhantomData`, without repeats.
+    fn forwarded_types(&self) -> Vec<&Type> {
+        let mut types: Vec<&Type> = vec![];
+        for field in self.fields.iter().filter(|field| !field.phantom) {
+            if !types.iter().any(|ty| ty.to_token_stream().to_string() == field.ty.to_token_stream().to_string()) {
+                types.push(&field.ty);
+            }
+        }
+        types
+    }
+    /// `impl #bound for Self`, where every forwarded type has to meet the
+    /// bound `extra` gives for it, if any.
+    fn implement(&self, bound: TokenStream, extra: impl Fn(&Type) -> Option<TokenStream>, body: TokenStream) -> TokenStream {
+        let name = &self.name;
+        let mut generics = self.generics.clone();
+        let where_clause = generics.make_where_clause();
+        for ty in self.forwarded_types() {
+            if let Some(more) = extra(ty) {
+                where_clause.predicates.push(syn::parse_quote!(#ty: #more));
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics #bound for #name #ty_generics #where_clause {
+                #body
+            }
+        }
+    }
+    /// Builds `Self` out of `value` applied to each field.
+    fn construct(&self, value: impl Fn(&Field) -> TokenStream) -> TokenStream {
+        let fields = self.fields.iter().map(|field| {
+            let member = &field.member;
+            if field.phantom {
+                quote!(#member: ::core::marker::PhantomData)
+            } else {
+                let value = value(field);
+                quote!(#member: #value)
+            }
+        });
+        quote!(Self { #(#fields),* })
+    }
+    /// `#property<#op>` with no items, such as `AssociativeOver`.
+    fn property(&self, property: &str, op: &str) -> TokenStream {
+        let property = syn::Ident::new(property, proc_macro2::Span::call_site());
+        let op = syn::Ident::new(op, proc_macro2::Span::call_site());
+        let bound = quote!(::existant_core::#property<::existant_core::#op>);
+        self.implement(bound.clone(), |_| Some(bound.clone()), quote!())
+    }
+    fn identity(&self, op: &str, value: &Option<Expr>) -> TokenStream {
+        let op = syn::Ident::new(op, proc_macro2::Span::call_site());
+        let bound = quote!(::existant_core::Identity<::existant_core::#op>);
+        let body = match value {
+            Some(value) => quote! {
+                const IDENTITY: Self = #value;
+                fn is_identity(&self) -> bool {
+                    *self == #value
+                }
+            },
+            None => {
+                let identity = self.construct(|field| {
+                    let ty = &field.ty;
+                    quote!(<#ty as ::existant_core::Identity<::existant_core::#op>>::IDENTITY)
+                });
+                let checks = self.fields.iter().filter(|field| !field.phantom).map(|field| {
+                    let (member, ty) = (&field.member, &field.ty);
+                    quote!(<#ty as ::existant_core::Identity<::existant_core::#op>>::is_identity(&self.#member))
+                });
+                quote! {
+                    const IDENTITY: Self = #identity;
+                    fn is_identity(&self) -> bool {
+                        #(#checks)&&*
+                    }
+                }
+            }
+        };
+        self.implement(bound.clone(), |_| value.is_none().then(|| bound.clone()), body)
+    }
+    fn inverse(&self, op: &str) -> TokenStream {
+        let op = syn::Ident::new(op, proc_macro2::Span::call_site());
+        let bound = quote!(::existant_core::Inverse<::existant_core::#op>);
+        let inverse = self.construct(|field| {
+            let (member, ty) = (&field.member, &field.ty);
+            quote!(<#ty as ::existant_core::Inverse<::existant_core::#op>>::inverse(self.#member))
+        });
+        self.implement(bound.clone(), |_| Some(bound.clone()), quote! {
+            fn inverse(self) -> Self {
+                #inverse
+            }
+        })
+    }
+    /// Zero absorbs multiplication in any ring, so this is the additive
+    /// identity when it's overridden.
+    fn absorption(&self) -> TokenStream {
+        let bound = quote!(::existant_core::Absorption<::existant_core::Multiplication>);
+        let body = match &self.zero {
+            Some(zero) => quote! {
+                const ABSORBING: Self = #zero;
+                fn is_absorber(&self) -> bool {
+                    *self == #zero
+                }
+            },
+            None => {
+                let absorbing = self.construct(|field| {
+                    let ty = &field.ty;
+                    quote!(<#ty as ::existant_core::Absorption<::existant_core::Multiplication>>::ABSORBING)
+                });
+                let checks = self.fields.iter().filter(|field| !field.phantom).map(|field| {
+                    let (member, ty) = (&field.member, &field.ty);
+                    quote!(<#ty as ::existant_core::Absorption<::existant_core::Multiplication>>::is_absorber(&self.#member))
+                });
+                quote! {
+                    const ABSORBING: Self = #absorbing;
+                    fn is_absorber(&self) -> bool {
+                        #(#checks)&&*
+                    }
+                }
+            }
+        };
+        self.implement(bound.clone(), |_| self.zero.is_none().then(|| bound.clone()), body)
+    }
+    /// The operator trait from `core::ops`, field by field.
+    fn operator(&self, operator: &str, method: &str) -> TokenStream {
+        let operator = syn::Ident::new(operator, proc_macro2::Span::call_site());
+        let method = syn::Ident::new(method, proc_macro2::Span::call_site());
+        let result = self.construct(|field| {
+            let member = &field.member;
+            quote!(::core::ops::#operator::#method(self.#member, rhs.#member))
+        });
+        self.implement(quote!(::core::ops::#operator), |ty| Some(quote!(::core::ops::#operator<Output = #ty>)), quote! {
+            type Output = Self;
+            fn #method(self, rhs: Self) -> Self {
+                #result
+            }
+        })
+    }
+    fn negation(&self) -> TokenStream {
+        let result = self.construct(|field| {
+            let member = &field.member;
+            quote!(::core::ops::Neg::neg(self.#member))
+        });
+        self.implement(quote!(::core::ops::Neg), |ty| Some(quote!(::core::ops::Neg<Output = #ty>)), quote! {
+            type Output = Self;
+            fn neg(self) -> Self {
+                #result
+            }
+        })
+    }
+    fn group(&self, commutative: bool) -> TokenStream {
+        let mut tokens = self.property("ClosedUnder", "Addition");
+        tokens.extend(self.property("AssociativeOver", "Addition"));
+        if commutative {
+            tokens.extend(self.property("CommutativeOver", "Addition"));
+        }
+        tokens.extend(self.identity("Addition", &self.zero));
+        tokens.extend(self.inverse("Addition"));
+        tokens.extend(self.operator("Add", "add"));
+        tokens.extend(self.operator("Sub", "sub"));
+        tokens.extend(self.negation());
+        tokens
+    }
+    fn ring(&self, commutative: bool) -> TokenStream {
+        let mut tokens = self.group(true);
+        tokens.extend(self.property("ClosedUnder", "Multiplication"));
+        tokens.extend(self.property("AssociativeOver", "Multiplication"));
+        if commutative {
+            tokens.extend(self.property("CommutativeOver", "Multiplication"));
+        }
+        tokens.extend(self.distributive());
+        tokens.extend(self.identity("Multiplication", &self.one));
+        tokens.extend(self.absorption());
+        tokens.extend(self.operator("Mul", "mul"));
+        tokens
+    }
+    fn distributive(&self) -> TokenStream {
+        let bound = quote!(::existant_core::Distributive<::existant_core::Multiplication, ::existant_core::Addition>);
+        self.implement(bound.clone(), |_| Some(bound.clone()), quote!())
+    }
+    /// Scalar multiplication by the one type every field has.
+    fn semimodule(&self) -> TokenStream {
+        let types = self.forwarded_types();
+        if types.len() != 1 {
+            panic!("Expected every field to have the same type, to use as the scalar");
+        }
+        let scalar = types[0];
+        let result = self.construct(|field| {
+            let member = &field.member;
+            quote!(<#scalar as ::existant_core::Groupoid<::existant_core::Multiplication>>::op(&self.#member, &rhs))
+        });
+        self.implement(quote!(::existant_core::Semimodule), |_| Some(quote!(::existant_core::Semiring)), quote! {
+            type Scalar = #scalar;
+            fn scalar_multiplication(&self, rhs: Self::Scalar) -> Self {
+                #result
+            }
+        })
+    }
+}
+
+pub fn derive_group_inner(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let structure = parse_macro_input!(item as DeriveInput);
+    let algebra = parse_algebra(&structure);
+    algebra.group(algebra.commutative).into()
+}
+
+pub fn derive_ring_inner(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let structure = parse_macro_input!(item as DeriveInput);
+    let algebra = parse_algebra(&structure);
+    algebra.ring(algebra.commutative).into()
+}
+
+pub fn derive_field_inner(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let structure = parse_macro_input!(item as DeriveInput);
+    let algebra = parse_algebra(&structure);
+    if algebra.fields.iter().filter(|field| !field.phantom).count() != 1 {
+        panic!("Expected exactly one field, a product of fields has zero divisors");
+    }
+    let mut tokens = algebra.ring(true);
+    tokens.extend(algebra.inverse("Multiplication"));
+    tokens.extend(algebra.operator("Div", "div"));
+    tokens.into()
+}
+
+pub fn derive_vector_space_inner(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let structure = parse_macro_input!(item as DeriveInput);
+    let algebra = parse_algebra(&structure);
+    let mut tokens = algebra.group(true);
+    tokens.extend(algebra.semimodule());
+    tokens.into()
+}
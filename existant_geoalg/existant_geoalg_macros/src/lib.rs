@@ -1,7 +1,9 @@
 #![allow(unused)]
 use crate::matrix::matrix_multiplication_inner;
+use crate::algebra::{derive_field_inner, derive_group_inner, derive_ring_inner, derive_vector_space_inner};
 
 mod matrix;
+mod algebra;
 /// Attributes:
 /// mul(
 ///     type()
@@ -14,3 +16,49 @@ mod matrix;
 pub fn matrix_multiplication(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     matrix_multiplication_inner(attr, item)
 }
+
+/// Makes a struct an additive `Group` by forwarding
+/// `+`, `-`, the identity and the inverse through its fields, which is most
+/// of what a unit like `Meters(f64)` needs. `PhantomData` fields are left
+/// alone.
+///
+/// Attributes:
+/// algebra(
+///     zero = <expr>, replaces the identity, so the fields don't need one,
+///         but `Self` needs `PartialEq`
+///     commutative, makes it an `AbelianGroup` too
+/// )
+#[proc_macro_derive(Group, attributes(algebra))]
+pub fn derive_group(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_group_inner(item)
+}
+
+/// Everything `Group` does plus `*`, its identity and distributivity, for
+/// a `Ring`. Several fields make the product ring.
+///
+/// Attributes:
+/// algebra(
+///     zero = <expr>
+///     one = <expr>
+///     commutative, makes `*` commutative
+/// )
+#[proc_macro_derive(Ring, attributes(algebra))]
+pub fn derive_ring(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_ring_inner(item)
+}
+
+/// Everything `Ring` does plus `/` and the multiplicative inverse, for a
+/// `Field` around a single field. Takes the same
+/// attributes as `Ring`.
+#[proc_macro_derive(Field, attributes(algebra))]
+pub fn derive_field(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_field_inner(item)
+}
+
+/// An abelian `Group` under `+`, and a `Semimodule`
+/// scaling each field, so a `VectorSpace`
+/// when the fields are a field. Every field has to have the same type.
+#[proc_macro_derive(VectorSpace, attributes(algebra))]
+pub fn derive_vector_space(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_vector_space_inner(item)
+}
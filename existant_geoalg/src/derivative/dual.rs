@@ -0,0 +1,500 @@
+use core::fmt;
+
+use existant_core::{Absorption, Addition, AssociativeOver, Bounds, ClosedUnder, CommutativeOver, Distributive, Division, FloatBits, FloatConsts, FloatingPoint, FromPrimitive, Identity, IntoPrimitive, Inverse, Multiplication, Signed, Subtraction};
+
+use super::Derivative;
+
+/// A dual number `a + b₁ε₁ + ... + bₙεₙ`, where every `εᵢεⱼ` is zero. Passing
+/// one through a function `f` gives `f(a) + f'(a)(b₁ε₁ + ... + bₙεₙ)`, so any
+/// code that is generic over [`FloatingPoint`] computes exact derivatives
+/// alongside its value, which is forward mode automatic differentiation.
+///
+/// With `N` set to more than 1 every `εᵢ` tracks the derivative with respect
+/// to a different variable, so one evaluation gives a whole gradient, see
+/// [`Dual::variables`]. For second derivatives use a [`HyperDual`].
+///
+/// Comparisons only look at the real part, since that is the value the code
+/// being differentiated thinks it's working with.
+/// ```
+/// use existant_core::FloatingPoint;
+/// use existant_geoalg::derivative::Dual;
+///
+/// let x = Dual::variable(2.0_f64);
+/// let y = x * x.sin();
+/// assert_eq!(y.real, 2.0 * 2.0_f64.sin());
+/// assert_eq!(y.derivative(), 2.0_f64.sin() + 2.0 * 2.0_f64.cos());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Dual<T, const N: usize = 1> {
+    pub real: T,
+    pub dual: [T; N],
+}
+
+/// A dual number with dual numbers for its parts, so one derivative ends
+/// up as the coefficient of the other. Seeding it with
+/// [`HyperDual::hyper_variable`] leaves the first derivative in
+/// `real.dual[0]` and the second in `dual[0].dual[0]`.
+pub type HyperDual<T> = Dual<Dual<T>>;
+
+impl<T: FloatingPoint, const N: usize> Dual<T, N> {
+    pub const fn new(real: T, dual: [T; N]) -> Self {
+        Self { real, dual }
+    }
+    /// A value that doesn't depend on any of the variables.
+    pub const fn constant(real: T) -> Self {
+        Self { real, dual: [<T as Identity<Addition>>::IDENTITY; N] }
+    }
+    /// The `index`th variable, whose derivative ends up in `dual[index]`.
+    pub fn variable_at(real: T, index: usize) -> Self {
+        let mut variable = Self::constant(real);
+        variable.dual[index] = <T as Identity<Multiplication>>::IDENTITY;
+        variable
+    }
+    /// Every value in `values` as its own variable, so a function of all of
+    /// them gives its gradient in `dual`.
+    pub fn variables(values: [T; N]) -> [Self; N] {
+        core::array::from_fn(|index| Self::variable_at(values[index], index))
+    }
+    /// The gradient with respect to every variable.
+    pub const fn gradient(&self) -> [T; N] {
+        self.dual
+    }
+    /// `f(self)` given `f(real)` and `f'(real)`, from the chain rule.
+    fn chain(self, value: T, slope: T) -> Self {
+        Self { real: value, dual: self.dual.map(|dual| dual * slope) }
+    }
+}
+
+impl<T: FloatingPoint> Dual<T> {
+    /// The variable being differentiated with respect to.
+    pub const fn variable(real: T) -> Self {
+        Self { real, dual: [<T as Identity<Multiplication>>::IDENTITY] }
+    }
+    pub const fn derivative(&self) -> T {
+        self.dual[0]
+    }
+}
+
+impl<T: FloatingPoint> HyperDual<T> {
+    pub const fn hyper_variable(real: T) -> Self {
+        Dual { real: Dual::variable(real), dual: [Dual::constant(<T as Identity<Multiplication>>::IDENTITY)] }
+    }
+    pub const fn second_derivative(&self) -> T {
+        self.dual[0].dual[0]
+    }
+}
+
+impl<T: FloatingPoint> Derivative for Dual<T> {
+    type Output = T;
+    fn derive(&self) -> Self::Output {
+        self.derivative()
+    }
+}
+
+impl<T: FloatingPoint + fmt::Display, const N: usize> fmt::Display for Dual<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.real, f)?;
+        for (index, dual) in self.dual.iter().enumerate() {
+            if dual.is_negative() {
+                f.write_str(" - ")?;
+                fmt::Display::fmt(&dual.abs(), f)?;
+            } else {
+                f.write_str(" + ")?;
+                fmt::Display::fmt(dual, f)?;
+            }
+            if N == 1 { f.write_str("ε")? } else { write!(f, "ε{}", index + 1)? }
+        }
+        Ok(())
+    }
+}
+
+impl<T: FloatingPoint, const N: usize> PartialEq for Dual<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.real == other.real
+    }
+}
+impl<T: FloatingPoint, const N: usize> PartialOrd for Dual<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.real.partial_cmp(&other.real)
+    }
+}
+
+impl<T: FloatingPoint, const N: usize> core::ops::Add for Dual<T, N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { real: self.real + rhs.real, dual: core::array::from_fn(|i| self.dual[i] + rhs.dual[i]) }
+    }
+}
+impl<T: FloatingPoint, const N: usize> core::ops::Sub for Dual<T, N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { real: self.real - rhs.real, dual: core::array::from_fn(|i| self.dual[i] - rhs.dual[i]) }
+    }
+}
+impl<T: FloatingPoint, const N: usize> core::ops::Mul for Dual<T, N> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { real: self.real * rhs.real, dual: core::array::from_fn(|i| self.dual[i] * rhs.real + self.real * rhs.dual[i]) }
+    }
+}
+impl<T: FloatingPoint, const N: usize> core::ops::Div for Dual<T, N> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let real = self.real / rhs.real;
+        Self { real, dual: core::array::from_fn(|i| (self.dual[i] - real * rhs.dual[i]) / rhs.real) }
+    }
+}
+impl<T: FloatingPoint, const N: usize> core::ops::Rem for Dual<T, N> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn rem(self, rhs: Self) -> Self::Output {
+        // x % y = x - y * trunc(x / y), where the truncated part is flat.
+        let quotient = (self.real / rhs.real).trunc();
+        Self { real: self.real % rhs.real, dual: core::array::from_fn(|i| self.dual[i] - quotient * rhs.dual[i]) }
+    }
+}
+impl<T: FloatingPoint, const N: usize> core::ops::Neg for Dual<T, N> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { real: -self.real, dual: self.dual.map(|dual| -dual) }
+    }
+}
+
+macro_rules! impl_dual_assign {
+    ($($trait:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl<T: FloatingPoint, const N: usize> core::ops::$trait for Dual<T, N> {
+                fn $method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+impl_dual_assign!(AddAssign, add_assign, +; SubAssign, sub_assign, -; MulAssign, mul_assign, *; DivAssign, div_assign, /; RemAssign, rem_assign, %);
+
+impl<T: FloatingPoint, const N: usize> AssociativeOver<Addition> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> CommutativeOver<Addition> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> ClosedUnder<Addition> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> AssociativeOver<Multiplication> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> CommutativeOver<Multiplication> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> ClosedUnder<Multiplication> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> AssociativeOver<Subtraction> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> ClosedUnder<Subtraction> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> ClosedUnder<Division> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> Distributive<Multiplication, Addition> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> Distributive<Multiplication, Subtraction> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> Distributive<Division, Addition> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> Distributive<Division, Subtraction> for Dual<T, N> {}
+impl<T: FloatingPoint, const N: usize> Identity<Addition> for Dual<T, N> {
+    const IDENTITY: Self = Self::constant(<T as Identity<Addition>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <T as Identity<Addition>>::is_identity(&self.real) && self.dual.iter().all(<T as Identity<Addition>>::is_identity)
+    }
+}
+impl<T: FloatingPoint, const N: usize> Identity<Subtraction> for Dual<T, N> {
+    const IDENTITY: Self = Self::constant(<T as Identity<Addition>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <Self as Identity<Addition>>::is_identity(self)
+    }
+}
+impl<T: FloatingPoint, const N: usize> Identity<Multiplication> for Dual<T, N> {
+    const IDENTITY: Self = Self::constant(<T as Identity<Multiplication>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <T as Identity<Multiplication>>::is_identity(&self.real) && self.dual.iter().all(<T as Identity<Addition>>::is_identity)
+    }
+}
+impl<T: FloatingPoint, const N: usize> Identity<Division> for Dual<T, N> {
+    const IDENTITY: Self = Self::constant(<T as Identity<Multiplication>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        <Self as Identity<Multiplication>>::is_identity(self)
+    }
+}
+impl<T: FloatingPoint, const N: usize> Absorption<Multiplication> for Dual<T, N> {
+    const ABSORBING: Self = Self::constant(<T as Identity<Addition>>::IDENTITY);
+    fn is_absorber(&self) -> bool {
+        <Self as Identity<Addition>>::is_identity(self)
+    }
+}
+impl<T: FloatingPoint, const N: usize> Inverse<Addition> for Dual<T, N> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+impl<T: FloatingPoint, const N: usize> Inverse<Subtraction> for Dual<T, N> {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+impl<T: FloatingPoint, const N: usize> Inverse<Multiplication> for Dual<T, N> {
+    fn inverse(self) -> Self {
+        self.recip()
+    }
+}
+
+impl<T: FloatingPoint, const N: usize> Signed for Dual<T, N> {
+    fn is_negative(self) -> bool {
+        self.real.is_negative()
+    }
+    fn is_positive(self) -> bool {
+        self.real.is_positive()
+    }
+    fn abs(self) -> Self {
+        if self.real.is_negative() { -self } else { self }
+    }
+    fn flip_sign(self) -> Self {
+        -self
+    }
+}
+
+impl<T: FloatingPoint, const N: usize> Bounds for Dual<T, N> {
+    fn min(self, other: Self) -> Self {
+        if other.real < self.real { other } else { self }
+    }
+    fn max(self, other: Self) -> Self {
+        if other.real > self.real { other } else { self }
+    }
+    const MIN: Self = Self::constant(T::MIN);
+    const MAX: Self = Self::constant(T::MAX);
+}
+
+macro_rules! impl_dual_primitive {
+    ($($from:ident, $to:ident, $ty:ty);*) => {
+        impl<T: FloatingPoint, const N: usize> FromPrimitive for Dual<T, N> {
+            $(
+                fn $from(val: $ty) -> Self {
+                    Self::constant(T::$from(val))
+                }
+            )*
+        }
+        impl<T: FloatingPoint, const N: usize> IntoPrimitive for Dual<T, N> {
+            $(
+                fn $to(self) -> $ty {
+                    self.real.$to()
+                }
+            )*
+        }
+    };
+}
+impl_dual_primitive!(
+    from_u8, to_u8, u8; from_u16, to_u16, u16; from_u32, to_u32, u32; from_u64, to_u64, u64;
+    from_u128, to_u128, u128; from_usize, to_usize, usize; from_i8, to_i8, i8; from_i16, to_i16, i16;
+    from_i32, to_i32, i32; from_i64, to_i64, i64; from_i128, to_i128, i128; from_isize, to_isize, isize;
+    from_f32, to_f32, f32; from_f64, to_f64, f64
+);
+
+macro_rules! impl_dual_consts {
+    ($($name:ident),*) => {
+        impl<T: FloatingPoint, const N: usize> FloatConsts for Dual<T, N> {
+            $(
+                const $name: Self = Self::constant(T::$name);
+            )*
+        }
+    };
+}
+impl_dual_consts!(
+    PI, TAU, PHI, EGAMMA, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, FRAC_1_PI, FRAC_1_SQRT_PI,
+    FRAC_2_PI, FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2, SQRT_3, FRAC_1_SQRT_3, E, LOG2_E, LOG2_10, LOG10_E,
+    LOG10_2, LN_2, LN_10, EPSILON, INFINITY
+);
+
+impl<T: FloatingPoint, const N: usize> FloatingPoint for Dual<T, N> {
+    fn acos(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.acos(), -(one - self.real * self.real).sqrt().recip())
+    }
+    fn acosh(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.acosh(), (self.real * self.real - one).sqrt().recip())
+    }
+    fn asin(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.asin(), (one - self.real * self.real).sqrt().recip())
+    }
+    fn asinh(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.asinh(), (self.real * self.real + one).sqrt().recip())
+    }
+    fn atan(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.atan(), (one + self.real * self.real).recip())
+    }
+    fn atan2(self, other: Self) -> Self {
+        let squared_length = self.real * self.real + other.real * other.real;
+        Self {
+            real: self.real.atan2(other.real),
+            dual: core::array::from_fn(|i| (other.real * self.dual[i] - self.real * other.dual[i]) / squared_length),
+        }
+    }
+    fn atanh(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.atanh(), (one - self.real * self.real).recip())
+    }
+    fn cbrt(self) -> Self {
+        let cbrt = self.real.cbrt();
+        self.chain(cbrt, (T::from_u8(3) * cbrt * cbrt).recip())
+    }
+    fn ceil(self) -> Self {
+        Self::constant(self.real.ceil())
+    }
+    fn cos(self) -> Self {
+        let (sin, cos) = self.real.sin_cos();
+        self.chain(cos, -sin)
+    }
+    fn exp(self) -> Self {
+        let exp = self.real.exp();
+        self.chain(exp, exp)
+    }
+    fn exp2(self) -> Self {
+        let exp2 = self.real.exp2();
+        self.chain(exp2, exp2 * T::LN_2)
+    }
+    fn exp_m1(self) -> Self {
+        self.chain(self.real.exp_m1(), self.real.exp())
+    }
+    fn floor(self) -> Self {
+        Self::constant(self.real.floor())
+    }
+    fn fract(self) -> Self {
+        Self { real: self.real.fract(), dual: self.dual }
+    }
+    fn hypot(self, other: Self) -> Self {
+        let hypot = self.real.hypot(other.real);
+        Self {
+            real: hypot,
+            dual: core::array::from_fn(|i| (self.real * self.dual[i] + other.real * other.dual[i]) / hypot),
+        }
+    }
+    fn ln(self) -> Self {
+        self.chain(self.real.ln(), self.real.recip())
+    }
+    fn ln_1p(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.ln_1p(), (one + self.real).recip())
+    }
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+    fn log10(self) -> Self {
+        self.chain(self.real.log10(), (self.real * T::LN_10).recip())
+    }
+    fn log2(self) -> Self {
+        self.chain(self.real.log2(), (self.real * T::LN_2).recip())
+    }
+    fn powf(self, n: Self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        let power = self.real.powf(n.real);
+        let slope = n.real * self.real.powf(n.real - one);
+        // The derivative with respect to the exponent only exists for a
+        // positive base, leaving it out keeps constant exponents working
+        // on negative bases.
+        let exponent_slope = if self.real > zero { power * self.real.ln() } else { zero };
+        // A constant base or exponent has no part in the derivative, even
+        // when its slope is infinite, which would make it NaN otherwise.
+        let term = |slope: T, dual: T| if <T as Identity<Addition>>::is_identity(&dual) { zero } else { slope * dual };
+        Self { real: power, dual: core::array::from_fn(|i| term(slope, self.dual[i]) + term(exponent_slope, n.dual[i])) }
+    }
+    fn powi(self, n: i32) -> Self {
+        let power = self.real.powi(n);
+        // n - 1 overflows for i32::MIN, where x^(n - 1) is x^n / x anyway.
+        let lower = match n.checked_sub(1) {
+            Some(n) => self.real.powi(n),
+            None => power / self.real,
+        };
+        self.chain(power, T::from_i32(n) * lower)
+    }
+    fn recip(self) -> Self {
+        let recip = self.real.recip();
+        self.chain(recip, -recip * recip)
+    }
+    fn round(self) -> Self {
+        Self::constant(self.real.round())
+    }
+    fn signum(self) -> Self {
+        Self::constant(self.real.signum())
+    }
+    fn sin(self) -> Self {
+        let (sin, cos) = self.real.sin_cos();
+        self.chain(sin, cos)
+    }
+    fn sin_cos(self) -> (Self, Self)
+            where Self: Sized {
+        let (sin, cos) = self.real.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+    fn sinh(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        let sinh = self.real.sinh();
+        self.chain(sinh, (one + sinh * sinh).sqrt())
+    }
+    fn sqrt(self) -> Self {
+        let sqrt = self.real.sqrt();
+        self.chain(sqrt, (T::from_u8(2) * sqrt).recip())
+    }
+    fn tan(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        let tan = self.real.tan();
+        self.chain(tan, one + tan * tan)
+    }
+    fn tanh(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        let tanh = self.real.tanh();
+        self.chain(tanh, one - tanh * tanh)
+    }
+    fn to_degrees(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.to_degrees(), one.to_degrees())
+    }
+    fn to_radians(self) -> Self {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        self.chain(self.real.to_radians(), one.to_radians())
+    }
+    fn trunc(self) -> Self {
+        Self::constant(self.real.trunc())
+    }
+    fn is_finite(self) -> bool {
+        self.real.is_finite() && self.dual.iter().all(|dual| dual.is_finite())
+    }
+    fn is_infinite(self) -> bool {
+        self.real.is_infinite() || self.dual.iter().any(|dual| dual.is_infinite())
+    }
+    fn is_nan(self) -> bool {
+        self.real.is_nan() || self.dual.iter().any(|dual| dual.is_nan())
+    }
+    fn is_normal(self) -> bool {
+        self.real.is_normal()
+    }
+    fn is_subnormal(self) -> bool {
+        self.real.is_subnormal()
+    }
+    fn erf(self) -> Self {
+        self.chain(self.real.erf(), T::FRAC_2_SQRT_PI * (-self.real * self.real).exp())
+    }
+    fn erfc(self) -> Self {
+        self.chain(self.real.erfc(), -T::FRAC_2_SQRT_PI * (-self.real * self.real).exp())
+    }
+    /// By the real part, then the derivatives in order.
+    fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.dual.iter().zip(&other.dual)
+            .fold(self.real.total_cmp(&other.real), |ordering, (a, b)| ordering.then_with(|| a.total_cmp(b)))
+    }
+    /// Moves the real part and keeps the derivatives.
+    fn next_up(self) -> Self {
+        Self { real: self.real.next_up(), dual: self.dual }
+    }
+    fn next_down(self) -> Self {
+        Self { real: self.real.next_down(), dual: self.dual }
+    }
+}
+impl<T: FloatBits, const N: usize> FloatBits for Dual<T, N> {
+    type Bits = (T::Bits, [T::Bits; N]);
+    fn to_bits(self) -> Self::Bits {
+        (self.real.to_bits(), self.dual.map(T::to_bits))
+    }
+    fn from_bits(bits: Self::Bits) -> Self {
+        Self { real: T::from_bits(bits.0), dual: bits.1.map(T::from_bits) }
+    }
+}
@@ -19,11 +19,21 @@ macro_rules! impl_derivative {
 }
 use existant_core::{Addition, FloatingPoint, Identity};
 
+mod dual;
+pub use dual::*;
+
 pub trait Derivative {
     type Output;
     fn derive(&self) -> Self::Output; 
 }
 
+/// The antiderivative, with the constant of integration left at zero, so
+/// deriving it gives back the original.
+pub trait Integral {
+    type Output;
+    fn integrate(&self) -> Self::Output;
+}
+
 impl_derivative!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 
 #[repr(transparent)]
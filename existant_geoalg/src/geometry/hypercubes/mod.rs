@@ -1,5 +1,5 @@
 mod rect2d;
-use existant_core::BasicField;
+use existant_core::{Lattice, OrderedField, UniversalOperationsOn};
 pub use rect2d::*;
 
 use crate::vectors::Vector2;
@@ -16,9 +16,15 @@ pub trait BoundingBox {
     fn bounding_box(&self) -> Self::Boundary;
 }
 
-// impl<T: BasicField> BoundingBox for [Vector2<T>] {
-//     type Boundary = Rect2D<T>;
-//     fn bounding_box(&self) -> Self::Boundary {
-        
-//     }
-// }
\ No newline at end of file
+impl<T: OrderedField + UniversalOperationsOn<T>> BoundingBox for [Vector2<T>] {
+    type Boundary = Rect2D<T>;
+    /// The rect spanning the meet and join of every point, or a dimensionless
+    /// one at the origin if there aren't any.
+    fn bounding_box(&self) -> Self::Boundary {
+        let Some((&first, rest)) = self.split_first() else {
+            return Rect2D::dimensionless();
+        };
+        let (min, max) = rest.iter().fold((first, first), |(min, max), &point| (min.meet(point), max.join(point)));
+        Rect2D::new(min, max-min)
+    }
+}
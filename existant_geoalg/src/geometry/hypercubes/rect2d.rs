@@ -1,4 +1,4 @@
-use existant_core::{Addition, BasicField, Bounds, FloatingPoint, FromPrimitive, Identity, Semigroup, Semimodule, Semiring, UniversalOperationsOn};
+use existant_core::{Addition, ApproxEq, BasicField, Bounds, FloatingPoint, FromPrimitive, Identity, Semigroup, Semimodule, Semiring, Tolerance, UniversalOperationsOn};
 
 use crate::{animation::remap, geometry::{Centroid, HyperCube, LinearSegment2D, Shape, Sphere2D, VertexShape}, vectors::{NormedVectorSpace, Vector2}};
 
@@ -59,6 +59,8 @@ impl<T: Semigroup<Addition> +  UniversalOperationsOn<T>> Rect2D<T> {
         this.dimensions = self.max().max(point)-this.pos;
         this
     }
+    /// The smallest [`Rect2D`] covering both, which is the join of the two
+    /// in the lattice of rects ordered by containment.
     pub fn fit_rect(&self, rect: Rect2D<T>) -> Self 
         where T: BasicField {
         if self.is_dimensionless() {
@@ -67,7 +69,7 @@ impl<T: Semigroup<Addition> +  UniversalOperationsOn<T>> Rect2D<T> {
         if rect.is_dimensionless() {
             return *self;
         }
-        Self::from_bounds(self.pos.min(rect.min()), self.max().max(rect.max()))
+        Self::from_bounds(Bounds::min(self.pos, rect.min()), Bounds::max(self.max(), rect.max()))
     }
     pub fn is_point_inside(&self, point: Vector2<T>) -> bool 
         where T: PartialOrd {
@@ -164,4 +166,15 @@ impl<T: Semiring + UniversalOperationsOn<T> + FromPrimitive> Centroid for Rect2D
 }
 impl<T: BasicField + UniversalOperationsOn<T>> HyperCube for Rect2D<T> {
     
-}
\ No newline at end of file
+}
+
+impl<T: UniversalOperationsOn<T> + ApproxEq> ApproxEq for Rect2D<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.pos.approx_eq(&other.pos, tolerance) &&
+        self.dimensions.approx_eq(&other.dimensions, tolerance)
+    }
+}
@@ -1,4 +1,4 @@
-use existant_core::{BasicField, FloatingPoint, Identity, Multiplication, Semiring, UniversalOperationsOn};
+use existant_core::{ApproxEq, BasicField, FloatingPoint, Identity, Multiplication, Semiring, Tolerance, UniversalOperationsOn};
 
 use crate::{geometry::Shape, vectors::{MetricSpace, NormedVectorSpace, Vector2}};
 
@@ -39,3 +39,14 @@ impl<T: FloatingPoint + BasicField> Sphere2D<T> {
 impl<T: Semiring + FloatingPoint> Shape for Sphere2D<T> {
     type Vertex = Vector2<T>;
 }
+
+impl<T: FloatingPoint + ApproxEq> ApproxEq for Sphere2D<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.center.approx_eq(&other.center, tolerance) &&
+        self.radius.approx_eq(&other.radius, tolerance)
+    }
+}
@@ -1,6 +1,6 @@
 mod ray2d;
 mod ray3d;
-use existant_core::BasicField;
+use existant_core::{ApproxEq, BasicField, Tolerance};
 pub use ray2d::*;
 pub use ray3d::*;
 
@@ -15,4 +15,15 @@ pub struct RayIntersection<T> {
 pub trait Intersect<T> {
     type Scalar: BasicField;
     fn intersect(&self, with: &T) -> Option<RayIntersection<Self::Scalar>>;
-}
\ No newline at end of file
+}
+
+impl<T: ApproxEq> ApproxEq for RayIntersection<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.point.approx_eq(&other.point, tolerance) &&
+        self.distance.approx_eq(&other.distance, tolerance)
+    }
+}
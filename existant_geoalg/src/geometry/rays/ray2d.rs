@@ -1,4 +1,4 @@
-use existant_core::{Addition, BasicField, FloatingPoint, Identity, Multiplication, Semimodule, UniversalOperationsOn};
+use existant_core::{Addition, ApproxEq, BasicField, FloatingPoint, Identity, Multiplication, Semimodule, Tolerance, UniversalOperationsOn};
 
 use crate::{geometry::{Intersect, LinearSegment2D, RayIntersection, Rect2D}, vectors::{GrassmanAlgebra, InnerProductSpace, MetricSpace, NormedVectorSpace, Vector2}};
 
@@ -6,6 +6,7 @@ use crate::{geometry::{Intersect, LinearSegment2D, RayIntersection, Rect2D}, vec
 //     intersections: [Vector2<T>; 2],
 // }
 
+#[derive(Debug, Clone, Copy)]
 pub struct Ray2D<T> {
     dir: Vector2<T>,
     pos: Vector2<T>
@@ -58,7 +59,7 @@ impl<T: BasicField> Ray2D<T>
         // never intersect. When the dot product is zero, the
         // two vectors are perpendicular.
         let dot = segment_dir.inner_product(perp_ray);
-        if dot.abs() < T::from_f32(f32::EPSILON) {
+        if dot.abs() <= T::EPSILON {
             return None;
         }
 
@@ -203,5 +204,16 @@ impl<T: FloatingPoint + BasicField> Intersect<LinearSegment2D<T>> for Ray2D<T> {
     }
 }
 
+impl<T: ApproxEq> ApproxEq for Ray2D<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.dir.approx_eq(&other.dir, tolerance) &&
+        self.pos.approx_eq(&other.pos, tolerance)
+    }
+}
+
 pub type FRay2D = Ray2D<f32>;
 pub type DRay2D = Ray2D<f64>;
\ No newline at end of file
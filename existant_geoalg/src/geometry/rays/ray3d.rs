@@ -1,7 +1,8 @@
-use existant_core::{BasicField, Semimodule};
+use existant_core::{ApproxEq, BasicField, Semimodule, Tolerance};
 
 use crate::vectors::{NormedVectorSpace, Vector3};
 
+#[derive(Debug, Clone, Copy)]
 pub struct Ray3D<T> {
     pos: Vector3<T>,
     dir: Vector3<T>,
@@ -35,4 +36,15 @@ impl<T: BasicField> Ray3D<T>
     pub fn dir(&self) -> Vector3<T> {
         self.dir
     }
-}
\ No newline at end of file
+}
+
+impl<T: ApproxEq> ApproxEq for Ray3D<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.pos.approx_eq(&other.pos, tolerance) &&
+        self.dir.approx_eq(&other.dir, tolerance)
+    }
+}
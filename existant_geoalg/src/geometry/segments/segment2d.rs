@@ -1,4 +1,4 @@
-use existant_core::{BasicField, Bounds, FloatingPoint};
+use existant_core::{ApproxEq, BasicField, Bounds, FloatingPoint, Tolerance};
 
 use crate::{geometry::{PolygonGeometry, PolygonOrdering, Shape, VertexShape}, vectors::Vector2};
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -36,4 +36,14 @@ impl<T: FloatingPoint + BasicField> VertexShape  for LinearSegment2D<T> {
             Some(vec![1, 0])
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T: FloatingPoint + BasicField + ApproxEq> ApproxEq for LinearSegment2D<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.points.approx_eq(&other.points, tolerance)
+    }
+}
@@ -1,4 +1,4 @@
-use existant_core::{BasicField, VectorSpace};
+use existant_core::{ApproxEq, BasicField, Tolerance, VectorSpace};
 
 use crate::geometry::{PolygonGeometry, PolygonOrdering, VertexShape};
 
@@ -19,4 +19,16 @@ impl<V: VectorSpace> Shape2D<V>
     pub fn from_vertices(shape: impl VertexShape<Vertex = V>, ordering: PolygonOrdering, geometry: PolygonGeometry) -> Option<Self> {
         Some(Self { vertices: shape.vertices(), indices: shape.indices(ordering, geometry)? })
     }
-}
\ No newline at end of file
+}
+
+impl<V: VectorSpace + ApproxEq> ApproxEq for Shape2D<V> 
+    where V::Scalar: BasicField {
+    type Epsilon = V::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        V::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.indices == other.indices &&
+        self.vertices.approx_eq(&other.vertices, tolerance)
+    }
+}
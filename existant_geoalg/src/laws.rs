@@ -0,0 +1,122 @@
+//! [`Arbitrary`] and [`LawEq`] for the types in this crate, which draw and
+//! compare each component on its own.
+
+use existant_core::{laws::{Arbitrary, LawEq}, BasicField, Ring, Semiring};
+use rand::Rng;
+
+use crate::{matrix::{Matrix2x2, Matrix3x3, Matrix4x4}, rotors::{Complex, Quaternion}, vectors::{Vector2, Vector3, Vector4, VectorN}};
+
+impl<T: Arbitrary> Arbitrary for Vector2<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(T::arbitrary(rng), T::arbitrary(rng))
+    }
+}
+impl<T: LawEq> LawEq for Vector2<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.x.law_eq(&other.x) &&
+        self.y.law_eq(&other.y)
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vector3<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(T::arbitrary(rng), T::arbitrary(rng), T::arbitrary(rng))
+    }
+}
+impl<T: LawEq> LawEq for Vector3<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.x.law_eq(&other.x) &&
+        self.y.law_eq(&other.y) &&
+        self.z.law_eq(&other.z)
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vector4<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(T::arbitrary(rng), T::arbitrary(rng), T::arbitrary(rng), T::arbitrary(rng))
+    }
+}
+impl<T: LawEq> LawEq for Vector4<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.x.law_eq(&other.x) &&
+        self.y.law_eq(&other.y) &&
+        self.z.law_eq(&other.z) &&
+        self.w.law_eq(&other.w)
+    }
+}
+
+impl<T: Arbitrary, const N: usize> Arbitrary for VectorN<T, N> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_fn(|_| T::arbitrary(rng))
+    }
+}
+impl<T: LawEq, const N: usize> LawEq for VectorN<T, N> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a.law_eq(b))
+    }
+}
+
+impl<T: BasicField + Arbitrary> Arbitrary for Complex<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(T::arbitrary(rng), T::arbitrary(rng))
+    }
+}
+impl<T: BasicField + LawEq> LawEq for Complex<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.r().law_eq(&other.r()) &&
+        self.i().law_eq(&other.i())
+    }
+}
+
+impl<T: BasicField + Arbitrary> Arbitrary for Quaternion<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(T::arbitrary(rng), T::arbitrary(rng), T::arbitrary(rng), T::arbitrary(rng))
+    }
+}
+impl<T: BasicField + LawEq> LawEq for Quaternion<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.r().law_eq(&other.r()) &&
+        self.i().law_eq(&other.i()) &&
+        self.j().law_eq(&other.j()) &&
+        self.k().law_eq(&other.k())
+    }
+}
+
+impl<T: Semiring + Arbitrary> Arbitrary for Matrix2x2<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(Vector2::arbitrary(rng), Vector2::arbitrary(rng))
+    }
+}
+impl<T: Semiring + LawEq> LawEq for Matrix2x2<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.x.law_eq(&other.x) &&
+        self.y.law_eq(&other.y)
+    }
+}
+
+impl<T: Semiring + Arbitrary> Arbitrary for Matrix3x3<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(Vector3::arbitrary(rng), Vector3::arbitrary(rng), Vector3::arbitrary(rng))
+    }
+}
+impl<T: Semiring + LawEq> LawEq for Matrix3x3<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.x.law_eq(&other.x) &&
+        self.y.law_eq(&other.y) &&
+        self.z.law_eq(&other.z)
+    }
+}
+
+impl<T: Ring + Arbitrary> Arbitrary for Matrix4x4<T> {
+    fn arbitrary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(Vector4::arbitrary(rng), Vector4::arbitrary(rng), Vector4::arbitrary(rng), Vector4::arbitrary(rng))
+    }
+}
+impl<T: Ring + LawEq> LawEq for Matrix4x4<T> {
+    fn law_eq(&self, other: &Self) -> bool {
+        self.x.law_eq(&other.x) &&
+        self.y.law_eq(&other.y) &&
+        self.z.law_eq(&other.z) &&
+        self.w.law_eq(&other.w)
+    }
+}
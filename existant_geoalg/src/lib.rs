@@ -4,4 +4,11 @@ pub mod rotors;
 pub mod geometry;
 pub mod animation;
 pub mod mappings;
-pub mod derivative;
\ No newline at end of file
+pub mod derivative;
+pub mod polynomial;
+#[cfg(feature = "laws")]
+mod laws;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use existant_geoalg_macros::{Field, Group, Ring, VectorSpace};
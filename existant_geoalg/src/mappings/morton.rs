@@ -7,6 +7,7 @@ use crate::vectors::{U8Vec2, U16Vec2};
 
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct MortonU16(u16);
 impl Hash for MortonU16 {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
@@ -65,6 +66,7 @@ impl MortonU16 {
 
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct MortonU32(u32);
 impl Hash for MortonU32 {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
@@ -122,6 +124,7 @@ impl From<U16Vec2> for MortonU32 {
 }
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct MortonU64(u64);
 impl Hash for MortonU64 {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
@@ -0,0 +1,163 @@
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use existant_core::{Addition, ApproxEq, Groupoid, Identity, Multiplication, Operator, Semiring, Tolerance};
+
+use crate::matrix::Matrix4x4;
+
+/// A matrix whose size is only known at runtime, stored column by column
+/// like the fixed size matrices. Entries are indexed by `(row, column)`.
+///
+/// Like [`Matrix4x4`], its products can be taken over any [`Semiring`], so
+/// for shortest paths on a graph of any size:
+/// ```
+/// use existant_core::{Addition, Min, MinPlus};
+/// use existant_geoalg::matrix::DynamicMatrix;
+///
+/// let edges = [(0, 1, 4), (1, 2, 1), (0, 2, 7), (2, 3, 2)];
+/// let mut weights = DynamicMatrix::semiring_identity::<Addition, Min>(4);
+/// for (from, to, weight) in edges {
+///     weights[(from, to)] = MinPlus::new(weight);
+/// }
+/// let distances = weights.semiring_pow::<Addition, Min>(3);
+/// assert_eq!(distances[(0, 3)], MinPlus::new(7));
+/// assert!(distances[(3, 0)].is_zero());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynamicMatrix<T> {
+    rows: usize,
+    columns: usize,
+    entries: Vec<T>,
+}
+
+impl<T> DynamicMatrix<T> {
+    pub fn from_fn(rows: usize, columns: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let entries = (0..columns).flat_map(|column| (0..rows).map(move |row| (row, column))).map(|(row, column)| f(row, column)).collect();
+        Self { rows, columns, entries }
+    }
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+    pub const fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.rows && column < self.columns {
+            self.entries.get(column * self.rows + row)
+        } else {
+            None
+        }
+    }
+    /// The `index`th column.
+    pub fn column(&self, index: usize) -> &[T] {
+        &self.entries[index * self.rows..(index + 1) * self.rows]
+    }
+}
+
+impl<T: Clone> DynamicMatrix<T> {
+    pub fn filled(rows: usize, columns: usize, value: T) -> Self {
+        Self { rows, columns, entries: vec![value; rows * columns] }
+    }
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.columns, self.rows, |row, column| self[(column, row)].clone())
+    }
+}
+
+impl<T: Copy> DynamicMatrix<T> {
+    /// The `size` by `size` matrix with the identity of `DistributiveOp` down
+    /// the diagonal, and the identity of `Op` everywhere else.
+    pub fn semiring_identity<DistributiveOp: Operator, Op: Operator>(size: usize) -> Self
+        where T: Semiring<DistributiveOp, Op> {
+        Self::from_fn(size, size, |row, column| {
+            if row == column {
+                <T as Identity<DistributiveOp>>::IDENTITY
+            } else {
+                <T as Identity<Op>>::IDENTITY
+            }
+        })
+    }
+    /// # Panics
+    /// When the number of columns in `self` isn't the number of rows in `rhs`.
+    pub fn semiring_product<DistributiveOp: Operator, Op: Operator>(&self, rhs: &Self) -> Self
+        where T: Semiring<DistributiveOp, Op> {
+        assert_eq!(self.columns, rhs.rows, "can't multiply a {}x{} matrix by a {}x{} one", self.rows, self.columns, rhs.rows, rhs.columns);
+        Self::from_fn(self.rows, rhs.columns, |row, column| {
+            (0..self.columns)
+                .map(|k| <T as Groupoid<DistributiveOp>>::op(&self[(row, k)], &rhs[(k, column)]))
+                .fold(<T as Identity<Op>>::IDENTITY, |sum, term| <T as Groupoid<Op>>::op(&sum, &term))
+        })
+    }
+    /// Raises the matrix to the `exponent`th power by squaring. For the
+    /// shortest paths on `n` vertices, `exponent` `n - 1` is enough.
+    ///
+    /// # Panics
+    /// When the matrix isn't square.
+    pub fn semiring_pow<DistributiveOp: Operator, Op: Operator>(&self, mut exponent: u32) -> Self
+        where T: Semiring<DistributiveOp, Op> {
+        assert!(self.is_square(), "can't raise a {}x{} matrix to a power", self.rows, self.columns);
+        let mut result = Self::semiring_identity::<DistributiveOp, Op>(self.rows);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.semiring_product::<DistributiveOp, Op>(&base);
+            }
+            base = base.semiring_product::<DistributiveOp, Op>(&base);
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl<T> Index<(usize, usize)> for DynamicMatrix<T> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && column < self.columns, "({row}, {column}) is outside of a {}x{} matrix", self.rows, self.columns);
+        &self.entries[column * self.rows + row]
+    }
+}
+impl<T> IndexMut<(usize, usize)> for DynamicMatrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && column < self.columns, "({row}, {column}) is outside of a {}x{} matrix", self.rows, self.columns);
+        &mut self.entries[column * self.rows + row]
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Add for DynamicMatrix<T> {
+    type Output = Self;
+    /// # Panics
+    /// When the matrices aren't the same size.
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(self.rows == rhs.rows && self.columns == rhs.columns, "can't add a {}x{} matrix to a {}x{} one", self.rows, self.columns, rhs.rows, rhs.columns);
+        let entries = self.entries.iter().zip(&rhs.entries).map(|(a, b)| *a + *b).collect();
+        Self { entries, ..self }
+    }
+}
+/// The usual product, over [`Multiplication`] and [`Addition`].
+impl<T: Semiring + Copy> Mul for DynamicMatrix<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.semiring_product::<Multiplication, Addition>(&rhs)
+    }
+}
+
+impl<T: Copy> From<Matrix4x4<T>> for DynamicMatrix<T> {
+    fn from(value: Matrix4x4<T>) -> Self {
+        let columns = [value.x, value.y, value.z, value.w];
+        Self::from_fn(4, 4, |row, column| columns[column][row])
+    }
+}
+
+/// Matrices of different sizes are never equal.
+impl<T: ApproxEq> ApproxEq for DynamicMatrix<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.rows == other.rows &&
+        self.columns == other.columns &&
+        self.entries.approx_eq(&other.entries, tolerance)
+    }
+}
@@ -1,7 +1,7 @@
 use core::ops::Index;
 
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Addition, AssociativeOver, ClosedUnder, CommutativeOver, Identity, Inverse, Multiplication, Ring, Semimodule, Semiring, Subtraction};
+use existant_core::{Addition, ApproxEq, AssociativeOver, ClosedUnder, CommutativeOver, Identity, Inverse, Multiplication, Ring, Semimodule, Semiring, Subtraction, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 use crate::{matrix::{Matrix, Matrix3x2, SquareMatrix}, vectors::Vector2};
 
@@ -23,7 +23,7 @@ pub struct Matrix2x2<T: Semiring> {
 unsafe impl<T: Semiring + Zeroable> Zeroable for Matrix2x2<T> {
     
 }
-unsafe impl<T: Semiring + Zeroable + 'static> Pod for Matrix2x2<T> {
+unsafe impl<T: Semiring + Pod> Pod for Matrix2x2<T> {
     
 }
 
@@ -216,6 +216,17 @@ impl<T: Semiring> Identity<Addition> for Matrix2x2<T> {
     }
 }
 
+impl<T: Semiring + ApproxEq> ApproxEq for Matrix2x2<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance)
+    }
+}
+
 pub type I8Mat2 = Matrix2x2<i8>;
 pub type I16Mat2 = Matrix2x2<i16>;
 pub type I32Mat2 = Matrix2x2<i32>;
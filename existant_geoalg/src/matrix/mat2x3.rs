@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Ring, Semiring};
+use existant_core::{ApproxEq, Ring, Semiring, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 
 use crate::{matrix::{Matrix, Matrix3x3, Matrix2x2, Matrix3x2}, vectors::{Vector2, Vector3}};
@@ -25,7 +25,7 @@ pub struct Matrix2x3<T: Semiring> {
 unsafe impl<T: Semiring + Zeroable> Zeroable for Matrix2x3<T> { 
     
 }
-unsafe impl<T: Semiring + Zeroable + 'static> Pod for Matrix2x3<T> {
+unsafe impl<T: Semiring + Pod> Pod for Matrix2x3<T> {
     
 }
 
@@ -101,6 +101,17 @@ impl<T: Semiring> Matrix2x3<T> {
     }
 }
 
+impl<T: Semiring + ApproxEq> ApproxEq for Matrix2x3<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance)
+    }
+}
+
 pub type I8Mat2x3 = Matrix2x3<i8>;
 pub type I16Mat2x3 = Matrix2x3<i16>;
 pub type I32Mat2x3 = Matrix2x3<i32>;
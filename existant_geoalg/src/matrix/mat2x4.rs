@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Ring, Semiring};
+use existant_core::{ApproxEq, Ring, Semiring, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 
 use crate::{matrix::{Matrix, Matrix4x2, Matrix4x4}, vectors::{Vector2, Vector4}};
@@ -23,7 +23,7 @@ pub struct Matrix2x4<T: Semiring> {
 unsafe impl<T: Semiring + Zeroable> Zeroable for Matrix2x4<T> {
     
 }
-unsafe impl<T: Semiring + Zeroable + 'static> Pod for Matrix2x4<T> {
+unsafe impl<T: Semiring + Pod> Pod for Matrix2x4<T> {
     
 }
 
@@ -49,6 +49,17 @@ impl<T: Semiring> Matrix2x4<T>  {
     }
 }
 
+impl<T: Semiring + ApproxEq> ApproxEq for Matrix2x4<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance)
+    }
+}
+
 pub type I8Mat2x4 = Matrix2x4<i8>;
 pub type I16Mat2x4 = Matrix2x4<i16>;
 pub type I32Mat2x4 = Matrix2x4<i32>;
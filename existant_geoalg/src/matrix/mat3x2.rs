@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Addition, Field, Identity, Multiplication, Ring, Semiring};
+use existant_core::{Addition, ApproxEq, Field, Identity, Multiplication, Ring, Semiring, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 
 use crate::{matrix::{Matrix, Matrix2x2, Matrix2x3, Matrix3x3, SolveEquations, SquareMatrix}, vectors::{Vector2, Vector3}};
@@ -24,7 +24,7 @@ pub struct Matrix3x2<T: Semiring> {
 unsafe impl<T: Semiring + Zeroable> Zeroable for Matrix3x2<T> {
     
 }
-unsafe impl<T: Semiring + Zeroable + 'static> Pod for Matrix3x2<T> {
+unsafe impl<T: Semiring + Pod> Pod for Matrix3x2<T> {
     
 }
 
@@ -125,6 +125,18 @@ impl<T: Semiring> Matrix3x2<T> {
     }
 }
 
+impl<T: Semiring + ApproxEq> ApproxEq for Matrix3x2<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance)
+    }
+}
+
 pub type I8Mat3x2 = Matrix3x2<i8>;
 pub type I16Mat3x2 = Matrix3x2<i16>;
 pub type I32Mat3x2 = Matrix3x2<i32>;
@@ -1,7 +1,7 @@
 use core::ops::Index;
 
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Addition, AssociativeOver, ClosedUnder, CommutativeOver, Identity, Inverse, Multiplication, Ring, Semimodule, Semiring, Subtraction};
+use existant_core::{Addition, ApproxEq, AssociativeOver, ClosedUnder, CommutativeOver, Identity, Inverse, Multiplication, Ring, Semimodule, Semiring, Subtraction, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 
 use crate::{matrix::{Matrix, Matrix2x2, Matrix2x3, SquareMatrix}, vectors::Vector3};
@@ -27,7 +27,7 @@ pub struct Matrix3x3<T: Semiring> {
 unsafe impl<T: Semiring + Zeroable> Zeroable for Matrix3x3<T> {
     
 }
-unsafe impl<T: Semiring + Zeroable + 'static> Pod for Matrix3x3<T> {
+unsafe impl<T: Semiring + Pod> Pod for Matrix3x3<T> {
     
 }
 
@@ -237,6 +237,18 @@ impl<T: Semiring> Identity<Addition> for Matrix3x3<T> {
     }
 }
 
+impl<T: Semiring + ApproxEq> ApproxEq for Matrix3x3<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance)
+    }
+}
+
 pub type I8Mat3 = Matrix3x3<i8>;
 pub type I16Mat3 = Matrix3x3<i16>;
 pub type I32Mat3 = Matrix3x3<i32>;
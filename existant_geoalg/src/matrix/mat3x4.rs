@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Ring, Semiring};
+use existant_core::{ApproxEq, Ring, Semiring, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 
 use crate::{matrix::{Matrix, Matrix2x3, Matrix2x4}, vectors::{Vector3, Vector4}};
@@ -26,7 +26,7 @@ pub struct Matrix3x4<T: Semiring> {
 unsafe impl<T: Semiring + Zeroable> Zeroable for Matrix3x4<T> {
     
 }
-unsafe impl<T: Semiring + Zeroable + 'static> Pod for Matrix3x4<T> {
+unsafe impl<T: Semiring + Pod> Pod for Matrix3x4<T> {
     
 }
 
@@ -132,6 +132,18 @@ impl<T: Semiring> Matrix3x4<T>  {
 }
 
 
+impl<T: Semiring + ApproxEq> ApproxEq for Matrix3x4<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance)
+    }
+}
+
 pub type I8Mat3x4 = Matrix3x4<i8>;
 pub type I16Mat3x4 = Matrix3x4<i16>;
 pub type I32Mat3x4 = Matrix3x4<i32>;
@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use existant_core::Ring;
+use existant_core::{ApproxEq, Ring, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 use crate::{matrix::{Matrix, Matrix2x4, Matrix4x4}, vectors::{Vector2, Vector4}};
 
@@ -24,7 +24,7 @@ pub struct Matrix4x2<T: Ring> {
 unsafe impl<T: Ring + Zeroable> Zeroable for Matrix4x2<T> {
     
 }
-unsafe impl<T: Ring + Zeroable + 'static> Pod for Matrix4x2<T> {
+unsafe impl<T: Ring + Pod> Pod for Matrix4x2<T> {
     
 }
 
@@ -117,6 +117,19 @@ impl<T: Ring> Matrix4x2<T>  {
     }
 }
 
+impl<T: Ring + ApproxEq> ApproxEq for Matrix4x2<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance) &&
+        self.w.approx_eq(&other.w, tolerance)
+    }
+}
+
 pub type I8Mat4x2 = Matrix4x2<i8>;
 pub type I16Mat4x2 = Matrix4x2<i16>;
 pub type I32Mat4x2 = Matrix4x2<i32>;
@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Addition, Field, Identity, Multiplication, Ring};
+use existant_core::{Addition, ApproxEq, Field, Identity, Multiplication, Ring, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 use crate::{matrix::{Matrix, Matrix3x3, Matrix3x4, Matrix4x4, SolveEquations, SquareMatrix}, vectors::{Vector3, Vector4}};
 
@@ -25,7 +25,7 @@ pub struct Matrix4x3<T: Ring> {
 unsafe impl<T: Ring + Zeroable> Zeroable for Matrix4x3<T> {
     
 }
-unsafe impl<T: Ring + Zeroable + 'static> Pod for Matrix4x3<T> {
+unsafe impl<T: Ring + Pod> Pod for Matrix4x3<T> {
     
 }
 
@@ -164,6 +164,19 @@ impl<T: Ring> Matrix4x3<T>  {
     }
 }
 
+impl<T: Ring + ApproxEq> ApproxEq for Matrix4x3<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance) &&
+        self.w.approx_eq(&other.w, tolerance)
+    }
+}
+
 pub type I8Mat4x3 = Matrix4x3<i8>;
 pub type I16Mat4x3 = Matrix4x3<i16>;
 pub type I32Mat4x3 = Matrix4x3<i32>;
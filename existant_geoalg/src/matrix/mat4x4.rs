@@ -2,7 +2,7 @@ use core::ops::Index;
 
 
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Addition, AssociativeOver, BasicField, ClosedUnder, CommutativeOver, FloatingPoint, FromPrimitive, Identity, Inverse, Multiplication, Ring, Semimodule, Subtraction};
+use existant_core::{const_math, Addition, ApproxEq, AssociativeOver, BasicField, ClosedUnder, CommutativeOver, FloatingPoint, FromPrimitive, Groupoid, Identity, Inverse, Multiplication, Operator, Ring, Semimodule, Semiring, Subtraction, Tolerance};
 use existant_geoalg_macros::matrix_multiplication;
 
 use crate::{matrix::{Matrix, Matrix2x4, Matrix3x3, Matrix3x4, SquareMatrix}, vectors::{InnerProductSpace, Vector3, Vector4}};
@@ -19,7 +19,7 @@ use crate::{matrix::{Matrix, Matrix2x4, Matrix3x3, Matrix3x4, SquareMatrix}, vec
 #[matrix_multiplication(columns(x, y, z), self_rows(x, y, z, w), ty(Matrix3x4), output(Matrix3x4))]
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Default)]
-pub struct Matrix4x4<T: Ring> {
+pub struct Matrix4x4<T> {
     /// First column of the matrix
     pub x: Vector4<T>,
     /// Second column of the matrix
@@ -32,7 +32,7 @@ pub struct Matrix4x4<T: Ring> {
 unsafe impl<T: Ring + Zeroable> Zeroable for Matrix4x4<T> {
     
 }
-unsafe impl<T: Ring + Zeroable + 'static> Pod for Matrix4x4<T> {
+unsafe impl<T: Ring + Pod> Pod for Matrix4x4<T> {
     
 }
 
@@ -200,10 +200,57 @@ impl<T: Ring + core::ops::Mul<Output = T>> Matrix for Matrix4x4<T> {
     }
 }
 
-impl<T: Ring> Matrix4x4<T> {
+impl<T> Matrix4x4<T> {
     pub const fn new(x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T>) -> Self {
         Self { x, y, z, w }
     }
+}
+
+/// Products over any [`Semiring`], not just the one `*` uses. Over
+/// `Semiring<Addition, Min>`, such as [`MinPlus`](existant_core::MinPlus),
+/// every entry becomes the cheapest way of going through one more vertex.
+impl<T: Copy> Matrix4x4<T> {
+    /// The identity of `DistributiveOp` down the diagonal, and the identity
+    /// of `Op` everywhere else.
+    pub fn semiring_identity<DistributiveOp: Operator, Op: Operator>() -> Self 
+        where T: Semiring<DistributiveOp, Op> {
+        let one = <T as Identity<DistributiveOp>>::IDENTITY;
+        let zero = <T as Identity<Op>>::IDENTITY;
+        Self::new(
+            Vector4::new(one, zero, zero, zero), 
+            Vector4::new(zero, one, zero, zero), 
+            Vector4::new(zero, zero, one, zero), 
+            Vector4::new(zero, zero, zero, one)
+        )
+    }
+    pub fn semiring_product<DistributiveOp: Operator, Op: Operator>(&self, rhs: &Self) -> Self 
+        where T: Semiring<DistributiveOp, Op> {
+        let lhs = [self.x, self.y, self.z, self.w];
+        let entry = |column: &Vector4<T>, row: usize| {
+            let term = |k: usize| <T as Groupoid<DistributiveOp>>::op(&lhs[k][row], &column[k]);
+            (1..4).fold(term(0), |sum, k| <T as Groupoid<Op>>::op(&sum, &term(k)))
+        };
+        let column = |column: &Vector4<T>| Vector4::new(entry(column, 0), entry(column, 1), entry(column, 2), entry(column, 3));
+        Self::new(column(&rhs.x), column(&rhs.y), column(&rhs.z), column(&rhs.w))
+    }
+    /// Raises the matrix to the `exponent`th power by squaring. For the
+    /// shortest paths on 4 vertices, `exponent` 3 is enough.
+    pub fn semiring_pow<DistributiveOp: Operator, Op: Operator>(&self, mut exponent: u32) -> Self 
+        where T: Semiring<DistributiveOp, Op> {
+        let mut result = Self::semiring_identity::<DistributiveOp, Op>();
+        let mut base = *self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.semiring_product::<DistributiveOp, Op>(&base);
+            }
+            base = base.semiring_product::<DistributiveOp, Op>(&base);
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl<T: Ring> Matrix4x4<T> {
     /// When dealing with linear transformations such as these, the
     /// order in which you multiply matters. In this case, if you
     /// want to translate an object, after using this method, then
@@ -333,12 +380,12 @@ impl<T: Ring> Matrix4x4<T> {
     }
     pub fn as_slice(&self) -> &[Vector4<T>] {
         unsafe { 
-            core::slice::from_raw_parts(self as *const _ as _, 3) 
+            core::slice::from_raw_parts(self as *const _ as _, 4) 
         }
     }
     pub fn as_mut_slice(&mut self) -> &mut [Vector4<T>] {
         unsafe { 
-            core::slice::from_raw_parts_mut(self as *mut _ as _, 3) 
+            core::slice::from_raw_parts_mut(self as *mut _ as _, 4) 
         }
     }
     pub fn derivative_matrix() -> Self 
@@ -351,6 +398,46 @@ impl<T: Ring> Matrix4x4<T> {
         )
     }
 }
+
+macro_rules! impl_const_rotations {
+    ($($float:ty, $sin_cos:path);*) => {
+        $(
+            /// Rotations that can be built in a `const` or `static`, turning
+            /// counterclockwise when looking down the axis towards the origin.
+            impl Matrix4x4<$float> {
+                pub const fn from_rotation_x(radians: $float) -> Self {
+                    let (s, c) = $sin_cos(radians);
+                    Self::new(
+                        Vector4::new(1.0, 0.0, 0.0, 0.0),
+                        Vector4::new(0.0, c, s, 0.0),
+                        Vector4::new(0.0, -s, c, 0.0),
+                        Vector4::new(0.0, 0.0, 0.0, 1.0),
+                    )
+                }
+                pub const fn from_rotation_y(radians: $float) -> Self {
+                    let (s, c) = $sin_cos(radians);
+                    Self::new(
+                        Vector4::new(c, 0.0, -s, 0.0),
+                        Vector4::new(0.0, 1.0, 0.0, 0.0),
+                        Vector4::new(s, 0.0, c, 0.0),
+                        Vector4::new(0.0, 0.0, 0.0, 1.0),
+                    )
+                }
+                pub const fn from_rotation_z(radians: $float) -> Self {
+                    let (s, c) = $sin_cos(radians);
+                    Self::new(
+                        Vector4::new(c, s, 0.0, 0.0),
+                        Vector4::new(-s, c, 0.0, 0.0),
+                        Vector4::new(0.0, 0.0, 1.0, 0.0),
+                        Vector4::new(0.0, 0.0, 0.0, 1.0),
+                    )
+                }
+            }
+        )*
+    };
+}
+impl_const_rotations!(f32, const_math::sin_cosf; f64, const_math::sin_cos);
+
 impl<T: Ring + ClosedUnder<Addition>> ClosedUnder<Addition> for Matrix4x4<T> {
     
 }
@@ -410,6 +497,19 @@ impl<T: Ring> Identity<Addition> for Matrix4x4<T> {
     }
 }
 
+impl<T: ApproxEq> ApproxEq for Matrix4x4<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance) &&
+        self.w.approx_eq(&other.w, tolerance)
+    }
+}
+
 pub type I8Mat4 = Matrix4x4<i8>;
 pub type I16Mat4 = Matrix4x4<i16>;
 pub type I32Mat4 = Matrix4x4<i32>;
@@ -421,4 +521,6 @@ pub type U32Mat4 = Matrix4x4<u32>;
 pub type U64Mat4 = Matrix4x4<u64>;
 pub type U128Mat4 = Matrix4x4<u128>;
 pub type FMat4 = Matrix4x4<f32>;
-pub type DMat4 = Matrix4x4<f64>;
\ No newline at end of file
+pub type DMat4 = Matrix4x4<f64>;
+#[cfg(feature = "half")]
+pub type HMat4 = Matrix4x4<existant_core::f16>;
\ No newline at end of file
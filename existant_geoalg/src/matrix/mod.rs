@@ -7,7 +7,10 @@ mod mat2x4;
 mod mat4x2;
 mod mat4x3;
 mod mat4x4;
-use existant_core::{Module, Ring, Semimodule, Semiring};
+mod dynamic;
+use existant_core::{Addition, Identity, Inverse, InvertibleLinearMap, LinearMap, Module, Multiplication, Ring, Semimodule, Semiring};
+
+use crate::vectors::{Vector2, Vector3, Vector4};
 pub use mat2x2::*;
 pub use mat3x2::*;
 pub use mat2x3::*;
@@ -17,6 +20,7 @@ pub use mat2x4::*;
 pub use mat4x2::*;
 pub use mat4x3::*;
 pub use mat4x4::*;
+pub use dynamic::*;
 
 /// Represents an NxM matrix.
 pub trait Matrix 
@@ -61,4 +65,55 @@ impl<M: Matrix> MatrixRing for M
 /// can apply to those matrices which are (n+1)xn.
 pub trait SolveEquations: Sized {
     fn solve_system(&self) -> Option<Self>;
-}
\ No newline at end of file
+}
+/// Every matrix is the [`LinearMap`] taking a vector with as many components
+/// as it has columns to the sum of its columns scaled by them.
+macro_rules! impl_linear_map {
+    ($($matrix:ident: $domain:ident -> $codomain:ident ($($column:ident),*)),*) => {
+        $(
+            impl<T: Ring + core::ops::Add<Output = T>> LinearMap<$domain<T>, $codomain<T>> for $matrix<T> {
+                fn apply(&self, v: $domain<T>) -> $codomain<T> {
+                    let mut image = <$codomain<T> as Identity<Addition>>::IDENTITY;
+                    $(
+                        image = image + <$codomain<T> as Semimodule>::scalar_multiplication(&self.$column, v.$column);
+                    )*
+                    image
+                }
+            }
+        )*
+    };
+}
+
+/// The square matrices undo themselves with their inverse, which only
+/// exists when the determinant isn't zero.
+macro_rules! impl_invertible_linear_map {
+    ($($matrix:ident: $vector:ident),*) => {
+        $(
+            impl<T: Ring + core::ops::Add<Output = T>> InvertibleLinearMap<$vector<T>, $vector<T>> for $matrix<T>
+                where Self: SquareMatrix<Vector = $vector<T>> + Inverse<Multiplication> + Copy {
+                type Inverse = Self;
+                fn invert(&self) -> Option<Self> {
+                    if <T as Identity<Addition>>::is_identity(&self.determinant()) {
+                        None
+                    } else {
+                        Some(Inverse::<Multiplication>::inverse(*self))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_invertible_linear_map!(Matrix2x2: Vector2, Matrix3x3: Vector3, Matrix4x4: Vector4);
+
+impl_linear_map!(
+    Matrix2x2: Vector2 -> Vector2 (x, y),
+    Matrix2x3: Vector2 -> Vector3 (x, y),
+    Matrix2x4: Vector2 -> Vector4 (x, y),
+    Matrix3x2: Vector3 -> Vector2 (x, y, z),
+    Matrix3x3: Vector3 -> Vector3 (x, y, z),
+    Matrix3x4: Vector3 -> Vector4 (x, y, z),
+    Matrix4x2: Vector4 -> Vector2 (x, y, z, w),
+    Matrix4x3: Vector4 -> Vector3 (x, y, z, w),
+    Matrix4x4: Vector4 -> Vector4 (x, y, z, w)
+);
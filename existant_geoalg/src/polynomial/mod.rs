@@ -0,0 +1,259 @@
+mod roots;
+
+use existant_core::{Addition, ApproxEq, Field, Groupoid, Identity, Inverse, Multiplication, Ring, Tolerance};
+
+use crate::{derivative::{Derivative, Integral}, vectors::{Vector2, Vector3, Vector4}};
+
+/// A polynomial `c₀ + c₁x + c₂x² + ...` of any degree over a [`Ring`], stored
+/// lowest degree first. The coefficients never end in zeros, so the zero
+/// polynomial has none and no degree.
+///
+/// Division and [`gcd`](Polynomial::gcd) need a [`Field`], and are exact for
+/// types like `Rational` and `Gf`. Over floats the remainders pick up rounding
+/// error, and root finding is the better tool.
+/// ```
+/// use existant_geoalg::{derivative::Derivative, polynomial::Polynomial};
+///
+/// // (x - 1)(x - 2)(x + 3) = x³ - 7x + 6
+/// let p = Polynomial::new([6.0, -7.0, 0.0, 1.0]);
+/// assert_eq!(p.evaluate(2.0), 0.0);
+/// assert_eq!(p.derive(), Polynomial::new([-7.0, 0.0, 3.0]));
+/// assert_eq!(p.real_roots(), [-3.0, 1.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<T: Ring> {
+    coefficients: Vec<T>,
+}
+
+fn add<T: Ring>(a: T, b: T) -> T {
+    <T as Groupoid<Addition>>::op(&a, &b)
+}
+fn mul<T: Ring>(a: T, b: T) -> T {
+    <T as Groupoid<Multiplication>>::op(&a, &b)
+}
+fn neg<T: Ring>(a: T) -> T {
+    <T as Inverse<Addition>>::inverse(a)
+}
+/// `value` added to itself `times` times, which is the only way a ring has of
+/// multiplying by an integer.
+fn times<T: Ring>(value: T, mut times: usize) -> T {
+    let mut result = <T as Identity<Addition>>::IDENTITY;
+    let mut doubled = value;
+    while times > 0 {
+        if times & 1 == 1 {
+            result = add(result, doubled);
+        }
+        doubled = add(doubled, doubled);
+        times >>= 1;
+    }
+    result
+}
+
+impl<T: Ring> Polynomial<T> {
+    /// Takes the coefficients lowest degree first, and drops any zeros at the
+    /// end.
+    pub fn new(coefficients: impl Into<Vec<T>>) -> Self {
+        let mut coefficients = coefficients.into();
+        while coefficients.last().is_some_and(|c| <T as Identity<Addition>>::is_identity(c)) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+    pub const fn zero() -> Self {
+        Self { coefficients: Vec::new() }
+    }
+    pub fn constant(value: T) -> Self {
+        Self::new([value])
+    }
+    /// `coefficient` xⁿ, where n is `degree`.
+    pub fn monomial(coefficient: T, degree: usize) -> Self {
+        let mut coefficients = vec![<T as Identity<Addition>>::IDENTITY; degree + 1];
+        coefficients[degree] = coefficient;
+        Self::new(coefficients)
+    }
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+    /// The coefficient of xⁿ, which is zero past the degree.
+    pub fn coefficient(&self, n: usize) -> T {
+        self.coefficients.get(n).copied().unwrap_or(<T as Identity<Addition>>::IDENTITY)
+    }
+    /// [`None`] for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+    pub fn leading_coefficient(&self) -> Option<T> {
+        self.coefficients.last().copied()
+    }
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+    /// Evaluates the polynomial at `x` by Horner's method.
+    pub fn evaluate(&self, x: T) -> T {
+        self.coefficients.iter().rev().fold(<T as Identity<Addition>>::IDENTITY, |result, c| add(mul(result, x), *c))
+    }
+    /// `self(other(x))`.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.coefficients.iter().rev().fold(Self::zero(), |result, c| result*other.clone() + Self::constant(*c))
+    }
+    /// The quotient and remainder of dividing by `divisor`, where the
+    /// remainder has a lower degree than `divisor`.
+    ///
+    /// # Panics
+    /// When `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self)
+        where T: Field {
+        let divisor_degree = divisor.degree().expect("can't divide by the zero polynomial");
+        let Some(quotient_len) = self.coefficients.len().checked_sub(divisor_degree) else {
+            return (Self::zero(), self.clone());
+        };
+        let leading_inverse = <T as Inverse<Multiplication>>::inverse(divisor.coefficients[divisor_degree]);
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![<T as Identity<Addition>>::IDENTITY; quotient_len];
+        for i in (0..quotient_len).rev() {
+            let factor = mul(remainder[i + divisor_degree], leading_inverse);
+            quotient[i] = factor;
+            for (j, d) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] = add(remainder[i + j], neg(mul(factor, *d)));
+            }
+        }
+        // the top of the remainder cancels out by construction, even when
+        // rounding says otherwise
+        remainder.truncate(divisor_degree);
+        (Self::new(quotient), Self::new(remainder))
+    }
+    /// Scales the polynomial so its leading coefficient is one.
+    pub fn monic(&self) -> Self
+        where T: Field {
+        match self.leading_coefficient() {
+            Some(leading) => self.clone()*<T as Inverse<Multiplication>>::inverse(leading),
+            None => Self::zero(),
+        }
+    }
+    /// The monic greatest common divisor, by Euclid's algorithm.
+    pub fn gcd(&self, other: &Self) -> Self
+        where T: Field {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = a % b.clone();
+            a = b;
+            b = remainder;
+        }
+        a.monic()
+    }
+}
+
+impl<T: Ring> Default for Polynomial<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Ring> core::ops::Add for Polynomial<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Self::new((0..len).map(|n| add(self.coefficient(n), rhs.coefficient(n))).collect::<Vec<_>>())
+    }
+}
+impl<T: Ring> core::ops::Neg for Polynomial<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { coefficients: self.coefficients.into_iter().map(neg).collect() }
+    }
+}
+impl<T: Ring> core::ops::Sub for Polynomial<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl<T: Ring> core::ops::Mul for Polynomial<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let mut coefficients = vec![<T as Identity<Addition>>::IDENTITY; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] = add(coefficients[i + j], mul(*a, *b));
+            }
+        }
+        Self::new(coefficients)
+    }
+}
+impl<T: Ring> core::ops::Mul<T> for Polynomial<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.coefficients.into_iter().map(|c| mul(c, rhs)).collect::<Vec<_>>())
+    }
+}
+/// The quotient of [`Polynomial::div_rem`].
+impl<T: Field> core::ops::Div for Polynomial<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+/// The remainder of [`Polynomial::div_rem`].
+impl<T: Field> core::ops::Rem for Polynomial<T> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<T: Ring> Derivative for Polynomial<T> {
+    type Output = Self;
+    fn derive(&self) -> Self::Output {
+        Self::new(self.coefficients.iter().enumerate().skip(1).map(|(n, c)| times(*c, n)).collect::<Vec<_>>())
+    }
+}
+impl<T: Field> Integral for Polynomial<T> {
+    type Output = Self;
+    /// # Panics
+    /// When `n` is zero in `T` for some `n` up to the degree plus one, which
+    /// only happens in fields like `Gf` whose characteristic is that small.
+    fn integrate(&self) -> Self::Output {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        let mut coefficients = vec![<T as Identity<Addition>>::IDENTITY];
+        coefficients.extend(self.coefficients.iter().enumerate().map(|(n, c)| {
+            let divisor = times(one, n + 1);
+            assert!(!<T as Identity<Addition>>::is_identity(&divisor), "can't divide by {} in this field", n + 1);
+            mul(*c, <T as Inverse<Multiplication>>::inverse(divisor))
+        }));
+        Self::new(coefficients)
+    }
+}
+
+/// Coefficients past the degree of either side count as zero, so a leftover
+/// term that rounding kept from cancelling out doesn't make them unequal.
+impl<T: Ring + ApproxEq> ApproxEq for Polynomial<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        let len = self.coefficients.len().max(other.coefficients.len());
+        (0..len).all(|n| self.coefficient(n).approx_eq(&other.coefficient(n), tolerance))
+    }
+}
+
+/// The vectors as the polynomials [`Derivative`] takes them to be, with `x`
+/// as the constant term.
+impl<T: Ring> From<Vector2<T>> for Polynomial<T> {
+    fn from(value: Vector2<T>) -> Self {
+        Self::new([value.x, value.y])
+    }
+}
+impl<T: Ring> From<Vector3<T>> for Polynomial<T> {
+    fn from(value: Vector3<T>) -> Self {
+        Self::new([value.x, value.y, value.z])
+    }
+}
+impl<T: Ring> From<Vector4<T>> for Polynomial<T> {
+    fn from(value: Vector4<T>) -> Self {
+        Self::new([value.x, value.y, value.z, value.w])
+    }
+}
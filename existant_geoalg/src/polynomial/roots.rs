@@ -0,0 +1,200 @@
+use existant_core::{Addition, BasicField, FloatingPoint, Identity, Multiplication};
+
+use crate::{polynomial::Polynomial, rotors::Complex};
+
+/// How many times Aberth's method gets to refine its guesses before it gives
+/// up and returns them as they are.
+const ABERTH_ITERATIONS: usize = 500;
+
+impl<T: BasicField + FloatingPoint> Polynomial<T> {
+    /// Every root, as many times as it repeats, in no particular order. Up to
+    /// degree 4 the roots come from the closed form solutions, and past that
+    /// from Aberth's method, which finds all of them at once.
+    ///
+    /// Roots that repeat are badly conditioned, so a double root can come
+    /// out as two roots around `√ε` apart.
+    pub fn complex_roots(&self) -> Vec<Complex<T>> {
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        // zero roots factor out exactly
+        let zeros = self.coefficients.iter().take_while(|c| **c == zero).count();
+        let coefficients = &self.coefficients[zeros..];
+        let mut roots = vec![Complex::new(zero, zero); zeros];
+        match *coefficients {
+            [] | [_] => {},
+            [b, a] => roots.push(Complex::new(-b/a, zero)),
+            [c, b, a] => roots.extend(quadratic(a, b, c)),
+            [d, c, b, a] => roots.extend(polish(coefficients, cubic(a, b, c, d))),
+            [e, d, c, b, a] => roots.extend(polish(coefficients, quartic(a, b, c, d, e))),
+            _ => roots.extend(aberth(coefficients)),
+        }
+        roots
+    }
+    /// The roots of [`Polynomial::complex_roots`] whose imaginary part is small
+    /// enough to be rounding error, smallest first.
+    pub fn real_roots(&self) -> Vec<T> {
+        let one = <T as Identity<Multiplication>>::IDENTITY;
+        let threshold = T::EPSILON.sqrt();
+        let mut roots: Vec<T> = self.complex_roots().into_iter()
+            .filter(|z| z.i().abs() <= threshold*z.r().abs().max(one))
+            .map(|z| z.r())
+            .collect();
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        roots
+    }
+}
+
+fn real<T: BasicField>(value: T) -> Complex<T> {
+    Complex::new(value, <T as Identity<Addition>>::IDENTITY)
+}
+
+fn magnitude<T: BasicField + FloatingPoint>(z: Complex<T>) -> T {
+    z.r().hypot(z.i())
+}
+
+/// The value and slope of the polynomial at `z`, by Horner's method.
+fn evaluate<T: BasicField + FloatingPoint>(coefficients: &[T], z: Complex<T>) -> (Complex<T>, Complex<T>) {
+    let zero = real(<T as Identity<Addition>>::IDENTITY);
+    coefficients.iter().rev().fold((zero, zero), |(value, slope), c| (value*z + real(*c), slope*z + value))
+}
+
+/// ax² + bx + c = 0, taking whichever root doesn't cancel out first and
+/// getting the other from their product.
+fn quadratic<T: BasicField + FloatingPoint>(a: T, b: T, c: T) -> [Complex<T>; 2] {
+    let zero = <T as Identity<Addition>>::IDENTITY;
+    let two = T::from_u32(2);
+    let discriminant = b*b - T::from_u32(4)*a*c;
+    if discriminant >= zero {
+        let root = discriminant.sqrt();
+        let q = -(b + if b.is_negative() { -root } else { root })/two;
+        if q == zero {
+            return [real(zero), real(zero)];
+        }
+        [real(q/a), real(c/q)]
+    } else {
+        let r = -b/(two*a);
+        let i = (-discriminant).sqrt()/(two*a);
+        [Complex::new(r, i), Complex::new(r, -i)]
+    }
+}
+
+/// ax³ + bx² + cx + d = 0, by substituting x = t - b/3a to get the depressed
+/// cubic t³ + pt + q = 0. With three real roots they come from the cosine of
+/// a third of an angle, otherwise Cardano's formula gives the real one and
+/// the other two are left in a quadratic.
+fn cubic<T: BasicField + FloatingPoint>(a: T, b: T, c: T, d: T) -> [Complex<T>; 3] {
+    let zero = <T as Identity<Addition>>::IDENTITY;
+    let one = <T as Identity<Multiplication>>::IDENTITY;
+    let two = T::from_u32(2);
+    let three = T::from_u32(3);
+    let (b, c, d) = (b/a, c/a, d/a);
+    let shift = -b/three;
+    let p = c - b*b/three;
+    let q = two*b*b*b/T::from_u32(27) - b*c/three + d;
+    let discriminant = q*q/T::from_u32(4) + p*p*p/T::from_u32(27);
+    if discriminant < zero {
+        let radius = two*(-p/three).sqrt();
+        let cosine = (three*q/(two*p)*(-three/p).sqrt()).max(-one).min(one);
+        let angle = cosine.acos()/three;
+        let third = T::TAU/three;
+        return [
+            real(radius*angle.cos() + shift),
+            real(radius*(angle - third).cos() + shift),
+            real(radius*(angle - two*third).cos() + shift),
+        ];
+    }
+    let root = discriminant.sqrt();
+    let u = (-q/two - if q.is_negative() { -root } else { root }).cbrt();
+    let v = if u == zero { zero } else { -p/(three*u) };
+    let t = u + v;
+    let [s, r] = quadratic(one, t, t*t + p);
+    [real(t + shift), s + real(shift), r + real(shift)]
+}
+
+/// ax⁴ + bx³ + cx² + dx + e = 0 by Ferrari's method. Substituting
+/// x = y - b/4a gives y⁴ + py² + qy + r = 0, and adding a root m of the
+/// resolvent cubic to both sides of y⁴ + py² = -qy - r turns both into
+/// squares, which leaves two quadratics.
+fn quartic<T: BasicField + FloatingPoint>(a: T, b: T, c: T, d: T, e: T) -> [Complex<T>; 4] {
+    let zero = <T as Identity<Addition>>::IDENTITY;
+    let one = <T as Identity<Multiplication>>::IDENTITY;
+    let two = T::from_u32(2);
+    let four = T::from_u32(4);
+    let (b, c, d, e) = (b/a, c/a, d/a, e/a);
+    let shift = real(-b/four);
+    let b2 = b*b;
+    let p = c - T::from_u32(3)*b2/T::from_u32(8);
+    let q = d - b*c/two + b2*b/T::from_u32(8);
+    let r = e - b*d/four + b2*c/T::from_u32(16) - T::from_u32(3)*b2*b2/T::from_u32(256);
+    // the resolvent is negative at zero and grows without bound, so its
+    // largest root is positive unless q is zero
+    let m = cubic(one, p, p*p/four - r, -q*q/T::from_u32(8)).into_iter()
+        .filter(|m| m.i() == zero)
+        .map(|m| m.r())
+        .fold(-T::INFINITY, |largest, m| largest.max(m));
+    if q == zero || m <= zero {
+        // y⁴ + py² + r is a quadratic in y²
+        let [s, t] = quadratic(one, p, r);
+        let (s, t) = (s.sqrt(), t.sqrt());
+        return [s + shift, -s + shift, t + shift, -t + shift];
+    }
+    let s = (two*m).sqrt();
+    let offset = q/(two*s);
+    let [y0, y1] = quadratic(one, s, p/two + m - offset);
+    let [y2, y3] = quadratic(one, -s, p/two + m + offset);
+    [y0 + shift, y1 + shift, y2 + shift, y3 + shift]
+}
+
+/// A few steps of Newton's method on each root, only kept while they bring
+/// the polynomial closer to zero, to clean up what the closed forms lost to
+/// rounding.
+fn polish<T: BasicField + FloatingPoint, const N: usize>(coefficients: &[T], mut roots: [Complex<T>; N]) -> [Complex<T>; N] {
+    for root in &mut roots {
+        for _ in 0..3 {
+            let (value, slope) = evaluate(coefficients, *root);
+            let next = *root - value/slope;
+            // NaN from a flat slope doesn't count as closer either
+            let closer = magnitude(evaluate(coefficients, next).0) < magnitude(value);
+            if !closer {
+                break;
+            }
+            *root = next;
+        }
+    }
+    roots
+}
+
+/// Aberth's method, which moves every guess by its Newton step, pushed away
+/// from all the other guesses so no two of them end up on the same root.
+/// They start out spread around a circle that holds every root.
+fn aberth<T: BasicField + FloatingPoint>(coefficients: &[T]) -> Vec<Complex<T>> {
+    let one = <T as Identity<Multiplication>>::IDENTITY;
+    let degree = coefficients.len() - 1;
+    let leading = coefficients[degree];
+    // Cauchy's bound
+    let radius = one + coefficients[..degree].iter().fold(<T as Identity<Addition>>::IDENTITY, |largest, c| largest.max((*c/leading).abs()));
+    let mut roots: Vec<Complex<T>> = (0..degree)
+        .map(|k| Complex::from_polar(radius, T::TAU*T::from_usize(k)/T::from_usize(degree) + T::from_f32(0.4)))
+        .collect();
+    for _ in 0..ABERTH_ITERATIONS {
+        let mut converged = true;
+        for k in 0..degree {
+            let (value, slope) = evaluate(coefficients, roots[k]);
+            let newton = value/slope;
+            let repulsion = (0..degree)
+                .filter(|j| *j != k)
+                .fold(real(<T as Identity<Addition>>::IDENTITY), |sum, j| sum + real(one)/(roots[k] - roots[j]));
+            let step = newton/(real(one) - newton*repulsion);
+            if !step.r().is_finite() || !step.i().is_finite() {
+                continue;
+            }
+            roots[k] = roots[k] - step;
+            if magnitude(step) > T::EPSILON*magnitude(roots[k]).max(one) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+    roots
+}
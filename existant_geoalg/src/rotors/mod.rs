@@ -1,4 +1,4 @@
-use existant_core::{Absorption, Addition, AssociativeOver, BasicField, ClosedUnder, CommutativeOver, Distributive, FloatingPoint, Identity, Inverse, Multiplication, Operator, Semimodule};
+use existant_core::{const_math, Absorption, Addition, ApproxEq, AssociativeOver, BasicField, ClosedUnder, CommutativeOver, Distributive, FloatingPoint, Homomorphism, Identity, Inverse, Multiplication, Operator, Semimodule, Tolerance};
 
 use crate::vectors::{InnerProductSpace, NormedVectorSpace, Vector2};
 mod quaternion;
@@ -66,8 +66,10 @@ impl<T: BasicField + Inverse<Addition>> Inverse<Addition> for Complex<T> {
     }
 }
 impl<T: BasicField + Inverse<Multiplication>> Inverse<Multiplication> for Complex<T> {
+    /// 1/(a + bi) = (a - bi)/(a² + b²)
     fn inverse(self) -> Self {
-        Self { inner: <Vector2<T> as Inverse<Multiplication>>::inverse(self.inner) }
+        let norm_squared = self.r()*self.r() + self.i()*self.i();
+        Self::new(self.r()/norm_squared, -self.i()/norm_squared)
     }
 }
 impl<T: BasicField + Identity<Multiplication>> Identity<Multiplication> for Complex<T> {
@@ -85,6 +87,21 @@ impl<T: BasicField + Identity<Addition>> Identity<Addition> for Complex<T> {
     }
 }
 
+macro_rules! impl_const_angle {
+    ($($float:ty, $sin_cos:path);*) => {
+        $(
+            impl Complex<$float> {
+                /// [`Complex::from_angle`], for use in a `const` or `static`.
+                pub const fn from_angle_const(radians: $float) -> Self {
+                    let (sin, cos) = $sin_cos(radians);
+                    Self::new(cos, sin)
+                }
+            }
+        )*
+    };
+}
+impl_const_angle!(f32, const_math::sin_cosf; f64, const_math::sin_cos);
+
 impl<T: BasicField> Complex<T>  {
     pub const fn new(real: T, imaginary: T) -> Self {
         Self { inner: Vector2::new(real, imaginary) }
@@ -104,6 +121,39 @@ impl<T: BasicField> Complex<T>  {
         where T: FloatingPoint {
         Self::new(radians.cos(), radians.sin())
     }
+    pub fn from_polar(magnitude: T, radians: T) -> Self 
+        where T: FloatingPoint {
+        Self::new(magnitude*radians.cos(), magnitude*radians.sin())
+    }
+    /// The angle from the real axis, in `(-π, π]`.
+    pub fn arg(self) -> T 
+        where T: FloatingPoint {
+        self.i().atan2(self.r())
+    }
+    /// The square root with a non negative real part.
+    pub fn sqrt(self) -> Self 
+        where T: FloatingPoint {
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        if self.r() == zero && self.i() == zero {
+            return self;
+        }
+        // taking the root of whichever of |z| ± re doesn't cancel out
+        let two = T::from_u32(2);
+        let magnitude = self.r().hypot(self.i());
+        if self.r() >= zero {
+            let t = ((magnitude + self.r())/two).sqrt();
+            Self::new(t, self.i()/(two*t))
+        } else {
+            let t = ((magnitude - self.r())/two).sqrt();
+            let t = if self.i().is_negative() { -t } else { t };
+            Self::new(self.i().abs()/(two*t.abs()), t)
+        }
+    }
+    /// The cube root with the smallest angle.
+    pub fn cbrt(self) -> Self 
+        where T: FloatingPoint {
+        Self::from_polar(self.r().hypot(self.i()).cbrt(), self.arg()/T::from_u32(3))
+    }
 }
 
 impl<T: BasicField> core::ops::Add for Complex<T> {
@@ -136,6 +186,29 @@ impl<T: BasicField> core::ops::Mul for Complex<T> {
         )
     }
 }
+impl<T: BasicField> core::ops::Div<T> for Complex<T> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.inner.x/rhs, self.inner.y/rhs)
+    }
+}
+impl<T: BasicField> core::ops::Div for Complex<T> {
+    type Output = Self;
+    /// (a + bi)/(c + di) = (a + bi)(c - di)/(c² + d²)
+    fn div(self, rhs: Self) -> Self::Output {
+        let norm_squared = rhs.r()*rhs.r() + rhs.i()*rhs.i();
+        Self::new(
+            (self.r()*rhs.r() + self.i()*rhs.i())/norm_squared,
+            (self.i()*rhs.r() - self.r()*rhs.i())/norm_squared,
+        )
+    }
+}
+impl<T: BasicField> core::ops::Neg for Complex<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.r(), -self.i())
+    }
+}
 impl<T: BasicField> core::ops::Mul<Quaternion<T>> for Complex<T> {
     type Output = Quaternion<T>;
     fn mul(self, rhs: Quaternion<T>) -> Self::Output {
@@ -152,4 +225,19 @@ impl<T: BasicField> From<Vector2<T>> for Complex<T> {
     fn from(value: Vector2<T>) -> Self {
         Self::new(value.x, value.y)
     }
-}
\ No newline at end of file
+}
+impl<T: BasicField> Homomorphism<Addition, Complex<T>> for Vector2<T> {
+    fn map(self) -> Complex<T> {
+        Complex::from(self)
+    }
+}
+
+impl<T: BasicField + ApproxEq> ApproxEq for Complex<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.inner.approx_eq(&other.inner, tolerance)
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::Mul;
 
-use existant_core::{BasicField, FloatingPoint, Semimodule};
+use existant_core::{const_math, Addition, ApproxEq, AssociativeOver, BasicField, ClosedUnder, FloatingPoint, Homomorphism, Identity, Inverse, Multiplication, Semimodule, Tolerance};
 
 use crate::{rotors::Complex, matrix::Matrix4x4, vectors::{InnerProductSpace, NormedVectorSpace, Vector3, Vector4}};
 
@@ -53,7 +53,7 @@ impl<T: BasicField> Quaternion<T> {
     }
     pub fn from_angle(axis: Vector3<T>, radians: T) -> Self 
         where T: FloatingPoint {
-        let (c, s) = radians.mul(T::from_f64(0.5)).sin_cos();
+        let (s, c) = radians.mul(T::from_f64(0.5)).sin_cos();
         let norm = axis.normalize()*s;
         Self::new(c, norm.x, norm.y, norm.z)
     }
@@ -62,6 +62,41 @@ impl<T: BasicField> Quaternion<T> {
     }
 }
 
+macro_rules! impl_const_angle {
+    ($($float:ty, $sqrt:path, $sin_cos:path);*) => {
+        $(
+            impl Quaternion<$float> {
+                /// [`Quaternion::from_angle`], for use in a `const` or `static`.
+                pub const fn from_angle_const(axis: Vector3<$float>, radians: $float) -> Self {
+                    let (sin, cos) = $sin_cos(radians * 0.5);
+                    let scale = sin / $sqrt(axis.x * axis.x + axis.y * axis.y + axis.z * axis.z);
+                    Self::new(cos, axis.x * scale, axis.y * scale, axis.z * scale)
+                }
+            }
+        )*
+    };
+}
+impl_const_angle!(f32, const_math::sqrtf, const_math::sin_cosf; f64, const_math::sqrt, const_math::sin_cos);
+
+// Multiplication is associative but not commutative, and everything but 0
+// has an inverse.
+impl<T: BasicField> ClosedUnder<Multiplication> for Quaternion<T> {}
+impl<T: BasicField> AssociativeOver<Multiplication> for Quaternion<T> {}
+impl<T: BasicField> Identity<Multiplication> for Quaternion<T> {
+    const IDENTITY: Self = Self::new(<T as Identity<Multiplication>>::IDENTITY, <T as Identity<Addition>>::IDENTITY, <T as Identity<Addition>>::IDENTITY, <T as Identity<Addition>>::IDENTITY);
+    fn is_identity(&self) -> bool {
+        *self == <Self as Identity<Multiplication>>::IDENTITY
+    }
+}
+impl<T: BasicField> Inverse<Multiplication> for Quaternion<T> {
+    /// The conjugate over the squared norm.
+    fn inverse(self) -> Self {
+        let norm_squared = self.r()*self.r() + self.i()*self.i() + self.j()*self.j() + self.k()*self.k();
+        let conjugate = self.conjugate();
+        Self::new(conjugate.r()/norm_squared, conjugate.i()/norm_squared, conjugate.j()/norm_squared, conjugate.k()/norm_squared)
+    }
+}
+
 impl<T: BasicField> Semimodule for Quaternion<T> {
     type Scalar = T;
     fn scalar_multiplication(&self, rhs: Self::Scalar) -> Self {
@@ -100,6 +135,13 @@ impl<T: BasicField> From<Quaternion<T>> for Matrix4x4<T> {
         )
     }
 }
+/// Only additive, the product of two of these matrices isn't the matrix of
+/// the product.
+impl<T: BasicField> Homomorphism<Addition, Matrix4x4<T>> for Quaternion<T> {
+    fn map(self) -> Matrix4x4<T> {
+        Matrix4x4::from(self)
+    }
+}
 
 impl<T: BasicField> Mul for Quaternion<T> {
     type Output = Self;
@@ -137,10 +179,25 @@ impl<T: BasicField> From<Vector4<T>> for Quaternion<T> {
         Self::new(value.x, value.y, value.z, value.w)
     }
 }
+impl<T: BasicField> Homomorphism<Addition, Quaternion<T>> for Vector4<T> {
+    fn map(self) -> Quaternion<T> {
+        Quaternion::from(self)
+    }
+}
 
 impl<T: BasicField> Mul<Vector4<T>> for Quaternion<T> {
     type Output = Quaternion<T>;
     fn mul(self, rhs: Vector4<T>) -> Self::Output {
         self*Quaternion::from(rhs)
     }
-}
\ No newline at end of file
+}
+
+impl<T: BasicField + ApproxEq> ApproxEq for Quaternion<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.inner.approx_eq(&other.inner, tolerance)
+    }
+}
@@ -0,0 +1,247 @@
+//! `Serialize` and `Deserialize` for the vectors, matrices, rotors and shapes.
+//! Vectors and rotors are written as a sequence of their components, and the
+//! matrices as a sequence of their columns, so a [`Matrix4x4`] is 16 numbers
+//! in the same order as it's laid out in memory.
+
+use std::{fmt, marker::PhantomData};
+
+use existant_core::{Addition, BasicField, FloatingPoint, Identity, Ring, Semigroup, Semimodule, Semiring, UniversalOperationsOn};
+use serde::{de::{self, SeqAccess, Visitor}, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{geometry::{LinearSegment2D, Ray2D, Ray3D, Rect2D, Sphere2D}, matrix::{DynamicMatrix, Matrix2x2, Matrix2x3, Matrix2x4, Matrix3x2, Matrix3x3, Matrix3x4, Matrix4x2, Matrix4x3, Matrix4x4}, rotors::{Complex, Quaternion}, vectors::{NormedVectorSpace, Vector2, Vector3, Vector4, VectorN}};
+
+macro_rules! impl_serde_vector {
+    ($structure:ident, $($component:ident),*) => {
+        impl<T: Serialize> Serialize for $structure<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ($(&self.$component),*).serialize(serializer)
+            }
+        }
+        impl<'de, T: Deserialize<'de>> Deserialize<'de> for $structure<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let ($($component),*) = Deserialize::deserialize(deserializer)?;
+                Ok(Self { $($component),* })
+            }
+        }
+    };
+}
+
+impl_serde_vector!(Vector2, x, y);
+impl_serde_vector!(Vector3, x, y, z);
+impl_serde_vector!(Vector4, x, y, z, w);
+
+impl<T: Serialize, const N: usize> Serialize for VectorN<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for component in &self.0 {
+            tuple.serialize_element(component)?;
+        }
+        tuple.end()
+    }
+}
+/// serde only covers arrays up to 32 long, so this goes through a visitor.
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for VectorN<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Components<T, const N: usize>(PhantomData<T>);
+        impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for Components<T, N> {
+            type Value = VectorN<T, N>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "{N} components")
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut components = Vec::with_capacity(N);
+                while components.len() < N {
+                    match seq.next_element()? {
+                        Some(component) => components.push(component),
+                        None => return Err(de::Error::invalid_length(components.len(), &self)),
+                    }
+                }
+                Ok(VectorN(components.try_into().unwrap_or_else(|_| unreachable!())))
+            }
+        }
+        deserializer.deserialize_tuple(N, Components(PhantomData))
+    }
+}
+
+/// Through the vector it converts to, so a rotor is written the same way.
+macro_rules! impl_serde_rotor {
+    ($structure:ident, $vector:ident) => {
+        impl<T: BasicField + Serialize> Serialize for $structure<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $vector::from(*self).serialize(serializer)
+            }
+        }
+        impl<'de, T: BasicField + Deserialize<'de>> Deserialize<'de> for $structure<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $vector::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+impl_serde_rotor!(Complex, Vector2);
+impl_serde_rotor!(Quaternion, Vector4);
+
+macro_rules! impl_serde_matrix {
+    ($structure:ident $(: $bound:ident)?, $($column:ident),*) => {
+        impl<T: $($bound +)? Serialize> Serialize for $structure<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ($(&self.$column),*).serialize(serializer)
+            }
+        }
+        impl<'de, T: $($bound +)? Deserialize<'de>> Deserialize<'de> for $structure<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let ($($column),*) = Deserialize::deserialize(deserializer)?;
+                Ok(Self { $($column),* })
+            }
+        }
+    };
+}
+
+impl_serde_matrix!(Matrix2x2: Semiring, x, y);
+impl_serde_matrix!(Matrix2x3: Semiring, x, y);
+impl_serde_matrix!(Matrix2x4: Semiring, x, y);
+impl_serde_matrix!(Matrix3x2: Semiring, x, y, z);
+impl_serde_matrix!(Matrix3x3: Semiring, x, y, z);
+impl_serde_matrix!(Matrix3x4: Semiring, x, y, z);
+impl_serde_matrix!(Matrix4x2: Ring, x, y, z, w);
+impl_serde_matrix!(Matrix4x3: Ring, x, y, z, w);
+impl_serde_matrix!(Matrix4x4, x, y, z, w);
+
+/// The entries of a [`DynamicMatrix`], column by column.
+struct Entries<'a, T>(&'a DynamicMatrix<T>);
+
+impl<T: Serialize> Serialize for Entries<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq((0..self.0.columns()).flat_map(|column| self.0.column(column)))
+    }
+}
+
+/// `[rows, columns, entries]`, with the entries column by column.
+impl<T: Serialize> Serialize for DynamicMatrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.rows(), self.columns(), Entries(self)).serialize(serializer)
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DynamicMatrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Dynamic<T>(PhantomData<T>);
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for Dynamic<T> {
+            type Value = DynamicMatrix<T>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("rows, columns and the entries column by column")
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let rows: usize = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let columns: usize = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let entries: Vec<T> = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                if rows.checked_mul(columns) != Some(entries.len()) {
+                    return Err(de::Error::custom(format_args!("expected {rows} by {columns} entries, found {}", entries.len())));
+                }
+                let mut entries = entries.into_iter();
+                Ok(DynamicMatrix::from_fn(rows, columns, |_, _| entries.next().unwrap()))
+            }
+        }
+        deserializer.deserialize_tuple(3, Dynamic(PhantomData))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Rect2D")]
+struct RectFields<T> {
+    pos: Vector2<T>,
+    dimensions: Vector2<T>,
+}
+
+impl<T: Semigroup<Addition> + UniversalOperationsOn<T> + Serialize> Serialize for Rect2D<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RectFields { pos: self.min(), dimensions: self.dimensions() }.serialize(serializer)
+    }
+}
+/// Only rects with dimensions of zero or more, which also turns away NaN.
+impl<'de, T> Deserialize<'de> for Rect2D<T>
+    where T: Semigroup<Addition> + Identity<Addition> + UniversalOperationsOn<T> + PartialOrd + Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RectFields { pos, dimensions } = RectFields::deserialize(deserializer)?;
+        let zero = <T as Identity<Addition>>::IDENTITY;
+        if !(dimensions.x >= zero && dimensions.y >= zero) {
+            return Err(de::Error::custom("the dimensions of a rect can't be negative"));
+        }
+        Ok(Self::new(pos, dimensions))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Sphere2D")]
+struct SphereFields<T> {
+    center: Vector2<T>,
+    radius: T,
+}
+
+impl<T: FloatingPoint + BasicField + Serialize> Serialize for Sphere2D<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SphereFields { center: self.center, radius: self.radius }.serialize(serializer)
+    }
+}
+/// Only spheres with a radius of zero or more, which also turns away NaN.
+impl<'de, T: FloatingPoint + BasicField + Deserialize<'de>> Deserialize<'de> for Sphere2D<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SphereFields { center, radius } = SphereFields::<T>::deserialize(deserializer)?;
+        if radius.is_nan() || radius < <T as Identity<Addition>>::IDENTITY {
+            return Err(de::Error::custom("the radius of a sphere can't be negative"));
+        }
+        Ok(Self::new(center, radius))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "LinearSegment2D")]
+struct SegmentFields<T> {
+    start: Vector2<T>,
+    end: Vector2<T>,
+}
+
+impl<T: FloatingPoint + BasicField + Serialize> Serialize for LinearSegment2D<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SegmentFields { start: self.points[0], end: self.points[1] }.serialize(serializer)
+    }
+}
+impl<'de, T: FloatingPoint + BasicField + Deserialize<'de>> Deserialize<'de> for LinearSegment2D<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SegmentFields { start, end } = SegmentFields::deserialize(deserializer)?;
+        Ok(Self::new(start, end))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RayFields<V> {
+    pos: V,
+    dir: V,
+}
+
+/// The direction is normalized again when it's read back, since it might
+/// not have been written by this, and a zero one has no direction to give.
+macro_rules! impl_serde_ray {
+    ($structure:ident, $vector:ident, $($argument:ident),*) => {
+        impl<T: BasicField + Serialize> Serialize for $structure<T>
+            where $vector<T>: NormedVectorSpace + Semimodule<Scalar = T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                RayFields { pos: self.pos(), dir: self.dir() }.serialize(serializer)
+            }
+        }
+        impl<'de, T: BasicField + Deserialize<'de>> Deserialize<'de> for $structure<T>
+            where $vector<T>: NormedVectorSpace + Semimodule<Scalar = T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let fields = RayFields::<$vector<T>>::deserialize(deserializer)?;
+                if <$vector<T> as Identity<Addition>>::is_identity(&fields.dir) {
+                    return Err(de::Error::custom("the direction of a ray can't be zero"));
+                }
+                Ok(Self::new($(fields.$argument),*))
+            }
+        }
+    };
+}
+
+impl_serde_ray!(Ray2D, Vector2, dir, pos);
+impl_serde_ray!(Ray3D, Vector3, pos, dir);
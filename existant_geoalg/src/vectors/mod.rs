@@ -5,9 +5,11 @@ use existant_core::{Addition, BasicField, Bounds, Field, FloatingPoint, FromPrim
 mod vec2d;
 mod vec3d;
 mod vec4d;
+mod vecnd;
 pub use vec2d::*;
 pub use vec3d::*;
 pub use vec4d::*;
+pub use vecnd::*;
 
 pub trait InnerProductSpace<DistributiveOp: Operator = Multiplication, Op: Operator = Addition>: NormedVectorSpace<DistributiveOp, Op>
     where <Self as Semimodule<DistributiveOp, Op>>::Scalar: Field<DistributiveOp, Op> {
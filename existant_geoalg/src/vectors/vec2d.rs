@@ -1,7 +1,7 @@
 use std::ops::{Index, IndexMut};
 
 use bytemuck::{Pod, Zeroable};
-use existant_core::{Absorption, Addition, AssociativeOver, BasicField, Bounds, ClosedUnder, CommutativeOver, Distributive, FloatingPoint, Groupoid, Identity, Inverse, Multiplication, Operator, Semimodule, Semiring};
+use existant_core::{Absorption, Addition, ApproxEq, AssociativeOver, BasicField, BoundedLattice, Bounds, ClosedUnder, CommutativeOver, Distributive, DistributiveLattice, FloatingPoint, Groupoid, Homomorphism, Identity, Inverse, Lattice, Multiplication, Operator, PartialOrderSet, Semimodule, Semiring, Tolerance};
 
 use crate::{derivative::Derivative, rotors::Complex, vectors::{GeometricAlgebra, GrassmanAlgebra, InnerProductSpace, MetricSpace, NormedVectorSpace, Vector3, Vector4}};
 
@@ -19,14 +19,8 @@ pub struct Vector2<T> {
     pub y: T
 }
 
-unsafe impl<T: Identity<Addition>> Zeroable for Vector2<T> {
-    fn zeroed() -> Self {
-        Self::new(<T as Identity<Addition>>::IDENTITY,<T as Identity<Addition>>::IDENTITY)
-    }
-}
-unsafe impl<T: Identity<Addition> + Copy + 'static> Pod for Vector2<T> {
-
-}
+unsafe impl<T: Zeroable> Zeroable for Vector2<T> {}
+unsafe impl<T: Pod> Pod for Vector2<T> {}
 
 impl<T> Index<usize> for Vector2<T> {
     type Output = T;
@@ -81,6 +75,32 @@ impl<Op: Operator, T: Identity<Op>> Identity<Op> for Vector2<T> {
     }
 }
 
+impl<T: PartialOrderSet> PartialOrderSet for Vector2<T> {
+    fn leq(&self, other: &Self) -> bool {
+        self.x.leq(&other.x) && 
+        self.y.leq(&other.y)
+    }
+}
+impl<T: Lattice> Lattice for Vector2<T> {
+    fn meet(self, other: Self) -> Self {
+        Self::new(
+            self.x.meet(other.x), 
+            self.y.meet(other.y)
+        )
+    }
+    fn join(self, other: Self) -> Self {
+        Self::new(
+            self.x.join(other.x), 
+            self.y.join(other.y)
+        )
+    }
+}
+impl<T: BoundedLattice> BoundedLattice for Vector2<T> {
+    const BOTTOM: Self = Self::new(T::BOTTOM, T::BOTTOM);
+    const TOP: Self = Self::new(T::TOP, T::TOP);
+}
+impl<T: DistributiveLattice> DistributiveLattice for Vector2<T> {}
+
 impl<DistributiveOp: Operator, Op: Operator, T: Semiring<DistributiveOp, Op>> Semimodule<DistributiveOp, Op> for Vector2<T> {
     type Scalar = T;
     fn scalar_multiplication(&self, rhs: Self::Scalar) -> Self {
@@ -258,6 +278,34 @@ impl<T: core::ops::Div<Output = T>> core::ops::Div for Vector2<T> {
     }
 }
 
+impl<T: core::ops::BitAnd<Output = T>> core::ops::BitAnd for Vector2<T> {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitand(rhs.x), self.y.bitand(rhs.y))
+    }
+}
+
+impl<T: core::ops::BitOr<Output = T>> core::ops::BitOr for Vector2<T> {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitor(rhs.x), self.y.bitor(rhs.y))
+    }
+}
+
+impl<T: core::ops::BitXor<Output = T>> core::ops::BitXor for Vector2<T> {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitxor(rhs.x), self.y.bitxor(rhs.y))
+    }
+}
+
+impl<T: core::ops::Not<Output = T>> core::ops::Not for Vector2<T> {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        Self::new(self.x.not(), self.y.not())
+    }
+}
+
 impl<T> From<(T, T)> for Vector2<T> {
     fn from(value: (T, T)) -> Self {
         Self::new(value.0, value.1)
@@ -269,6 +317,11 @@ impl<T: BasicField> From<Complex<T>> for Vector2<T> {
         Self::new(value.r(), value.i())
     }
 }
+impl<T: BasicField> Homomorphism<Addition, Vector2<T>> for Complex<T> {
+    fn map(self) -> Vector2<T> {
+        Vector2::from(self)
+    }
+}
 
 impl<T: BasicField> core::ops::Mul<Complex<T>> for Vector2<T> {
     type Output = Vector2<T>;
@@ -306,6 +359,17 @@ impl<T: Clone> Derivative for Vector2<T> {
     }
 }
 
+impl<T: ApproxEq> ApproxEq for Vector2<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance)
+    }
+}
+
 pub type I8Vec2 = Vector2<i8>;
 pub type I16Vec2 = Vector2<i16>;
 pub type I32Vec2 = Vector2<i32>;
@@ -317,4 +381,6 @@ pub type U32Vec2 = Vector2<u32>;
 pub type U64Vec2 = Vector2<u64>;
 pub type U128Vec2 = Vector2<u128>;
 pub type FVec2 = Vector2<f32>;
-pub type DVec2 = Vector2<f64>;
\ No newline at end of file
+pub type DVec2 = Vector2<f64>;
+#[cfg(feature = "half")]
+pub type HVec2 = Vector2<existant_core::f16>;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::ops::{Index, IndexMut};
 
-use existant_core::{Absorption, Addition, AssociativeOver, BasicField, ClosedUnder, CommutativeOver, Distributive, FloatingPoint, FromPrimitive, Groupoid, Identity, Inverse, Multiplication, Operator, Semimodule, Semiring};
+use bytemuck::{Pod, Zeroable};
+use existant_core::{Absorption, Addition, ApproxEq, AssociativeOver, BasicField, BoundedLattice, ClosedUnder, CommutativeOver, Distributive, DistributiveLattice, FloatingPoint, FromPrimitive, Groupoid, Identity, Inverse, Lattice, Multiplication, Operator, PartialOrderSet, Semimodule, Semiring, Tolerance};
 
 use crate::{derivative::Derivative, vectors::{GeometricAlgebra, GrassmanAlgebra, InnerProductSpace, MetricSpace, NormedVectorSpace, Vector2, Vector4}};
 
@@ -11,6 +12,10 @@ pub struct Vector3<T> {
     pub y: T,
     pub z: T,
 }
+
+unsafe impl<T: Zeroable> Zeroable for Vector3<T> {}
+unsafe impl<T: Pod> Pod for Vector3<T> {}
+
 impl<T> Index<usize> for Vector3<T> {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
@@ -67,6 +72,35 @@ impl<Op: Operator, T: Identity<Op>> Identity<Op> for Vector3<T> {
     }
 }
 
+impl<T: PartialOrderSet> PartialOrderSet for Vector3<T> {
+    fn leq(&self, other: &Self) -> bool {
+        self.x.leq(&other.x) && 
+        self.y.leq(&other.y) && 
+        self.z.leq(&other.z)
+    }
+}
+impl<T: Lattice> Lattice for Vector3<T> {
+    fn meet(self, other: Self) -> Self {
+        Self::new(
+            self.x.meet(other.x), 
+            self.y.meet(other.y), 
+            self.z.meet(other.z)
+        )
+    }
+    fn join(self, other: Self) -> Self {
+        Self::new(
+            self.x.join(other.x), 
+            self.y.join(other.y), 
+            self.z.join(other.z)
+        )
+    }
+}
+impl<T: BoundedLattice> BoundedLattice for Vector3<T> {
+    const BOTTOM: Self = Self::new(T::BOTTOM, T::BOTTOM, T::BOTTOM);
+    const TOP: Self = Self::new(T::TOP, T::TOP, T::TOP);
+}
+impl<T: DistributiveLattice> DistributiveLattice for Vector3<T> {}
+
 impl<DistributiveOp: Operator, Op: Operator, T: Semiring<DistributiveOp, Op>> Semimodule<DistributiveOp, Op> for Vector3<T> {
     type Scalar = T;
     fn scalar_multiplication(&self, rhs: Self::Scalar) -> Self {
@@ -231,6 +265,34 @@ impl<T: core::ops::Div<Output = T>> core::ops::Div for Vector3<T> {
     }
 }
 
+impl<T: core::ops::BitAnd<Output = T>> core::ops::BitAnd for Vector3<T> {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitand(rhs.x), self.y.bitand(rhs.y), self.z.bitand(rhs.z))
+    }
+}
+
+impl<T: core::ops::BitOr<Output = T>> core::ops::BitOr for Vector3<T> {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitor(rhs.x), self.y.bitor(rhs.y), self.z.bitor(rhs.z))
+    }
+}
+
+impl<T: core::ops::BitXor<Output = T>> core::ops::BitXor for Vector3<T> {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitxor(rhs.x), self.y.bitxor(rhs.y), self.z.bitxor(rhs.z))
+    }
+}
+
+impl<T: core::ops::Not<Output = T>> core::ops::Not for Vector3<T> {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        Self::new(self.x.not(), self.y.not(), self.z.not())
+    }
+}
+
 impl<T> From<(T, T, T)> for Vector3<T> {
     fn from(value: (T, T, T)) -> Self {
         Self::new(value.0, value.1, value.2)
@@ -250,6 +312,18 @@ impl<T: BasicField + FromPrimitive> Derivative for Vector3<T> {
     }
 }
 
+impl<T: ApproxEq> ApproxEq for Vector3<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance)
+    }
+}
+
 pub type I8Vec3 = Vector3<i8>;
 pub type I16Vec3 = Vector3<i16>;
 pub type I32Vec3 = Vector3<i32>;
@@ -261,4 +335,6 @@ pub type U32Vec3 = Vector3<u32>;
 pub type U64Vec3 = Vector3<u64>;
 pub type U128Vec3 = Vector3<u128>;
 pub type FVec3 = Vector3<f32>;
-pub type DVec3 = Vector3<f64>;
\ No newline at end of file
+pub type DVec3 = Vector3<f64>;
+#[cfg(feature = "half")]
+pub type HVec3 = Vector3<existant_core::f16>;
\ No newline at end of file
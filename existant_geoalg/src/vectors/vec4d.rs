@@ -1,6 +1,7 @@
 use std::ops::{Index, IndexMut};
 
-use existant_core::{Absorption, Addition, AssociativeOver, BasicField, ClosedUnder, CommutativeOver, Distributive, FloatingPoint, FromPrimitive, Groupoid, Identity, Inverse, Multiplication, Operator, Semimodule, Semiring};
+use bytemuck::{Pod, Zeroable};
+use existant_core::{Absorption, Addition, ApproxEq, AssociativeOver, BasicField, BoundedLattice, ClosedUnder, CommutativeOver, Distributive, DistributiveLattice, FloatingPoint, FromPrimitive, Groupoid, Homomorphism, Identity, Inverse, Lattice, Multiplication, Operator, PartialOrderSet, Semimodule, Semiring, Tolerance};
 
 use crate::{derivative::Derivative, rotors::Quaternion, vectors::{InnerProductSpace, MetricSpace, NormedVectorSpace, Vector2, Vector3}};
 
@@ -13,6 +14,9 @@ pub struct Vector4<T> {
     pub w: T,
 }
 
+unsafe impl<T: Zeroable> Zeroable for Vector4<T> {}
+unsafe impl<T: Pod> Pod for Vector4<T> {}
+
 impl<T> Index<usize> for Vector4<T> {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
@@ -72,6 +76,38 @@ impl<Op: Operator, T: Identity<Op>> Identity<Op> for Vector4<T> {
     }
 }
 
+impl<T: PartialOrderSet> PartialOrderSet for Vector4<T> {
+    fn leq(&self, other: &Self) -> bool {
+        self.x.leq(&other.x) && 
+        self.y.leq(&other.y) && 
+        self.z.leq(&other.z) && 
+        self.w.leq(&other.w)
+    }
+}
+impl<T: Lattice> Lattice for Vector4<T> {
+    fn meet(self, other: Self) -> Self {
+        Self::new(
+            self.x.meet(other.x), 
+            self.y.meet(other.y), 
+            self.z.meet(other.z), 
+            self.w.meet(other.w)
+        )
+    }
+    fn join(self, other: Self) -> Self {
+        Self::new(
+            self.x.join(other.x), 
+            self.y.join(other.y), 
+            self.z.join(other.z), 
+            self.w.join(other.w)
+        )
+    }
+}
+impl<T: BoundedLattice> BoundedLattice for Vector4<T> {
+    const BOTTOM: Self = Self::new(T::BOTTOM, T::BOTTOM, T::BOTTOM, T::BOTTOM);
+    const TOP: Self = Self::new(T::TOP, T::TOP, T::TOP, T::TOP);
+}
+impl<T: DistributiveLattice> DistributiveLattice for Vector4<T> {}
+
 impl<DistributiveOp: Operator, Op: Operator, T: Semiring<DistributiveOp, Op>> Semimodule<DistributiveOp, Op> for Vector4<T> {
     type Scalar = T;
     fn scalar_multiplication(&self, rhs: Self::Scalar) -> Self {
@@ -241,6 +277,34 @@ impl<T: core::ops::Div<Output = T>> core::ops::Div for Vector4<T> {
     }
 }
 
+impl<T: core::ops::BitAnd<Output = T>> core::ops::BitAnd for Vector4<T> {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitand(rhs.x), self.y.bitand(rhs.y), self.z.bitand(rhs.z), self.w.bitand(rhs.w))
+    }
+}
+
+impl<T: core::ops::BitOr<Output = T>> core::ops::BitOr for Vector4<T> {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitor(rhs.x), self.y.bitor(rhs.y), self.z.bitor(rhs.z), self.w.bitor(rhs.w))
+    }
+}
+
+impl<T: core::ops::BitXor<Output = T>> core::ops::BitXor for Vector4<T> {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self::new(self.x.bitxor(rhs.x), self.y.bitxor(rhs.y), self.z.bitxor(rhs.z), self.w.bitxor(rhs.w))
+    }
+}
+
+impl<T: core::ops::Not<Output = T>> core::ops::Not for Vector4<T> {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        Self::new(self.x.not(), self.y.not(), self.z.not(), self.w.not())
+    }
+}
+
 impl<T> From<(T, T, T, T)> for Vector4<T> {
     fn from(value: (T, T, T, T)) -> Self {
         Self::new(value.0, value.1, value.2, value.3)
@@ -252,6 +316,11 @@ impl<T: BasicField> From<Quaternion<T>> for Vector4<T> {
         Self::new(value.r(), value.i(), value.j(), value.k())
     }
 }
+impl<T: BasicField> Homomorphism<Addition, Vector4<T>> for Quaternion<T> {
+    fn map(self) -> Vector4<T> {
+        Vector4::from(self)
+    }
+}
 
 impl<T: BasicField> core::ops::Mul<Quaternion<T>> for Vector4<T> {
     type Output = Vector4<T>;
@@ -274,6 +343,19 @@ impl<T: BasicField + FromPrimitive> Derivative for Vector4<T> {
     }
 }
 
+impl<T: ApproxEq> ApproxEq for Vector4<T> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.x.approx_eq(&other.x, tolerance) &&
+        self.y.approx_eq(&other.y, tolerance) &&
+        self.z.approx_eq(&other.z, tolerance) &&
+        self.w.approx_eq(&other.w, tolerance)
+    }
+}
+
 pub type I8Vec4 = Vector4<i8>;
 pub type I16Vec4 = Vector4<i16>;
 pub type I32Vec4 = Vector4<i32>;
@@ -285,4 +367,6 @@ pub type U32Vec4 = Vector4<u32>;
 pub type U64Vec4 = Vector4<u64>;
 pub type U128Vec4 = Vector4<u128>;
 pub type FVec4 = Vector4<f32>;
-pub type DVec4 = Vector4<f64>;
\ No newline at end of file
+pub type DVec4 = Vector4<f64>;
+#[cfg(feature = "half")]
+pub type HVec4 = Vector4<existant_core::f16>;
\ No newline at end of file
@@ -0,0 +1,304 @@
+use std::ops::{Index, IndexMut};
+
+use bytemuck::{Pod, Zeroable};
+use existant_core::{Absorption, Addition, ApproxEq, AssociativeOver, BasicField, BoundedLattice, Bounds, ClosedUnder, CommutativeOver, Distributive, DistributiveLattice, FloatingPoint, Groupoid, Identity, Inverse, Lattice, Multiplication, Operator, PartialOrderSet, Semimodule, Semiring, Tolerance};
+
+use crate::vectors::{InnerProductSpace, MetricSpace, NormedVectorSpace, Vector2, Vector3, Vector4};
+
+/// A vector with any number of components, for when there's more than
+/// four of them, like feature vectors or the state of a rigid body. It's
+/// laid out exactly like `[T; N]`, and so like [`Vector2`], [`Vector3`] and
+/// [`Vector4`] when `N` matches, which is why converting between them is free.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VectorN<T, const N: usize>(pub [T; N]);
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for VectorN<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for VectorN<T, N> {}
+
+impl<T: Default, const N: usize> Default for VectorN<T, N> {
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VectorN<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T: core::fmt::Display, const N: usize> core::fmt::Display for VectorN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("<")?;
+        for (i, component) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            component.fmt(f)?;
+        }
+        f.write_str(">")
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for VectorN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter())
+            .finish()
+    }
+}
+
+impl<Op: Operator, T: ClosedUnder<Op>, const N: usize> ClosedUnder<Op> for VectorN<T, N> {}
+impl<Op: Operator, T: Absorption<Op>, const N: usize> Absorption<Op> for VectorN<T, N> {
+    const ABSORBING: Self = Self([T::ABSORBING; N]);
+    fn is_absorber(&self) -> bool {
+        self.0.iter().all(|component| component.is_absorber())
+    }
+}
+impl<Op: Operator, T: AssociativeOver<Op>, const N: usize> AssociativeOver<Op> for VectorN<T, N> {}
+impl<Op: Operator, T: CommutativeOver<Op>, const N: usize> CommutativeOver<Op> for VectorN<T, N> {}
+impl<DistributiveOp: Operator, Op: Operator, T: Distributive<DistributiveOp, Op>, const N: usize> Distributive<DistributiveOp, Op> for VectorN<T, N> {}
+impl<Op: Operator, T: Inverse<Op>, const N: usize> Inverse<Op> for VectorN<T, N> {
+    fn inverse(self) -> Self {
+        self.map(T::inverse)
+    }
+}
+impl<Op: Operator, T: Identity<Op>, const N: usize> Identity<Op> for VectorN<T, N> {
+    const IDENTITY: Self = Self([T::IDENTITY; N]);
+    fn is_identity(&self) -> bool {
+        self.0.iter().all(|component| component.is_identity())
+    }
+}
+
+impl<T: PartialOrderSet, const N: usize> PartialOrderSet for VectorN<T, N> {
+    fn leq(&self, other: &Self) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a.leq(b))
+    }
+}
+impl<T: Lattice, const N: usize> Lattice for VectorN<T, N> {
+    fn meet(self, other: Self) -> Self {
+        self.zip_with(other, T::meet)
+    }
+    fn join(self, other: Self) -> Self {
+        self.zip_with(other, T::join)
+    }
+}
+impl<T: BoundedLattice, const N: usize> BoundedLattice for VectorN<T, N> {
+    const BOTTOM: Self = Self([T::BOTTOM; N]);
+    const TOP: Self = Self([T::TOP; N]);
+}
+impl<T: DistributiveLattice, const N: usize> DistributiveLattice for VectorN<T, N> {}
+
+impl<DistributiveOp: Operator, Op: Operator, T: Semiring<DistributiveOp, Op>, const N: usize> Semimodule<DistributiveOp, Op> for VectorN<T, N> {
+    type Scalar = T;
+    fn scalar_multiplication(&self, rhs: Self::Scalar) -> Self {
+        self.map(|component| <T as Groupoid<DistributiveOp>>::op(&component, &rhs))
+    }
+}
+
+impl<T: BasicField + FloatingPoint, const N: usize> InnerProductSpace for VectorN<T, N> {
+    fn inner_product(&self, other: Self) -> Self::Scalar {
+        self.0.iter().zip(other.0.iter()).fold(<T as Identity<Addition>>::IDENTITY, |sum, (a, b)| sum + *a * *b)
+    }
+}
+
+impl<T: BasicField + FloatingPoint, const N: usize> NormedVectorSpace for VectorN<T, N> {
+    fn normalize(&self) -> Self {
+        let magnitude = self.magnitude();
+        self.map(|component| component/magnitude)
+    }
+    fn magnitude(&self) -> Self::Scalar {
+        self.squared_length().sqrt()
+    }
+}
+
+impl<T: BasicField + FloatingPoint, const N: usize> MetricSpace for VectorN<T, N> {
+    type Distance = T;
+    fn distance(&self, other: Self) -> Self::Distance {
+        (*self - other).magnitude()
+    }
+}
+
+impl<T: Bounds, const N: usize> Bounds for VectorN<T, N> {
+    const MIN: Self = Self([T::MIN; N]);
+    const MAX: Self = Self([T::MAX; N]);
+    fn min(self, other: Self) -> Self {
+        self.zip_with(other, T::min)
+    }
+    fn max(self, other: Self) -> Self {
+        self.zip_with(other, T::max)
+    }
+}
+
+impl<T, const N: usize> VectorN<T, N> {
+    #[inline]
+    pub const fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+    /// Builds each component from its index.
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self(core::array::from_fn(f))
+    }
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> VectorN<U, N> {
+        VectorN(self.0.map(f))
+    }
+    /// Applies `f` to each pair of components.
+    pub fn zip_with<U, V>(self, other: VectorN<U, N>, mut f: impl FnMut(T, U) -> V) -> VectorN<V, N> {
+        let mut other = other.0.into_iter();
+        self.map(|component| f(component, other.next().unwrap()))
+    }
+    /// The vector with a one at `axis` and zeros everywhere else.
+    pub fn axis(axis: usize) -> Self
+        where T: Identity<Multiplication> + Identity<Addition> {
+        Self::from_fn(|i| if i == axis { <T as Identity<Multiplication>>::IDENTITY } else { <T as Identity<Addition>>::IDENTITY })
+    }
+    pub const fn into_array(self) -> [T; N]
+        where T: Copy {
+        self.0
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        N
+    }
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub const fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T: Semiring<Multiplication, Addition>, const N: usize> core::ops::Mul<T> for VectorN<T, N> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        self.scalar_multiplication(rhs)
+    }
+}
+impl<T: core::ops::Neg<Output = T>, const N: usize> core::ops::Neg for VectorN<T, N> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.map(T::neg)
+    }
+}
+impl<T: core::ops::Not<Output = T>, const N: usize> core::ops::Not for VectorN<T, N> {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        self.map(T::not)
+    }
+}
+
+macro_rules! impl_componentwise {
+    ($($operator:ident, $method:ident);*) => {
+        $(
+            impl<T: core::ops::$operator<Output = T>, const N: usize> core::ops::$operator for VectorN<T, N> {
+                type Output = Self;
+                fn $method(self, rhs: Self) -> Self::Output {
+                    self.zip_with(rhs, T::$method)
+                }
+            }
+        )*
+    };
+}
+
+impl_componentwise!(Add, add; Sub, sub; Mul, mul; Div, div; BitAnd, bitand; BitOr, bitor; BitXor, bitxor);
+
+impl<T, const N: usize> From<[T; N]> for VectorN<T, N> {
+    fn from(value: [T; N]) -> Self {
+        Self(value)
+    }
+}
+impl<T, const N: usize> From<VectorN<T, N>> for [T; N] {
+    fn from(value: VectorN<T, N>) -> Self {
+        value.0
+    }
+}
+impl<T, const N: usize> AsRef<[T]> for VectorN<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+impl<T, const N: usize> AsMut<[T]> for VectorN<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+/// The fixed size vectors are `repr(C)` structs of `N` fields of the same
+/// type, so a reference to one is also a reference to a [`VectorN`].
+macro_rules! impl_fixed_conversions {
+    ($structure:ident, $n:literal, $($component:ident),*) => {
+        impl<T> From<$structure<T>> for VectorN<T, $n> {
+            fn from(value: $structure<T>) -> Self {
+                Self([$(value.$component),*])
+            }
+        }
+        impl<T> From<VectorN<T, $n>> for $structure<T> {
+            fn from(value: VectorN<T, $n>) -> Self {
+                let [$($component),*] = value.0;
+                Self::new($($component),*)
+            }
+        }
+        impl<T> AsRef<VectorN<T, $n>> for $structure<T> {
+            fn as_ref(&self) -> &VectorN<T, $n> {
+                // SAFETY: `$structure` is `repr(C)` with `$n` fields of `T`, so it
+                // has the layout of `[T; $n]`, which `VectorN` is `repr(transparent)`
+                // over. The borrow keeps its lifetime.
+                unsafe { &*(self as *const Self as *const VectorN<T, $n>) }
+            }
+        }
+        impl<T> AsMut<VectorN<T, $n>> for $structure<T> {
+            fn as_mut(&mut self) -> &mut VectorN<T, $n> {
+                // SAFETY: the same layout as in `as_ref`, and the exclusive
+                // borrow of `self` carries over to the result.
+                unsafe { &mut *(self as *mut Self as *mut VectorN<T, $n>) }
+            }
+        }
+        impl<T> AsRef<$structure<T>> for VectorN<T, $n> {
+            fn as_ref(&self) -> &$structure<T> {
+                // SAFETY: `VectorN` is `repr(transparent)` over `[T; $n]`, which has
+                // the layout of the `repr(C)` `$structure` with its `$n` fields of `T`.
+                unsafe { &*(self as *const Self as *const $structure<T>) }
+            }
+        }
+        impl<T> AsMut<$structure<T>> for VectorN<T, $n> {
+            fn as_mut(&mut self) -> &mut $structure<T> {
+                // SAFETY: the same layout as in `as_ref`, and the exclusive
+                // borrow of `self` carries over to the result.
+                unsafe { &mut *(self as *mut Self as *mut $structure<T>) }
+            }
+        }
+    };
+}
+
+impl_fixed_conversions!(Vector2, 2, x, y);
+impl_fixed_conversions!(Vector3, 3, x, y, z);
+impl_fixed_conversions!(Vector4, 4, x, y, z, w);
+
+impl<T: ApproxEq, const N: usize> ApproxEq for VectorN<T, N> {
+    type Epsilon = T::Epsilon;
+    fn default_tolerance() -> Tolerance<Self::Epsilon> {
+        T::default_tolerance()
+    }
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+pub type FVecN<const N: usize> = VectorN<f32, N>;
+pub type DVecN<const N: usize> = VectorN<f64, N>;
+#[cfg(feature = "half")]
+pub type HVecN<const N: usize> = VectorN<existant_core::f16, N>;
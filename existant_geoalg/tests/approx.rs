@@ -0,0 +1,56 @@
+//! `ApproxEq` on the vectors, rotors, matrices and shapes, which compare
+//! every component with the same tolerance.
+
+use existant_core::{assert_approx_eq, ApproxEq, FromPrimitive, Inverse, Multiplication, Tolerance, Q16_16};
+use existant_geoalg::{geometry::{LinearSegment2D, Ray2D, Rect2D}, matrix::{DynamicMatrix, Matrix4x4}, rotors::Quaternion, vectors::{Vector2, Vector3, Vector4}};
+
+#[test]
+fn componentwise() {
+    let a = Vector3::new(1.0f32, 0.1 + 0.2, -4.0);
+    assert_approx_eq!(a, Vector3::new(1.0, 0.3, -4.0));
+    assert!(!a.approx_eq(&Vector3::new(1.0, 0.3, -4.001), Tolerance::Relative(1e-4)));
+    assert!(a.approx_eq(&Vector3::new(1.0, 0.3, -4.001), Tolerance::Relative(1e-3)));
+}
+
+#[test]
+fn rotors_and_matrices() {
+    let q = Quaternion::from_angle(Vector3::new(0.0f64, 0.6, 0.8), 1.2);
+    assert_approx_eq!(q * <Quaternion<f64> as Inverse<Multiplication>>::inverse(q), Quaternion::new(1.0, 0.0, 0.0, 0.0), Tolerance::Absolute(1e-12));
+
+    let m = Matrix4x4::new(
+        Vector4::new(2.0f64, 0.0, 1.0, 0.0),
+        Vector4::new(0.0, 3.0, 0.0, 1.0),
+        Vector4::new(1.0, 0.0, 4.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 5.0),
+    );
+    let identity = DynamicMatrix::from(m * <Matrix4x4<f64> as Inverse<Multiplication>>::inverse(m));
+    assert_approx_eq!(identity, DynamicMatrix::from_fn(4, 4, |row, column| (row == column) as u8 as f64), Tolerance::Absolute(1e-12));
+    assert!(!identity.approx_eq(&DynamicMatrix::filled(4, 3, 0.0), Tolerance::Absolute(1.0)));
+}
+
+#[test]
+fn geometry() {
+    let rect = Rect2D::from_bounds(Vector2::new(0.3f64, 1.0), Vector2::new(0.1 + 0.2, 0.0));
+    assert_approx_eq!(rect, Rect2D::new(Vector2::new(0.3, 0.0), Vector2::new(0.0, 1.0)), Tolerance::Absolute(1e-15));
+
+    // off parallel by far less than an f32 epsilon, which an f64 ray can
+    // still tell apart
+    let segment = LinearSegment2D::new(Vector2::new(0.0f64, 1.0), Vector2::new(2e9, 1.0));
+    let ray = Ray2D::new(Vector2::new(1.0, 1e-9), Vector2::new(0.5, 0.0));
+    let hit = ray.line_intersection(segment).unwrap();
+    assert_approx_eq!(hit.point, Vector2::new(0.5 + 1e9, 1.0), Tolerance::Relative(1e-9));
+
+    // and the number types without an `ApproxEq` of their own still work
+    let q = Q16_16::from_f32;
+    let segment = LinearSegment2D::new(Vector2::new(q(0.0), q(1.0)), Vector2::new(q(2.0), q(1.0)));
+    let hit = Ray2D::new(Vector2::new(q(0.0), q(1.0)), Vector2::new(q(0.5), q(0.0))).line_intersection(segment).unwrap();
+    assert_eq!(hit.distance, q(1.0));
+    let parallel = Ray2D::new(Vector2::new(q(1.0), q(0.0)), Vector2::new(q(0.5), q(0.0)));
+    assert!(parallel.line_intersection(segment).is_none());
+}
+
+#[test]
+#[should_panic(expected = "     left: [1.0, 2.0]\n    right: [1.0, 2.1]\ntolerance: Ulps(4)")]
+fn failed_assertion() {
+    assert_approx_eq!(Vector2::new(1.0f32, 2.0), Vector2::new(1.0, 2.1));
+}
@@ -0,0 +1,106 @@
+//! The algebra derives on the kind of newtypes they're meant for.
+
+use core::marker::PhantomData;
+
+use existant_core::{AbelianGroup, Absorption, Addition, AssociativeOver, ClosedUnder, CommutativeRing, Field, Group, Identity, Inverse, Multiplication, Semimodule, VectorSpace};
+
+#[derive(Debug, Clone, Copy, PartialEq, existant_geoalg::Group)]
+#[algebra(commutative)]
+struct Meters(f64);
+
+/// Integers under `+`, where `*` is only there to make a ring.
+#[derive(Debug, Clone, Copy, PartialEq, existant_geoalg::Ring)]
+#[algebra(commutative)]
+struct Cents {
+    amount: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Seconds;
+
+#[derive(Debug, Clone, Copy, PartialEq, existant_geoalg::Field)]
+struct Quantity<T, Unit>(T, PhantomData<Unit>);
+
+#[derive(Debug, Clone, Copy, PartialEq, existant_geoalg::VectorSpace)]
+struct Velocity {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+/// Wraps a type that has `+` but no `Identity` of its own, so the identity
+/// has to be given.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Offset(i32);
+
+impl core::ops::Add for Offset {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Offset(self.0 + rhs.0)
+    }
+}
+impl core::ops::Sub for Offset {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Offset(self.0 - rhs.0)
+    }
+}
+impl core::ops::Neg for Offset {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Offset(-self.0)
+    }
+}
+impl ClosedUnder<Addition> for Offset {}
+impl AssociativeOver<Addition> for Offset {}
+impl Inverse<Addition> for Offset {
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, existant_geoalg::Group)]
+#[algebra(zero = Pixels(Offset(0)))]
+struct Pixels(Offset);
+
+fn abelian_group<T: AbelianGroup<Addition>>() {}
+fn group<T: Group<Addition>>() {}
+fn commutative_ring<T: CommutativeRing>() {}
+fn field<T: Field>() {}
+fn vector_space<V: VectorSpace>() where V::Scalar: Field {}
+
+#[test]
+fn groups() {
+    abelian_group::<Meters>();
+    assert_eq!(Meters(1.5) + Meters(2.0) - Meters(0.5), Meters(3.0));
+    assert_eq!(-Meters(1.0), <Meters as Inverse<Addition>>::inverse(Meters(1.0)));
+    assert_eq!(<Meters as Identity<Addition>>::IDENTITY, Meters(0.0));
+    assert!(<Meters as Identity<Addition>>::is_identity(&Meters(0.0)));
+
+    group::<Pixels>();
+    assert_eq!(<Pixels as Identity<Addition>>::IDENTITY, Pixels(Offset(0)));
+    assert_eq!(Pixels(Offset(3)) - Pixels(Offset(5)), Pixels(Offset(-2)));
+    assert!(<Pixels as Identity<Addition>>::is_identity(&(Pixels(Offset(4)) + -Pixels(Offset(4)))));
+}
+
+#[test]
+fn rings() {
+    commutative_ring::<Cents>();
+    let (a, b) = (Cents { amount: 250 }, Cents { amount: -4 });
+    assert_eq!(a * b + a, Cents { amount: -750 });
+    assert_eq!(<Cents as Identity<Multiplication>>::IDENTITY, Cents { amount: 1 });
+    assert!(<Cents as Absorption<Multiplication>>::is_absorber(&Cents { amount: 0 }));
+
+    field::<Quantity<f64, Seconds>>();
+    let t = Quantity::<f64, Seconds>(4.0, PhantomData);
+    assert_eq!(<Quantity<f64, Seconds> as Inverse<Multiplication>>::inverse(t), Quantity(0.25, PhantomData));
+    assert_eq!(t / t, <Quantity<f64, Seconds> as Identity<Multiplication>>::IDENTITY);
+}
+
+#[test]
+fn vector_spaces() {
+    vector_space::<Velocity>();
+    let v = Velocity { x: 1.0, y: -2.0, z: 0.5 };
+    assert_eq!(v.scalar_multiplication(2.0), v + v);
+    assert_eq!(v - v, <Velocity as Identity<Addition>>::IDENTITY);
+}
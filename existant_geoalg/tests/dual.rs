@@ -0,0 +1,111 @@
+//! `Dual` and `HyperDual` against derivatives worked out by hand and
+//! evaluated with the f64 functions from std.
+
+use existant_core::{FloatingPoint, Signed};
+use existant_geoalg::derivative::{Derivative, Dual, HyperDual};
+
+fn assert_close(actual: f64, expected: f64, what: &str) {
+    let tolerance = 1e-12 * expected.abs().max(1.0);
+    assert!((actual - expected).abs() <= tolerance || actual == expected, "{what}: {actual} != {expected}");
+}
+
+/// Runs `f` on f64, a `Dual` and a `HyperDual` at every point, checking the
+/// value, the first derivative `d` and the second derivative `dd`.
+macro_rules! check {
+    (|$x:ident| $f:expr, $d:expr, $dd:expr, $points:expr) => {
+        let (d, dd): (fn(f64) -> f64, fn(f64) -> f64) = ($d, $dd);
+        for point in $points {
+            let value = { let $x = point; $f };
+            let first = { let $x = Dual::variable(point); $f };
+            let second = { let $x = HyperDual::hyper_variable(point); $f };
+            let what = format!("{} at {point}", stringify!($f));
+            assert_close(first.real, value, &what);
+            assert_close(first.derivative(), d(point), &what);
+            assert_close(second.real.real, value, &what);
+            assert_close(second.real.derivative(), d(point), &what);
+            assert_close(second.dual[0].real, d(point), &what);
+            assert_close(second.second_derivative(), dd(point), &what);
+        }
+    };
+}
+
+const POSITIVE: [f64; 6] = [0.01, 0.3, 0.7, 1.5, 2.0, 10.0];
+const ANY: [f64; 7] = [-3.0, -0.5, -0.01, 0.0, 0.25, 1.0, 4.5];
+const UNIT: [f64; 5] = [-0.9, -0.2, 0.0, 0.5, 0.99];
+
+#[test]
+fn against_std() {
+    check!(|x| x.sin(), |x| x.cos(), |x| -x.sin(), ANY);
+    check!(|x| x.cos(), |x| -x.sin(), |x| -x.cos(), ANY);
+    check!(|x| x.exp(), |x| x.exp(), |x| x.exp(), ANY);
+    check!(|x| x.ln(), |x| 1.0 / x, |x| -1.0 / (x * x), POSITIVE);
+    check!(|x| x.log2(), |x| 1.0 / (x * core::f64::consts::LN_2), |x| -1.0 / (x * x * core::f64::consts::LN_2), POSITIVE);
+    check!(|x| x.sqrt(), |x| 0.5 / x.sqrt(), |x| -0.25 / (x * x.sqrt()), POSITIVE);
+    check!(|x| x.cbrt(), |x| 1.0 / (3.0 * x.cbrt().powi(2)), |x| -2.0 / (9.0 * x * x.cbrt().powi(2)), POSITIVE);
+    check!(|x| x.atan(), |x| 1.0 / (1.0 + x * x), |x| -2.0 * x / (1.0 + x * x).powi(2), ANY);
+    check!(|x| x.asin(), |x| 1.0 / (1.0 - x * x).sqrt(), |x| x / (1.0 - x * x).powf(1.5), UNIT);
+    check!(|x| x.tanh(), |x| 1.0 - x.tanh().powi(2), |x| -2.0 * x.tanh() * (1.0 - x.tanh().powi(2)), ANY);
+    check!(|x| x.recip(), |x| -1.0 / (x * x), |x| 2.0 / (x * x * x), POSITIVE);
+    check!(|x| x.powi(5), |x| 5.0 * x.powi(4), |x| 20.0 * x.powi(3), ANY);
+    check!(|x| x.powi(-2), |x| -2.0 * x.powi(-3), |x| 6.0 * x.powi(-4), POSITIVE);
+    check!(|x| x.powf(x), |x| x.powf(x) * (x.ln() + 1.0), |x| x.powf(x) * ((x.ln() + 1.0).powi(2) + 1.0 / x), POSITIVE);
+    check!(|x| x * x.sin(), |x| x.sin() + x * x.cos(), |x| 2.0 * x.cos() - x * x.sin(), ANY);
+    check!(|x| x.sin() / x.exp(), |x| (x.cos() - x.sin()) / x.exp(), |x| -2.0 * x.cos() / x.exp(), ANY);
+    check!(|x| x.abs(), |x| if x < 0.0 { -1.0 } else { 1.0 }, |_| 0.0, ANY);
+}
+
+#[test]
+fn gradients() {
+    let [x, y, z] = Dual::variables([2.0f64, -3.0, 0.5]);
+    let f = x * y + z.sin() * x;
+    assert_eq!(f.real, -6.0 + 0.5f64.sin() * 2.0);
+    assert_eq!(f.gradient(), [-3.0 + 0.5f64.sin(), 2.0, 0.5f64.cos() * 2.0]);
+    let [a, b] = Dual::variables([3.0f64, 4.0]);
+    let hypotenuse = a.hypot(b);
+    assert_eq!(hypotenuse.real, 5.0);
+    assert_close(hypotenuse.gradient()[0], 0.6, "hypot");
+    assert_close(hypotenuse.gradient()[1], 0.8, "hypot");
+    let angle = b.atan2(a);
+    assert_close(angle.gradient()[0], -4.0 / 25.0, "atan2");
+    assert_close(angle.gradient()[1], 3.0 / 25.0, "atan2");
+
+    // the same thing in f32, against the f64 answer
+    let x = Dual::variable(1.2f32);
+    let y = (x * x).exp().ln_1p();
+    let expected = 2.0 * 1.2 * 1.44f64.exp() / (1.0 + 1.44f64.exp());
+    assert!((y.derivative() as f64 - expected).abs() < 1e-5);
+    assert_eq!(Dual::variable(2.0f64).sin().derive(), 2.0f64.cos());
+}
+
+#[test]
+fn edges() {
+    // the derivative blows up where the function has a vertical tangent
+    assert_eq!(Dual::variable(0.0f64).sqrt().derivative(), f64::INFINITY);
+    assert_eq!(Dual::variable(0.0f64).recip().derivative(), f64::NEG_INFINITY);
+    assert!(Dual::variable(-1.0f64).ln().derivative() == -1.0 && Dual::variable(-1.0f64).ln().real.is_nan());
+    // a constant exponent works on a negative base
+    let cube = Dual::variable(-2.0f64).powf(Dual::constant(3.0));
+    assert_eq!((cube.real, cube.derivative()), (-8.0, 12.0));
+    // huge exponents, where n - 1 would overflow
+    let one = Dual::variable(1.0f64);
+    assert_eq!(one.powi(i32::MAX).derivative(), i32::MAX as f64);
+    assert_eq!(one.powi(i32::MIN).derivative(), i32::MIN as f64);
+    assert_eq!(Dual::variable(2.0f64).powi(i32::MIN).derivative(), 0.0);
+    assert_eq!(Dual::variable(2.0f64).powf(Dual::constant(2000.0)).derivative(), f64::INFINITY);
+    assert_eq!(Dual::variable(0.5f64).powf(Dual::constant(2000.0)).derivative(), 0.0);
+    // a first derivative of zero can still have a second derivative
+    let constant = |value: f64| HyperDual::constant(Dual::constant(value));
+    let x = HyperDual::hyper_variable(0.0f64);
+    assert_eq!((x * x + constant(1.0)).powf(constant(3.0)).second_derivative(), 6.0);
+    // flat pieces have no derivative
+    for flat in [Dual::variable(2.7f64).floor(), Dual::variable(2.7f64).round(), Dual::variable(-2.7f64).signum()] {
+        assert_eq!(flat.derivative(), 0.0);
+    }
+    let remainder = Dual::variable(7.5f64) % Dual::constant(2.0);
+    assert_eq!((remainder.real, remainder.derivative()), (1.5, 1.0));
+    // comparisons only look at the real part
+    assert_eq!(Dual::new(1.0f64, [5.0]), Dual::new(1.0, [-5.0]));
+    assert!(Dual::new(1.0f64, [5.0]) < Dual::new(2.0, [0.0]));
+    assert_eq!(Dual::variable(-2.0f64).abs().derivative(), -1.0);
+    assert!(Dual::variable(-1.0f64).is_negative());
+}
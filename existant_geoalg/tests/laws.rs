@@ -0,0 +1,52 @@
+//! Runs the vectors, rotors and matrices through `existant_core::laws`, for
+//! every law they claim.
+
+use existant_core::{laws, Addition, And, Multiplication, Or, Xor};
+use existant_geoalg::{matrix::{Matrix2x2, Matrix3x3, Matrix4x4}, rotors::{Complex, Quaternion}, vectors::{Vector2, Vector3, Vector4, VectorN}};
+use rand::{rngs::SmallRng, SeedableRng};
+
+const SAMPLES: usize = 10_000;
+
+fn rng() -> SmallRng {
+    SmallRng::seed_from_u64(0x1A55)
+}
+
+#[test]
+fn vectors() {
+    laws::check_field::<Vector2<f32>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_field::<Vector3<f64>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_commutative_ring::<Vector4<i32>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_absorption::<Vector2<i64>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_field::<VectorN<f64, 6>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_commutative_ring::<VectorN<i32, 8>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn complex() {
+    laws::check_field::<Complex<f32>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_field::<Complex<f64>, Multiplication, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_absorption::<Complex<f64>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn quaternion() {
+    laws::check_group::<Quaternion<f32>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_group::<Quaternion<f64>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn matrices() {
+    laws::check_commutative_monoid::<Matrix2x2<f32>, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_identity::<Matrix2x2<f32>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_commutative_monoid::<Matrix3x3<i32>, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_identity::<Matrix3x3<i32>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_commutative_monoid::<Matrix4x4<f64>, Addition, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_identity::<Matrix4x4<f64>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_inverse::<Matrix4x4<f64>, Multiplication, _>(&mut rng(), SAMPLES).unwrap();
+}
+
+#[test]
+fn boolean_vectors() {
+    laws::check_commutative_ring::<Vector4<u64>, And, Xor, _>(&mut rng(), SAMPLES).unwrap();
+    laws::check_semiring::<Vector4<bool>, And, Or, _>(&mut rng(), SAMPLES).unwrap();
+}
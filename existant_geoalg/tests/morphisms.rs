@@ -0,0 +1,64 @@
+//! The conversions between rotors and vectors as homomorphisms, and matrices
+//! as linear maps.
+
+use existant_core::{assert_approx_eq, Addition, Homomorphism, InvertibleLinearMap, Isomorphism, LinearMap};
+use existant_geoalg::{matrix::{Matrix2x2, Matrix2x3, Matrix3x2, Matrix3x3, Matrix4x4}, rotors::{Complex, Quaternion}, vectors::{Vector2, Vector3, Vector4}};
+
+/// `f(a) + f(b) = f(a + b)`, for any additive homomorphism.
+fn preserves_addition<A, B>(a: A, b: A) -> bool
+    where A: Homomorphism<Addition, B> + core::ops::Add<Output = A> + Copy,
+    B: core::ops::Add<Output = B> + PartialEq {
+    a.map() + b.map() == (a + b).map()
+}
+
+#[test]
+fn rotors() {
+    let (a, b) = (Complex::new(1.0, -2.0), Complex::new(0.5, 4.0));
+    assert!(preserves_addition::<_, Vector2<f64>>(a, b));
+    assert_eq!(Complex::unmap(Vector2::new(3.0, 1.0)), Complex::new(3.0, 1.0));
+    let v: Vector2<f64> = a.map();
+    assert_eq!(Complex::unmap(v), a);
+
+    let (p, q) = (Quaternion::new(1.0, 2.0, -3.0, 0.5), Quaternion::new(-0.75, 1.5, 2.0, -1.0));
+    assert_eq!(<Vector4<f64> as Isomorphism<Addition, Quaternion<f64>>>::unmap(p), Vector4::new(1.0, 2.0, -3.0, 0.5));
+    // quaternions only add through their components
+    let sum = Quaternion::unmap(Vector4::from(p) + Vector4::from(q));
+    let (mp, mq): (Matrix4x4<f64>, Matrix4x4<f64>) = (p.map(), q.map());
+    assert_approx_eq!(mp + mq, Homomorphism::<Addition, Matrix4x4<f64>>::map(sum));
+}
+
+#[test]
+fn matrices() {
+    let rotation = Matrix4x4::<f64>::from_rotation_z(0.5);
+    let v = Vector4::new(1.0, 2.0, 3.0, 1.0);
+    assert_eq!(rotation.apply(v), rotation * v);
+    assert_eq!(rotation.apply(v * 2.0), rotation.apply(v) * 2.0);
+
+    let lift = Matrix2x3::new(Vector3::new(1, 0, 2), Vector3::new(0, 1, -1));
+    assert_eq!(lift.apply(Vector2::new(3, 4)), lift * Vector2::new(3, 4));
+    assert_eq!(lift.apply(Vector2::new(3, 4)), Vector3::new(3, 4, 2));
+    let project = Matrix3x2::new(Vector2::new(1, 0), Vector2::new(0, 1), Vector2::new(1, 1));
+    let there_and_back = lift.then::<Vector2<i32>, _>(project);
+    assert_eq!(there_and_back.apply(Vector2::new(3, 4)), (project * lift) * Vector2::new(3, 4));
+    assert_eq!(there_and_back.apply(Vector2::new(3, 4)), Vector2::new(5, 6));
+
+    let twice = Matrix3x3::new(Vector3::new(2, 0, 0), Vector3::new(0, 2, 0), Vector3::new(0, 0, 2));
+    assert_eq!(twice.then::<Vector3<i32>, _>(twice).apply(Vector3::new(1, -1, 3)), Vector3::new(4, -4, 12));
+}
+
+#[test]
+fn inverses() {
+    let rotation = Matrix4x4::<f64>::from_rotation_z(0.5);
+    let v = Vector4::new(1.0, 2.0, 3.0, 1.0);
+    assert_approx_eq!(rotation.invert().unwrap().apply(rotation.apply(v)), v);
+
+    let shear = Matrix2x2::new(Vector2::new(1.0, 0.0), Vector2::new(2.0, 1.0));
+    let scale = Matrix2x2::new(Vector2::new(4.0, 0.0), Vector2::new(0.0, 0.5));
+    let both = shear.then::<Vector2<f64>, _>(scale);
+    let u = Vector2::new(3.0, -1.0);
+    assert_eq!(both.invert().unwrap().apply(both.apply(u)), u);
+
+    let flat = Matrix3x3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 4.0, 6.0), Vector3::new(0.0, 1.0, 0.0));
+    assert!(flat.invert().is_none());
+    assert!(shear.then::<Vector2<f64>, _>(Matrix2x2::new(Vector2::new(1.0, 1.0), Vector2::new(1.0, 1.0))).invert().is_none());
+}
@@ -0,0 +1,36 @@
+//! Vectors, matrices and rotors over a float that only comes in through
+//! `num_traits`.
+
+use existant_core::{assert_approx_eq, Identity, Multiplication, NumTraits, Tolerance};
+use existant_geoalg::{matrix::Matrix3x3, rotors::Quaternion, vectors::{InnerProductSpace, NormedVectorSpace, Vector3}};
+
+type F = NumTraits<f64>;
+
+fn vector(x: f64, y: f64, z: f64) -> Vector3<F> {
+    Vector3::new(F::new(x), F::new(y), F::new(z))
+}
+
+#[test]
+fn vectors() {
+    let v = vector(2.0, 3.0, 6.0);
+    assert_eq!(v.magnitude(), F::new(7.0));
+    assert_approx_eq!(v.normalize(), vector(2.0 / 7.0, 3.0 / 7.0, 6.0 / 7.0));
+    assert_eq!(v.inner_product(vector(1.0, -1.0, 0.0)), F::new(-1.0));
+}
+
+#[test]
+fn matrices() {
+    let identity = <Matrix3x3<F> as Identity<Multiplication>>::IDENTITY;
+    let m = Matrix3x3::new(vector(1.0, 0.0, 0.0), vector(0.0, 2.0, 0.0), vector(1.0, 0.0, 3.0));
+    assert_approx_eq!(identity * m, m);
+    let squared = Matrix3x3::new(vector(1.0, 0.0, 0.0), vector(0.0, 4.0, 0.0), vector(4.0, 0.0, 9.0));
+    assert_approx_eq!(m * m, squared);
+}
+
+#[test]
+fn rotors() {
+    let turn = Quaternion::from_angle(vector(0.0, 0.0, 1.0), F::new(core::f64::consts::FRAC_PI_2));
+    let half_turn = Quaternion::from_angle(vector(0.0, 0.0, 1.0), F::new(core::f64::consts::PI));
+    assert_approx_eq!(turn * turn, half_turn, Tolerance::Absolute(F::new(1e-15)));
+    assert_approx_eq!(turn * turn.conjugate(), <Quaternion<F> as Identity<Multiplication>>::IDENTITY);
+}
@@ -0,0 +1,48 @@
+//! The component-wise order on vectors, and the bounding boxes built from it.
+
+use core::cmp::Ordering;
+
+use existant_core::{BoundedLattice, FromPrimitive, Lattice, PartialOrderSet, Q16_16};
+use existant_geoalg::{geometry::{BoundingBox, Rect2D}, vectors::{Vector2, Vector3, Vector4}};
+
+#[test]
+fn component_wise() {
+    let a = Vector3::new(1, 5, 2);
+    let b = Vector3::new(3, 4, 2);
+    assert_eq!(a.meet(b), Vector3::new(1, 4, 2));
+    assert_eq!(a.join(b), Vector3::new(3, 5, 2));
+    assert!(!a.comparable(&b));
+    assert_eq!(a.partial_order(&b), None);
+    assert!(a.meet(b).leq(&a) && a.leq(&a.join(b)));
+    assert_eq!(Vector4::<u8>::BOTTOM, Vector4::new(0, 0, 0, 0));
+    assert_eq!(Vector2::<f32>::TOP.meet(Vector2::new(1.0, -2.0)), Vector2::new(1.0, -2.0));
+}
+
+#[test]
+fn dominance() {
+    let points = [Vector2::new(1.0, 4.0), Vector2::new(3.0, 3.0), Vector2::new(2.0, 2.0), Vector2::new(4.0, 1.0), Vector2::new(3.0, 3.0)];
+    let front: Vec<_> = points.iter().filter(|p| !points.iter().any(|q| q.dominates(p))).collect();
+    assert_eq!(front, [&points[0], &points[1], &points[3], &points[4]]);
+    assert_eq!(points[1].partial_order(&points[2]), Some(Ordering::Greater));
+}
+
+#[test]
+fn bounding_boxes() {
+    let points = [Vector2::new(1.0, -2.0), Vector2::new(-3.0, 0.5), Vector2::new(2.0, 4.0)];
+    let bounds = points.bounding_box();
+    assert_eq!(bounds, Rect2D::new(Vector2::new(-3.0, -2.0), Vector2::new(5.0, 6.0)));
+    assert_eq!(bounds.min(), Vector2::<f64>::TOP.meet(points[0]).meet(points[1]).meet(points[2]));
+    let empty: [Vector2<f64>; 0] = [];
+    assert_eq!(empty.bounding_box(), Rect2D::dimensionless());
+
+    let a = Rect2D::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+    let b = Rect2D::new(Vector2::new(2.0, -1.0), Vector2::new(1.0, 1.0));
+    assert_eq!(a.fit_rect(b), Rect2D::new(Vector2::new(0.0, -1.0), Vector2::new(3.0, 2.0)));
+    assert_eq!(a.fit_rect(b), b.fit_rect(a));
+
+    // fixed point has no lattice of its own, but still fits
+    let q = |x: f32, y: f32| Vector2::new(Q16_16::from_f32(x), Q16_16::from_f32(y));
+    let a = Rect2D::new(q(0.5, 0.0), q(1.0, 1.0));
+    let b = Rect2D::new(q(-1.0, 2.0), q(1.0, 0.5));
+    assert_eq!(a.fit_rect(b), Rect2D::new(q(-1.0, 0.0), q(2.5, 2.5)));
+}
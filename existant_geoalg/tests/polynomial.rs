@@ -0,0 +1,109 @@
+//! Arithmetic on `Polynomial` over exact types, and its roots over floats
+//! checked against polynomials built from known roots.
+
+use existant_core::{assert_approx_eq, ApproxEq, Gf, Rational, Tolerance};
+use existant_geoalg::{derivative::{Derivative, Integral}, polynomial::Polynomial, rotors::Complex, vectors::Vector4};
+
+fn rational(numerator: i64, denominator: i64) -> Rational<i64> {
+    Rational::new(numerator, denominator)
+}
+
+/// The monic polynomial with these roots.
+fn from_roots(roots: &[f64]) -> Polynomial<f64> {
+    roots.iter().fold(Polynomial::constant(1.0), |p, root| p*Polynomial::new([-root, 1.0]))
+}
+
+#[test]
+fn arithmetic() {
+    let p = Polynomial::new([1i64, 2, 3]);
+    let q = Polynomial::new([-1i64, 0, 0, 1]);
+    assert_eq!(p.clone() + q.clone(), Polynomial::new([0, 2, 3, 1]));
+    assert_eq!(p.clone() - p.clone(), Polynomial::zero());
+    assert_eq!((p.clone() - p.clone()).degree(), None);
+    assert_eq!(p.clone()*q.clone(), Polynomial::new([-1, -2, -3, 1, 2, 3]));
+    assert_eq!(p.clone()*2, Polynomial::new([2, 4, 6]));
+    assert_eq!(p.evaluate(-2), 9);
+    // p(q(x)) = 1 + 2(x³ - 1) + 3(x³ - 1)²
+    assert_eq!(p.compose(&q), Polynomial::new([2, 0, 0, -4, 0, 0, 3]));
+    assert_eq!(p.compose(&q).evaluate(3), p.evaluate(q.evaluate(3)));
+    assert_eq!(Polynomial::from(Vector4::new(4, 3, 2, 1)).derive(), Polynomial::new([3, 4, 3]));
+}
+
+#[test]
+fn division() {
+    let a = Polynomial::new([rational(-4, 1), rational(0, 1), rational(-2, 1), rational(1, 1)]);
+    let b = Polynomial::new([rational(-3, 1), rational(1, 1)]);
+    let (quotient, remainder) = a.div_rem(&b);
+    assert_eq!(quotient, Polynomial::new([rational(3, 1), rational(1, 1), rational(1, 1)]));
+    assert_eq!(remainder, Polynomial::constant(rational(5, 1)));
+    assert_eq!(quotient*b.clone() + remainder, a);
+    assert_eq!(b.div_rem(&a), (Polynomial::zero(), b.clone()));
+}
+
+#[test]
+fn gcd() {
+    // (x - 1)(2x + 1) and (x - 1)(x + 5), over the rationals and mod 7
+    let a = Polynomial::new([rational(-1, 1), rational(-1, 1), rational(2, 1)]);
+    let b = Polynomial::new([rational(-5, 1), rational(4, 1), rational(1, 1)]);
+    assert_eq!(a.gcd(&b), Polynomial::new([rational(-1, 1), rational(1, 1)]));
+    assert_eq!(a.gcd(&Polynomial::zero()), a.monic());
+
+    let a = Polynomial::new([Gf::<7>::from(-1i64), Gf::from(-1i64), Gf::from(2u64)]);
+    let b = Polynomial::new([Gf::<7>::from(-5i64), Gf::from(4u64), Gf::from(1u64)]);
+    assert_eq!(a.gcd(&b), Polynomial::new([Gf::from(-1i64), Gf::from(1u64)]));
+}
+
+#[test]
+fn calculus() {
+    let p = Polynomial::new([rational(1, 1), rational(-1, 2), rational(3, 1), rational(2, 3)]);
+    assert_eq!(p.integrate(), Polynomial::new([rational(0, 1), rational(1, 1), rational(-1, 4), rational(1, 1), rational(1, 6)]));
+    assert_eq!(p.integrate().derive(), p);
+    assert_eq!(Polynomial::<Rational<i64>>::zero().integrate(), Polynomial::zero());
+}
+
+#[test]
+fn real_roots() {
+    for roots in [
+        vec![2.5],
+        vec![-1.0, 3.0],
+        vec![-3.0, 1.0, 2.0],
+        vec![0.0, 0.5, 4.0],
+        vec![-2.0, -1.0, 1.0, 2.0],
+        vec![-7.0, 0.1, 0.2, 9.0],
+        vec![-4.0, -1.5, 0.0, 2.0, 3.0, 8.0],
+    ] {
+        assert_approx_eq!(from_roots(&roots).real_roots()[..], roots[..], Tolerance::Absolute(1e-9));
+    }
+    // only the real one of (x - 2)(x² + 1)(x² + 2x + 5)
+    let p = from_roots(&[2.0])*Polynomial::new([1.0, 0.0, 1.0])*Polynomial::new([5.0, 2.0, 1.0]);
+    assert_approx_eq!(p.real_roots()[..], [2.0][..], Tolerance::Absolute(1e-9));
+    assert!(Polynomial::new([1.0, 0.0, 1.0]).real_roots().is_empty());
+    assert!(Polynomial::constant(3.0).real_roots().is_empty());
+}
+
+/// Checks every root in `expected` turns up in the roots of `p`, with
+/// nothing extra.
+fn assert_complex_roots(p: Polynomial<f64>, expected: &[Complex<f64>]) {
+    let roots = p.complex_roots();
+    assert_eq!(roots.len(), expected.len());
+    for root in expected {
+        assert!(roots.iter().any(|r| r.approx_eq(root, Tolerance::Absolute(1e-9))), "{root:?} isn't in {roots:?}");
+    }
+}
+
+#[test]
+fn complex_roots() {
+    let i = Complex::new(0.0, 1.0);
+    // x² + 1, x² + 2x + 5 and x² - 4x + 13, with roots ±i, -1 ± 2i and 2 ± 3i
+    let a = Polynomial::new([1.0, 0.0, 1.0]);
+    let b = Polynomial::new([5.0, 2.0, 1.0]);
+    let c = Polynomial::new([13.0, -4.0, 1.0]);
+    let (a_roots, b_roots, c_roots) = ([i, -i], [Complex::new(-1.0, 2.0), Complex::new(-1.0, -2.0)], [Complex::new(2.0, 3.0), Complex::new(2.0, -3.0)]);
+    let two = Complex::new(2.0, 0.0);
+
+    assert_complex_roots(a.clone(), &a_roots);
+    assert_complex_roots(a.clone()*from_roots(&[2.0]), &[a_roots[0], a_roots[1], two]);
+    assert_complex_roots(a.clone()*b.clone(), &[a_roots, b_roots].concat());
+    assert_complex_roots(a.clone()*b.clone()*from_roots(&[2.0]), &[&a_roots[..], &b_roots, &[two]].concat());
+    assert_complex_roots(a*b*c, &[a_roots, b_roots, c_roots].concat());
+}
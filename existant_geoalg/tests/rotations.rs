@@ -0,0 +1,31 @@
+//! The rotations that can be built at compile time, against the ones built
+//! at runtime.
+
+use existant_core::{assert_approx_eq, Tolerance};
+use existant_geoalg::{matrix::Matrix4x4, rotors::{Complex, Quaternion}, vectors::{Vector3, Vector4}};
+
+const QUARTER_TURN: Matrix4x4<f64> = Matrix4x4::<f64>::from_rotation_z(core::f64::consts::FRAC_PI_2);
+const ROOTS_OF_UNITY: [Complex<f32>; 3] = [
+    Complex::<f32>::from_angle_const(0.0),
+    Complex::<f32>::from_angle_const(core::f32::consts::TAU / 3.0),
+    Complex::<f32>::from_angle_const(2.0 * core::f32::consts::TAU / 3.0),
+];
+static TILT: Quaternion<f64> = Quaternion::<f64>::from_angle_const(Vector3::new(0.0, 3.0, 4.0), 1.2);
+
+#[test]
+fn matrices() {
+    let close = Tolerance::Absolute(1e-15);
+    assert_approx_eq!(QUARTER_TURN * Vector4::new(1.0, 0.0, 0.0, 1.0), Vector4::new(0.0, 1.0, 0.0, 1.0), close);
+    assert_approx_eq!(Matrix4x4::<f64>::from_rotation_x(core::f64::consts::FRAC_PI_2) * Vector4::new(0.0, 1.0, 0.0, 0.0), Vector4::new(0.0, 0.0, 1.0, 0.0), close);
+    assert_approx_eq!(Matrix4x4::<f64>::from_rotation_y(core::f64::consts::FRAC_PI_2) * Vector4::new(0.0, 0.0, 1.0, 0.0), Vector4::new(1.0, 0.0, 0.0, 0.0), close);
+    // translations are left alone
+    assert_eq!(Matrix4x4::<f32>::from_rotation_z(0.3) * Vector4::new(0.0, 0.0, 5.0, 1.0), Vector4::new(0.0, 0.0, 5.0, 1.0));
+}
+
+#[test]
+fn rotors() {
+    for (k, root) in ROOTS_OF_UNITY.iter().enumerate() {
+        assert_approx_eq!(*root, Complex::from_angle(k as f32 * core::f32::consts::TAU / 3.0), Tolerance::Ulps(1));
+    }
+    assert_approx_eq!(TILT, Quaternion::from_angle(Vector3::new(0.0, 3.0, 4.0), 1.2), Tolerance::Ulps(1));
+}
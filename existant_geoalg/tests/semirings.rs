@@ -0,0 +1,49 @@
+//! Matrix products over semirings other than the usual one.
+
+use existant_core::{Addition, And, Min, MinPlus, Or};
+use existant_geoalg::{matrix::{DynamicMatrix, Matrix4x4}, vectors::Vector4};
+
+#[test]
+fn tropical() {
+    // the zero is i32::MAX underneath, which raw integers would overflow on
+    let identity = DynamicMatrix::<MinPlus<i32>>::semiring_identity::<Addition, Min>(3);
+    assert_eq!(identity.semiring_pow::<Addition, Min>(2), identity);
+
+    let mut weights = identity.clone();
+    weights[(0, 1)] = MinPlus::new(-2);
+    weights[(1, 2)] = MinPlus::new(i32::MAX - 1);
+    let distances = weights.semiring_pow::<Addition, Min>(2);
+    assert_eq!(distances[(0, 2)], MinPlus::new(i32::MAX - 3));
+    assert!(distances[(2, 0)].is_zero());
+}
+
+/// The matrices don't compare, only their columns do.
+fn columns(m: Matrix4x4<bool>) -> [Vector4<bool>; 4] {
+    [m.x, m.y, m.z, m.w]
+}
+
+#[test]
+fn reachability() {
+    // the edges 0 -> 1 -> 2 -> 3 plus a loop on each vertex, with the edge
+    // from `i` to `j` in row `i` of column `j`
+    let (o, l) = (false, true);
+    let edges = Matrix4x4::new(
+        Vector4::new(l, o, o, o),
+        Vector4::new(l, l, o, o),
+        Vector4::new(o, l, l, o),
+        Vector4::new(o, o, l, l),
+    );
+    assert_eq!(columns(Matrix4x4::<bool>::semiring_identity::<And, Or>().semiring_product::<And, Or>(&edges)), columns(edges));
+
+    let two_steps = edges.semiring_product::<And, Or>(&edges);
+    assert!(two_steps.z.x && !two_steps.w.x);
+    let reachable = edges.semiring_pow::<And, Or>(3);
+    assert_eq!(columns(reachable), [
+        Vector4::new(l, o, o, o),
+        Vector4::new(l, l, o, o),
+        Vector4::new(l, l, l, o),
+        Vector4::new(l, l, l, l),
+    ]);
+    // and nothing new turns up after that
+    assert_eq!(columns(edges.semiring_pow::<And, Or>(7)), columns(reachable));
+}
@@ -0,0 +1,72 @@
+//! Round trips through JSON, the compact layout of the vectors and matrices,
+//! and the values that shouldn't make it back.
+
+use existant_core::{assert_approx_eq, Identity, Multiplication};
+use existant_geoalg::{geometry::{LinearSegment2D, Ray2D, Ray3D, Rect2D, Sphere2D}, mappings::MortonU32, matrix::{DynamicMatrix, Matrix3x2, Matrix4x4}, rotors::{Complex, Quaternion}, vectors::{Vector2, Vector3, Vector4, VectorN}};
+use serde::{de::DeserializeOwned, Serialize};
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn compact() {
+    assert_eq!(serde_json::to_string(&Vector3::new(1, 2, 3)).unwrap(), "[1,2,3]");
+    assert_eq!(serde_json::to_string(&VectorN::from(Vector3::new(1, 2, 3))).unwrap(), "[1,2,3]");
+    assert_eq!(serde_json::to_string(&Complex::new(1.0, -2.0)).unwrap(), "[1.0,-2.0]");
+    assert_eq!(serde_json::to_string(&Quaternion::new(1.0, 0.0, 0.0, 0.0)).unwrap(), "[1.0,0.0,0.0,0.0]");
+    let matrix = Matrix3x2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0), Vector2::new(5.0, 6.0));
+    assert_eq!(serde_json::to_string(&matrix).unwrap(), "[[1.0,2.0],[3.0,4.0],[5.0,6.0]]");
+    let dynamic = DynamicMatrix::from_fn(2, 3, |row, column| row * 10 + column);
+    assert_eq!(serde_json::to_string(&dynamic).unwrap(), "[2,3,[0,10,1,11,2,12]]");
+    let morton = MortonU32::encode_xy(5, 9);
+    assert_eq!(serde_json::to_string(&morton).unwrap(), (*morton).to_string());
+}
+
+#[test]
+fn round_trips() {
+    assert!(round_trip(&Vector4::new(1.5f32, -2.0, 0.25, 8.0)) == Vector4::new(1.5, -2.0, 0.25, 8.0));
+    let long = VectorN::<u16, 40>::from_fn(|i| i as u16 * 3);
+    assert_eq!(round_trip(&long), long);
+    assert!(round_trip(&Quaternion::new(0.5, 0.5, -0.5, 0.5)) == Quaternion::new(0.5, 0.5, -0.5, 0.5));
+    assert_approx_eq!(round_trip(&<Matrix4x4<f64> as Identity<Multiplication>>::IDENTITY), <Matrix4x4<f64> as Identity<Multiplication>>::IDENTITY);
+    let dynamic = DynamicMatrix::from_fn(3, 2, |row, column| (row as i32) - (column as i32));
+    assert_eq!(round_trip(&dynamic), dynamic);
+    let morton = MortonU32::encode_xy(300, 7);
+    assert_eq!(round_trip(&morton), morton);
+
+    let rect = Rect2D::new(Vector2::new(-1.0, 2.0), Vector2::new(3.0, 0.0));
+    assert_eq!(round_trip(&rect), rect);
+    let sphere = Sphere2D::new(Vector2::new(1.0, 1.0), 2.0);
+    assert_eq!(round_trip(&sphere), sphere);
+    let segment = LinearSegment2D::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, -1.0));
+    assert_eq!(round_trip(&segment), segment);
+
+    let ray = Ray2D::new(Vector2::new(3.0, 4.0), Vector2::new(1.0, 1.0));
+    let read = round_trip(&ray);
+    assert_approx_eq!(read.dir(), ray.dir());
+    assert_approx_eq!(read.pos(), ray.pos());
+    let ray = Ray3D::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 0.0, 2.0));
+    let read = round_trip(&ray);
+    assert_approx_eq!(read.dir(), Vector3::new(0.0, 0.0, 1.0));
+    assert_approx_eq!(read.pos(), ray.pos());
+}
+
+#[test]
+fn invalid() {
+    assert!(serde_json::from_str::<Rect2D<f32>>(r#"{"pos":[0.0,0.0],"dimensions":[-1.0,2.0]}"#).is_err());
+    assert!(serde_json::from_str::<Rect2D<i32>>(r#"{"pos":[0,0],"dimensions":[1,-2]}"#).is_err());
+    assert!(serde_json::from_str::<Rect2D<i32>>(r#"{"pos":[0,0],"dimensions":[1,2]}"#).is_ok());
+    assert!(serde_json::from_str::<Vector3<f32>>("[1.0,2.0]").is_err());
+    assert!(serde_json::from_str::<VectorN<f32, 5>>("[1.0,2.0,3.0,4.0]").is_err());
+    assert!(serde_json::from_str::<VectorN<f32, 2>>("[1.0,2.0,3.0]").is_err());
+    assert!(serde_json::from_str::<DynamicMatrix<i32>>("[2,2,[1,2,3]]").is_err());
+    assert!(serde_json::from_str::<Sphere2D<f64>>(r#"{"center":[0.0,0.0],"radius":-1.0}"#).is_err());
+    assert!(serde_json::from_str::<Sphere2D<f64>>(r#"{"center":[0.0,0.0],"radius":0.0}"#).is_ok());
+    assert!(serde_json::from_str::<Ray2D<f64>>(r#"{"pos":[1.0,1.0],"dir":[0.0,0.0]}"#).is_err());
+    assert!(serde_json::from_str::<Ray3D<f32>>(r#"{"pos":[1.0,1.0,1.0],"dir":[0.0,-0.0,0.0]}"#).is_err());
+
+    // a direction written by hand is normalized on the way in
+    let ray: Ray2D<f64> = serde_json::from_str(r#"{"pos":[0.0,0.0],"dir":[0.0,5.0]}"#).unwrap();
+    assert_approx_eq!(ray.dir(), Vector2::new(0.0, 1.0));
+}
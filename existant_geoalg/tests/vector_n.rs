@@ -0,0 +1,49 @@
+//! `VectorN` against the fixed size vectors it's supposed to agree with.
+
+use existant_core::{assert_approx_eq, Addition, Bounds, Identity, Lattice};
+use existant_geoalg::vectors::{DVecN, InnerProductSpace, MetricSpace, NormedVectorSpace, Vector3, Vector4, VectorN};
+
+#[test]
+fn agrees_with_fixed_size() {
+    let a = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    let b = Vector4::new(0.25, 4.0, -1.0, 2.0);
+    let (n, m) = (VectorN::from(a), VectorN::from(b));
+    assert_eq!(n.inner_product(m), a.inner_product(b));
+    assert_eq!(n.magnitude(), a.magnitude());
+    assert_eq!(Vector4::from(n.normalize()), a.normalize());
+    assert_eq!(Vector4::from(n + m * 2.0), a + b * 2.0);
+    assert_eq!(Vector4::from(n / m), a / b);
+    assert_eq!(Vector4::from(-n), a * -1.0);
+    assert_eq!(n.distance(m), (a - b).magnitude());
+    assert_eq!(n.to_string(), a.to_string());
+    assert_eq!(format!("{n:?}"), format!("{a:?}"));
+}
+
+#[test]
+fn conversions() {
+    let mut v = Vector3::new(1, 2, 3);
+    let n: &mut VectorN<i32, 3> = v.as_mut();
+    n[1] = 7;
+    assert_eq!(v, Vector3::new(1, 7, 3));
+    let n = VectorN::new([4u8, 5, 6]);
+    let fixed: &Vector3<u8> = n.as_ref();
+    assert_eq!(fixed.z, 6);
+    assert_eq!(bytemuck::cast::<Vector3<u8>, VectorN<u8, 3>>(*fixed), n);
+    assert_eq!(<[u8; 3]>::from(n), [4, 5, 6]);
+}
+
+#[test]
+fn six_dimensions() {
+    let state = DVecN::<6>::new([1.0, 2.0, 2.0, 0.0, 4.0, 0.0]);
+    assert_eq!(state.magnitude(), 5.0);
+    assert_approx_eq!(state.normalize().magnitude(), 1.0);
+    assert_eq!(state.len(), 6);
+    assert_eq!(DVecN::<6>::axis(4).inner_product(state), 4.0);
+    assert!(<DVecN<6> as Identity<Addition>>::is_identity(&(state - state)));
+    assert_eq!(Bounds::max(state, DVecN::<6>::MIN), state);
+
+    let a = VectorN::new([3, 1, 4, 1, 5, 9, 2, 6]);
+    let b = VectorN::new([2, 7, 1, 8, 2, 8, 1, 8]);
+    assert_eq!(a.meet(b), VectorN::new([2, 1, 1, 1, 2, 8, 1, 6]));
+    assert_eq!(a.zip_with(b, |x, y| x * 10 + y), VectorN::new([32, 17, 41, 18, 52, 98, 21, 68]));
+}